@@ -0,0 +1,159 @@
+use std::{collections::BTreeMap, fs, io, path::{Path, PathBuf}};
+use aoc_common::{parse::numbered_lines, ParseError};
+
+/// The name of the file holding the expected answers, at the root of the repository.
+pub const ANSWERS_FILE_NAME: &str = "answers.toml";
+
+/// The expected answers, by day number and part number.
+/// 
+/// They are stored as a small subset of TOML, with a table per day and a string per part:
+/// 
+/// ```toml
+/// [day_01]
+/// part_1 = "24000"
+/// part_2 = "45000"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExpectedAnswers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+impl ExpectedAnswers {
+    /// Returns the expected answer of a part, if there is one.
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Sets the expected answer of a part.
+    pub fn set(&mut self, day: u8, part: u8, answer: String) {
+        self.answers.insert((day, part), answer);
+    }
+
+    /// Parses the content of an answers file.
+    pub fn parse(content: &str) -> Result<Self, ParseError> {
+        let mut expected = Self::default();
+        let mut current_day = None;
+
+        for (line_number, line) in numbered_lines(content) {
+            let trimmed = line.trim();
+
+            // skip the comments and the empty lines
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            // a table starts the answers of a day
+            if let Some(table) = trimmed.strip_prefix('[').and_then(|t| t.strip_suffix(']')) {
+                let day = table
+                    .strip_prefix("day_")
+                    .and_then(|day| day.parse::<u8>().ok())
+                    .ok_or_else(|| ParseError::new("expected a day, such as `[day_01]`").within(line, trimmed).at_line(line_number, line))?;
+                current_day = Some(day);
+                continue;
+            }
+
+            // otherwise, the line is the answer of a part
+            let (key, value) = trimmed
+                .split_once('=')
+                .ok_or_else(|| ParseError::new("expected an answer, such as `part_1 = \"24000\"`").at_line(line_number, line))?;
+            let (key, value) = (key.trim(), value.trim());
+            let part = match key {
+                "part_1" => 1,
+                "part_2" => 2,
+                _ => return Err(ParseError::new("expected `part_1` or `part_2`").within(line, key).at_line(line_number, line)),
+            };
+            let day = current_day
+                .ok_or_else(|| ParseError::new("the answer doesn't belong to a day, add a `[day_NN]` line before it").at_line(line_number, line))?;
+            let answer = unescape(value).map_err(|error| error.within(line, value).at_line(line_number, line))?;
+
+            expected.set(day, part, answer);
+        }
+
+        Ok(expected)
+    }
+
+    /// Loads the answers file, an absent file doesn't contain any answer.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content)
+                .map_err(|error| format!("Unable to parse `{}`:\n{}", path.display(), error.report())),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(format!("Unable to read `{}`: {error}", path.display())),
+        }
+    }
+
+    /// Saves the answers to the given file.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_string()).map_err(|error| format!("Unable to write `{}`: {error}", path.display()))
+    }
+}
+
+impl std::fmt::Display for ExpectedAnswers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# The expected answer of each part, checked by `aoc check` and updated by `aoc check <DAYS> --record`.")?;
+
+        let mut previous_day = None;
+        for (&(day, part), answer) in &self.answers {
+            // start a new table for each day
+            if previous_day != Some(day) {
+                writeln!(f, "\n[day_{day:02}]")?;
+                previous_day = Some(day);
+            }
+            writeln!(f, "part_{part} = {}", escape(answer))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Converts an answer into a TOML basic string, escaping the characters that need to be.
+fn escape(answer: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in answer.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Converts a TOML basic string back into an answer.
+fn unescape(value: &str) -> Result<String, ParseError> {
+    let content = value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .filter(|_| value.len() >= 2)
+        .ok_or_else(|| ParseError::new("expected a string between double quotes"))?;
+
+    let mut answer = String::new();
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            answer.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('"') => answer.push('"'),
+            Some('\\') => answer.push('\\'),
+            Some('n') => answer.push('\n'),
+            Some('t') => answer.push('\t'),
+            _ => return Err(ParseError::new("unsupported escape sequence, expected `\\\"`, `\\\\`, `\\n` or `\\t`")),
+        }
+    }
+
+    Ok(answer)
+}
+
+/// Finds the answers file: in the current directory, or at the root of the repository.
+pub fn default_answers_path() -> PathBuf {
+    let local = PathBuf::from(ANSWERS_FILE_NAME);
+    if local.exists() {
+        return local;
+    }
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(ANSWERS_FILE_NAME)
+}
@@ -0,0 +1,225 @@
+use std::{
+    collections::HashMap,
+    fs,
+    hint::black_box,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+use aoc_common::{ParseError, Solution};
+use crate::{days::Day, options::Options, read_input, table::Table};
+
+/// The number of times each day is measured, unless told otherwise.
+pub const DEFAULT_ITERATIONS: usize = 10;
+
+/// Once a day has been measured for this long, its measure stops even if it didn't reach the number of iterations.
+/// It keeps slow days (such as the 15th, which uses a solver) from taking forever.
+const TIME_BUDGET: Duration = Duration::from_secs(5);
+
+/// The time taken by each phase of a day.
+/// Each time is the median of several runs, to be less sensitive to the noise.
+/// 
+/// # Fields
+/// 
+/// * `parse` - The time taken to parse the input.
+/// * `part_one` - The time taken to compute the answer of the first part.
+/// * `part_two` - The time taken to compute the answer of the second part.
+/// * `runs` - The number of runs the medians were computed from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Measure {
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+    pub runs: usize,
+}
+
+impl Measure {
+    /// The time taken by the three phases.
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_one + self.part_two
+    }
+}
+
+/// Measures each phase of a day.
+/// 
+/// # Arguments
+/// 
+/// * `input` - The puzzle input.
+/// * `iterations` - How many times each phase is run (at least once, and fewer times if the day is slow).
+pub fn measure<S: Solution>(input: &str, iterations: usize) -> Result<Measure, ParseError> {
+    // the time taken by each run of each phase
+    let mut parse_times = vec![];
+    let mut part_one_times = vec![];
+    let mut part_two_times = vec![];
+
+    let budget_start = Instant::now();
+    for _ in 0..iterations.max(1) {
+        // the inputs and the answers go through `black_box` so the compiler can't optimize the work away
+        let start = Instant::now();
+        let parsed = S::parse_input(black_box(input))?;
+        parse_times.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part_one(black_box(&parsed)));
+        part_one_times.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part_two(black_box(&parsed)));
+        part_two_times.push(start.elapsed());
+
+        // stop early if the day is too slow
+        if budget_start.elapsed() > TIME_BUDGET {
+            break;
+        }
+    }
+
+    Ok(Measure {
+        runs: parse_times.len(),
+        parse: median(&mut parse_times),
+        part_one: median(&mut part_one_times),
+        part_two: median(&mut part_two_times),
+    })
+}
+
+/// Finds the median of the given times.
+pub fn median(times: &mut [Duration]) -> Duration {
+    times.sort();
+    times[times.len() / 2]
+}
+
+/// The measures of a previous benchmark, by day number.
+pub type Baseline = HashMap<u8, Measure>;
+
+/// Saves the measures to a baseline file.
+/// 
+/// The file has one line per day, holding the number of the day and the time taken by each phase in nanoseconds.
+pub fn save_baseline(path: &str, measures: &[(u8, Measure)]) -> Result<(), String> {
+    let mut content = String::from("# day parse part_one part_two runs (times in nanoseconds)\n");
+    for (day, measure) in measures {
+        content.push_str(&format!(
+            "{day} {} {} {} {}\n",
+            measure.parse.as_nanos(),
+            measure.part_one.as_nanos(),
+            measure.part_two.as_nanos(),
+            measure.runs
+        ));
+    }
+
+    fs::write(path, content).map_err(|error| format!("Unable to save the baseline to `{path}`: {error}"))
+}
+
+/// Loads the measures saved to a baseline file by [`save_baseline`].
+pub fn load_baseline(path: &str) -> Result<Baseline, String> {
+    let content = fs::read_to_string(path).map_err(|error| format!("Unable to read the baseline `{path}`: {error}"))?;
+
+    let mut baseline = Baseline::new();
+    for (line_index, line) in content.lines().enumerate() {
+        // skip the comments and the empty lines
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        // parse the day and its times
+        let invalid = || format!("The line {} of the baseline `{path}` is invalid: `{line}`", line_index + 1);
+        let values: Vec<u64> = line
+            .split_whitespace()
+            .map(|value| value.parse().map_err(|_| invalid()))
+            .collect::<Result<_, _>>()?;
+        let [day, parse, part_one, part_two, runs] = values[..] else {
+            return Err(invalid());
+        };
+        let day = u8::try_from(day).map_err(|_| invalid())?;
+
+        baseline.insert(day, Measure {
+            parse: Duration::from_nanos(parse),
+            part_one: Duration::from_nanos(part_one),
+            part_two: Duration::from_nanos(part_two),
+            runs: runs as usize,
+        });
+    }
+
+    Ok(baseline)
+}
+
+/// Formats a time, along with how it changed compared to the baseline (if there is one).
+pub fn format_time(time: Duration, baseline_time: Option<Duration>) -> String {
+    match baseline_time {
+        Some(baseline_time) if !baseline_time.is_zero() => {
+            let change = (time.as_secs_f64() / baseline_time.as_secs_f64() - 1.0) * 100.0;
+            format!("{time:.2?} ({change:+.1}%)")
+        },
+        _ => format!("{time:.2?}"),
+    }
+}
+
+/// Benchmarks the selected days, and displays the results in a table.
+/// 
+/// # Options
+/// 
+/// * `--iterations N` - How many times each phase is run.
+/// * `--save PATH` - Saves the results as a baseline.
+/// * `--baseline PATH` - Compares the results to a saved baseline.
+pub fn bench(days: &[&Day], options: &Options) -> Result<ExitCode, String> {
+    let iterations = options.parse_value("iterations", DEFAULT_ITERATIONS)?;
+    let baseline = match options.value("baseline").map(load_baseline).transpose() {
+        Ok(baseline) => baseline,
+        Err(error) => {
+            eprintln!("{error}");
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+
+    // create the table that will hold the results
+    let mut table = Table::new(&["Day", "Parse", "Part 1", "Part 2", "Total", "Runs"]);
+
+    // measure each day
+    let mut measures = vec![];
+    let mut failed = false;
+    for day in days {
+        // if the input can't be read, say why and keep measuring the other days
+        let input = match read_input(day) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("{error}\n");
+                failed = true;
+                continue;
+            }
+        };
+
+        let measure = match (day.measure)(&input, iterations) {
+            Ok(measure) => measure,
+            Err(error) => {
+                eprintln!("{}\n", error.report());
+                failed = true;
+                continue;
+            }
+        };
+
+        // add the results to the table, compared to the baseline if there is one
+        let previous = baseline.as_ref().and_then(|baseline| baseline.get(&day.number));
+        table.push(vec![
+            format!("{:02}", day.number),
+            format_time(measure.parse, previous.map(|p| p.parse)),
+            format_time(measure.part_one, previous.map(|p| p.part_one)),
+            format_time(measure.part_two, previous.map(|p| p.part_two)),
+            format_time(measure.total(), previous.map(Measure::total)),
+            measure.runs.to_string(),
+        ]);
+        measures.push((day.number, measure));
+    }
+
+    // display the results
+    println!("Median time of each phase:");
+    println!("{table}");
+
+    // save the results if asked to
+    if let Some(path) = options.value("save") {
+        if let Err(error) = save_baseline(path, &measures) {
+            eprintln!("{error}");
+            return Ok(ExitCode::FAILURE);
+        }
+        println!("The results were saved to `{path}`.");
+    }
+
+    Ok(if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
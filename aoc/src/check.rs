@@ -0,0 +1,164 @@
+use std::{path::PathBuf, process::ExitCode};
+use crate::{
+    answers::{default_answers_path, ExpectedAnswers},
+    days::Day,
+    options::Options,
+    read_input,
+    table::Table,
+};
+
+/// The result of comparing an answer to the expected one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    /// The answer is the expected one.
+    Correct,
+    /// The answer is different from the expected one.
+    Wrong,
+    /// There is no expected answer to compare to.
+    Missing,
+}
+
+/// Shows the differences between the expected answer and the actual one, line by line.
+/// 
+/// The lines of the expected answer start with `-`, the lines of the actual answer start with `+`,
+/// and the lines common to both answers start with a space.
+fn diff(expected: &str, actual: &str) -> String {
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+
+    let mut diff = vec![];
+    for index in 0..expected_lines.len().max(actual_lines.len()) {
+        match (expected_lines.get(index), actual_lines.get(index)) {
+            (Some(expected_line), Some(actual_line)) if expected_line == actual_line => {
+                diff.push(format!("  {expected_line}"));
+            },
+            (expected_line, actual_line) => {
+                if let Some(expected_line) = expected_line {
+                    diff.push(format!("- {expected_line}"));
+                }
+                if let Some(actual_line) = actual_line {
+                    diff.push(format!("+ {actual_line}"));
+                }
+            },
+        }
+    }
+
+    diff.join("\n")
+}
+
+/// Runs the selected days, and compares their answers to the expected ones.
+/// 
+/// # Options
+/// 
+/// * `--answers PATH` - The file holding the expected answers (`answers.toml` by default).
+/// * `--record` - Records the current answers as the expected ones, instead of failing when they differ.
+/// 
+/// # Returns
+/// 
+/// A failure exit code if an answer is wrong or missing, or if an input can't be read or parsed.
+pub fn check(days: &[&Day], options: &Options) -> Result<ExitCode, String> {
+    let path = options.value("answers").map(PathBuf::from).unwrap_or_else(default_answers_path);
+    let record = options.flag("record");
+    let mut expected = match ExpectedAnswers::load(&path) {
+        Ok(expected) => expected,
+        Err(error) => {
+            eprintln!("{error}");
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+
+    // create the table that will hold the status of each answer
+    let mut table = Table::new(&["Day", "Part 1", "Part 2"]);
+
+    // the differences found, and the number of answers with each status
+    let mut diffs = vec![];
+    let (mut correct, mut wrong, mut missing, mut parse_errors, mut unreadable) = (0, 0, 0, 0, 0);
+
+    // check each day
+    for day in days {
+        // if the input can't be read, say why and keep checking the other days
+        let input = match read_input(day) {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("{error}\n");
+                unreadable += 1;
+                table.push(vec![format!("{:02}", day.number), "unreadable input".to_owned(), "unreadable input".to_owned()]);
+                continue;
+            }
+        };
+
+        // solve the day, if the input couldn't be parsed, show where the problem is and keep checking the other days
+        let answers = match (day.solve)(&input) {
+            Ok((_, answers)) => answers,
+            Err(error) => {
+                eprintln!("{}\n", error.report());
+                parse_errors += 1;
+                table.push(vec![format!("{:02}", day.number), "parse error".to_owned(), "parse error".to_owned()]);
+                continue;
+            }
+        };
+
+        // compare each answer to the expected one
+        let mut row = vec![format!("{:02}", day.number)];
+        for answer in answers {
+            let status = match expected.get(answer.day, answer.part) {
+                Some(expected_answer) if expected_answer == answer.answer => Status::Correct,
+                Some(expected_answer) => {
+                    diffs.push(format!(
+                        "Day {:02}, part {}:\n{}",
+                        answer.day,
+                        answer.part,
+                        diff(expected_answer, &answer.answer)
+                    ));
+                    Status::Wrong
+                },
+                None => Status::Missing,
+            };
+
+            match status {
+                Status::Correct => correct += 1,
+                Status::Wrong => wrong += 1,
+                Status::Missing => missing += 1,
+            }
+            row.push(match status {
+                Status::Correct => "ok".to_owned(),
+                Status::Wrong => "WRONG".to_owned(),
+                Status::Missing => "missing".to_owned(),
+            });
+
+            // remember the answer if it has to be recorded
+            if record {
+                expected.set(answer.day, answer.part, answer.answer);
+            }
+        }
+        table.push(row);
+    }
+
+    // display the status of each answer, and the differences
+    println!("{table}");
+    for diff in &diffs {
+        println!("\n{diff}");
+    }
+    let days = |count: usize| if count == 1 { "day" } else { "days" };
+    println!(
+        "\n{correct} correct, {wrong} wrong, {missing} missing, {parse_errors} {} with a parse error, {unreadable} {} with an unreadable input.",
+        days(parse_errors),
+        days(unreadable)
+    );
+
+    // record the answers if asked to, they become the expected ones
+    if record {
+        if let Err(error) = expected.save(&path) {
+            eprintln!("{error}");
+            return Ok(ExitCode::FAILURE);
+        }
+        println!("The answers were recorded to `{}`.", path.display());
+        return Ok(if parse_errors + unreadable > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS });
+    }
+
+    if missing > 0 {
+        println!("Use `--record` to record the missing answers.");
+    }
+
+    Ok(if wrong + missing + parse_errors + unreadable > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
@@ -0,0 +1,88 @@
+use std::time::Duration;
+use aoc_common::{
+    output::{solve_part, solve_timed, Answer},
+    random::Rng,
+    timings::{self, Timings},
+    ParseError, Solution,
+};
+use aoc_visual::{parse_frames, Frames, Visualize};
+use crate::bench::{measure, Measure};
+
+/// Solves both parts of a day, returning the time taken to parse the input and the answers of both parts.
+pub type SolveFn = fn(&str) -> Result<(Duration, [Answer; 2]), ParseError>;
+
+/// Solves a single part of a day (`1` or `2`), returning the time taken to parse the input and the answer of the part.
+pub type SolvePartFn = fn(&str, u8) -> Result<(Duration, Answer), ParseError>;
+
+/// Solves both parts of a day, also measuring the time taken and the heap used by each phase.
+pub type TimingsFn = fn(&str) -> Result<Timings, ParseError>;
+
+/// Measures the time taken by each phase of a day, running them the given number of times.
+pub type MeasureFn = fn(&str, usize) -> Result<Measure, ParseError>;
+
+/// Generates a random puzzle input of a day, of the given size.
+pub type GenerateFn = fn(&mut Rng, usize) -> String;
+
+/// Creates the frames animating the simulation of a day from its puzzle input.
+pub type VisualizeFn = fn(&str) -> Result<Frames, ParseError>;
+
+/// A day of the challenge, along with the function solving it.
+/// 
+/// # Fields
+/// 
+/// * `number` - The number of the day, starting at 1.
+/// * `solve` - Solves both parts of the day using the given puzzle input, fails if the input can't be parsed.
+///   Returns the time taken to parse the input, and the answers of both parts.
+/// * `solve_part` - Solves a single part of the day (`1` or `2`) using the given puzzle input, like `solve`.
+/// * `timings` - Solves both parts of the day like `solve`, also measuring the heap used by each phase.
+/// * `measure` - Measures the time taken by each phase of the day, running them the given number of times.
+/// * `generate` - Generates a random puzzle input of the given size, whose meaning depends on the day.
+/// * `visualize` - Creates the frames animating the simulation of the day, for the days that have one.
+pub struct Day {
+    pub number: u8,
+    pub solve: SolveFn,
+    pub solve_part: SolvePartFn,
+    pub timings: TimingsFn,
+    pub measure: MeasureFn,
+    pub generate: GenerateFn,
+    pub visualize: Option<VisualizeFn>,
+}
+
+impl Day {
+    /// Creates the day corresponding to the given solution, and to the generator of its inputs.
+    const fn of<S: Solution>(generate: GenerateFn) -> Self {
+        Self {
+            number: S::DAY,
+            solve: solve_timed::<S>,
+            solve_part: solve_part::<S>,
+            timings: timings::measure::<S>,
+            measure: measure::<S>,
+            generate,
+            visualize: None,
+        }
+    }
+
+    /// Adds the animation of the simulation of the day.
+    const fn visualized<V: Visualize>(self) -> Self {
+        Self { visualize: Some(parse_frames::<V>), ..self }
+    }
+}
+
+/// Every day of the challenge, in order.
+pub const DAYS: [Day; 15] = [
+    Day::of::<day_01::Day01>(day_01::generator::generate_input),
+    Day::of::<day_02::Day02>(day_02::generator::generate_input),
+    Day::of::<day_03::Day03>(day_03::generator::generate_input),
+    Day::of::<day_04::Day04>(day_04::generator::generate_input),
+    Day::of::<day_05::Day05>(day_05::generator::generate_input).visualized::<day_05::Day05>(),
+    Day::of::<day_06::Day06>(day_06::generator::generate_input),
+    Day::of::<day_07::Day07>(day_07::generator::generate_input),
+    Day::of::<day_08::Day08>(day_08::generator::generate_input),
+    Day::of::<day_09::Day09>(day_09::generator::generate_input).visualized::<day_09::Day09>(),
+    Day::of::<day_10::Day10>(day_10::generator::generate_input).visualized::<day_10::Day10>(),
+    Day::of::<day_11::Day11>(day_11::generator::generate_input),
+    Day::of::<day_12::Day12>(day_12::generator::generate_input).visualized::<day_12::Day12>(),
+    Day::of::<day_13::Day13>(day_13::generator::generate_input),
+    Day::of::<day_14::Day14>(day_14::generator::generate_input).visualized::<day_14::Day14>(),
+    Day::of::<day_15::Day15>(day_15::generator::generate_input),
+];
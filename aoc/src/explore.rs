@@ -0,0 +1,614 @@
+use std::{
+    collections::VecDeque,
+    io::{self, Write},
+    panic::{self, AssertUnwindSafe, PanicHookInfo},
+    process::ExitCode,
+    sync::{mpsc::{self, Receiver, TryRecvError}, Arc},
+    thread,
+    time::{Duration, Instant},
+};
+use aoc_common::{load_input, output::Answer};
+use aoc_visual::{Color, Frame, Frames};
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::{Attribute, Color as TerminalColor, Print, ResetColor, SetAttribute, SetForegroundColor},
+    terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use crate::{days::{Day, VisualizeFn}, options::Options, parallel::panic_message};
+
+/// How often the explorer checks whether a part finished, or whether the next frame of an animation is due.
+const TICK: Duration = Duration::from_millis(20);
+
+/// How long each frame of an animation is displayed, when it plays on its own.
+const FRAME_DELAY: Duration = Duration::from_millis(50);
+
+/// The number of cells of the frames kept in memory, so stepping back through an animation doesn't compute it again from the start.
+/// The frames older than that are forgotten, and computed again when they are needed.
+pub const HISTORY_CELLS: usize = 20_000_000;
+
+/// The width of the list of days, on the left of the screen.
+const LIST_WIDTH: u16 = 20;
+
+/// The color of the text explaining the keys, and of the parts that weren't run.
+const HINT_COLOR: Color = Color::GRAY;
+
+/// The name of the threads running the parts, whose panics are shown in the result pane instead of being printed.
+const PART_THREAD: &str = "explorer part";
+
+/// A function called when a thread panics.
+type PanicHook = Box<dyn Fn(&PanicHookInfo<'_>) + Sync + Send + 'static>;
+
+/// Browses the selected days in an interactive terminal interface: their inputs, the answers of their parts, and their animations.
+///
+/// The explorer doesn't take any option.
+///
+/// # Returns
+///
+/// A failure exit code if the terminal couldn't be used.
+pub fn explore(days: &[&'static Day], _options: &Options) -> Result<ExitCode, String> {
+    let mut explorer = Explorer::new(days);
+
+    if let Err(error) = run(&mut explorer) {
+        eprintln!("Unable to run the explorer: {error}");
+        return Ok(ExitCode::FAILURE);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+/// What is known about a part of a day.
+enum PartState {
+    /// The part wasn't run yet.
+    NotRun,
+    /// The part is running on its own thread, which sends its outcome when it is done.
+    Running(Receiver<Result<(Duration, Answer), String>>),
+    /// The part is done: the time taken to parse the input and its answer, or why it failed.
+    Done(Result<(Duration, Answer), String>),
+}
+
+/// A day, along with its input and its parts.
+///
+/// # Fields
+///
+/// * `day` - The day and the functions solving it.
+/// * `input` - The puzzle input of the day, or why it couldn't be read.
+/// * `parts` - What is known about each part.
+/// * `scroll` - The first line of the input that is displayed.
+struct DayState {
+    day: &'static Day,
+    input: Result<String, String>,
+    parts: [PartState; 2],
+    scroll: usize,
+}
+
+/// The animation of the simulation of a day, whose frames are computed as they are needed.
+///
+/// # Fields
+///
+/// * `visualize` - Creates the frames of the animation, used to start it over.
+/// * `input` - The puzzle input the animation is about.
+/// * `frames` - The frames that weren't computed yet.
+/// * `history` - The last frames that were computed, up to the current one.
+/// * `first` - The number of the first frame of the history, starting at 0.
+/// * `current` - The number of the displayed frame, starting at 0.
+/// * `finished` - Whether every frame was computed.
+/// * `playing` - Whether the animation moves to the next frame on its own.
+/// * `next_frame` - When the next frame is due, if the animation is playing.
+/// * `history_cells` - The number of cells of the frames kept in the history, [`HISTORY_CELLS`] by default.
+pub struct Animation {
+    visualize: VisualizeFn,
+    input: String,
+    frames: Frames,
+    history: VecDeque<Frame>,
+    first: usize,
+    current: usize,
+    finished: bool,
+    playing: bool,
+    next_frame: Instant,
+    history_cells: usize,
+}
+
+impl Animation {
+    /// Starts the animation of a day, displaying its first frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input can't be parsed, or if the animation doesn't have any frame.
+    pub fn start(visualize: VisualizeFn, input: &str) -> Result<Self, String> {
+        let mut frames = visualize(input).map_err(|error| error.report())?;
+        let first_frame = frames.next().ok_or_else(|| "The animation doesn't have any frame.".to_owned())?;
+
+        Ok(Self {
+            visualize,
+            input: input.to_owned(),
+            frames,
+            history: VecDeque::from([first_frame]),
+            first: 0,
+            current: 0,
+            finished: false,
+            playing: false,
+            next_frame: Instant::now(),
+            history_cells: HISTORY_CELLS,
+        })
+    }
+
+    /// Changes the number of cells of the frames kept in the history, so the oldest frames are forgotten sooner or later.
+    pub fn with_history_cells(self, history_cells: usize) -> Self {
+        Self { history_cells, ..self }
+    }
+
+    /// The displayed frame.
+    pub fn frame(&self) -> &Frame {
+        &self.history[self.current - self.first]
+    }
+
+    /// The number of frames, if they were all computed.
+    pub fn frame_count(&self) -> Option<usize> {
+        self.finished.then(|| self.first + self.history.len())
+    }
+
+    /// Moves to the next frame, computing it if needed.
+    ///
+    /// # Returns
+    ///
+    /// False if the displayed frame was already the last one.
+    pub fn forward(&mut self) -> bool {
+        // the next frame may already be known, after stepping back
+        if self.current + 1 < self.first + self.history.len() {
+            self.current += 1;
+            return true;
+        }
+
+        let Some(frame) = self.frames.next() else {
+            self.finished = true;
+            self.playing = false;
+            return false;
+        };
+
+        // forget the oldest frames once the history is too big, always keeping the current one
+        let cells = frame.width() * frame.height();
+        self.history.push_back(frame);
+        self.current += 1;
+        while self.history.len() > 1 && self.history.len() * cells > self.history_cells {
+            self.history.pop_front();
+            self.first += 1;
+        }
+        true
+    }
+
+    /// Moves to the previous frame, if the displayed frame isn't the first one.
+    pub fn backward(&mut self) {
+        if self.current > 0 {
+            self.seek(self.current - 1);
+        }
+    }
+
+    /// Moves to the given frame, which comes before the current one, starting the animation over if it was forgotten.
+    pub fn seek(&mut self, target: usize) {
+        if target >= self.first {
+            self.current = target;
+            return;
+        }
+
+        let mut restarted = Self::start(self.visualize, &self.input)
+            .expect("the animation already started once with the same input")
+            .with_history_cells(self.history_cells);
+        while restarted.current < target && restarted.forward() {}
+
+        // the frames after the target are computed again when moving forward
+        restarted.playing = self.playing;
+        *self = restarted;
+    }
+}
+
+/// The state of the explorer.
+///
+/// # Fields
+///
+/// * `days` - The days that can be explored, in order.
+/// * `selected` - The index of the selected day.
+/// * `animation` - The animation being displayed, if any.
+/// * `message` - A message about the last action, such as why it failed.
+/// * `quit` - Whether the user asked to quit.
+struct Explorer {
+    days: Vec<DayState>,
+    selected: usize,
+    animation: Option<Animation>,
+    message: Option<String>,
+    quit: bool,
+}
+
+impl Explorer {
+    /// Creates the explorer of the given days, reading their inputs.
+    fn new(days: &[&'static Day]) -> Self {
+        let days = days
+            .iter()
+            .map(|&day| DayState {
+                day,
+                input: load_input(day.number, None).map_err(|error| format!("Unable to read the input: {error}")),
+                parts: [PartState::NotRun, PartState::NotRun],
+                scroll: 0,
+            })
+            .collect();
+
+        Self { days, selected: 0, animation: None, message: None, quit: false }
+    }
+
+    /// Updates the explorer after a key was pressed.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key that was pressed.
+    /// * `page` - The number of lines of the input displayed at once, to scroll one page at a time.
+    fn press(&mut self, key: KeyEvent, page: usize) {
+        self.message = None;
+
+        // ctrl-c always quits, even in an animation
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            self.quit = true;
+            return;
+        }
+
+        if let Some(animation) = &mut self.animation {
+            match key.code {
+                KeyCode::Right | KeyCode::Char('l') => {
+                    animation.playing = false;
+                    animation.forward();
+                }
+                KeyCode::Left | KeyCode::Char('h') => {
+                    animation.playing = false;
+                    animation.backward();
+                }
+                KeyCode::Char(' ') => {
+                    animation.playing = !animation.playing;
+                    animation.next_frame = Instant::now() + FRAME_DELAY;
+                }
+                KeyCode::Home => animation.seek(0),
+                KeyCode::Esc | KeyCode::Char('q') => self.animation = None,
+                _ => {}
+            }
+            return;
+        }
+
+        let state = &mut self.days[self.selected];
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => self.selected = (self.selected + 1).min(self.days.len() - 1),
+            KeyCode::PageUp => state.scroll = state.scroll.saturating_sub(page),
+            KeyCode::PageDown => {
+                let line_count = state.input.as_ref().map_or(0, |input| input.lines().count());
+                state.scroll = (state.scroll + page).min(line_count.saturating_sub(1));
+            }
+            KeyCode::Home => state.scroll = 0,
+            KeyCode::Char('1') => self.run_part(1),
+            KeyCode::Char('2') => self.run_part(2),
+            KeyCode::Char('r') => {
+                self.run_part(1);
+                self.run_part(2);
+            }
+            KeyCode::Char('v') | KeyCode::Enter => self.start_animation(),
+            KeyCode::Esc | KeyCode::Char('q') => self.quit = true,
+            _ => {}
+        }
+    }
+
+    /// Starts running a part of the selected day on its own thread, so the explorer stays responsive.
+    fn run_part(&mut self, part: u8) {
+        let state = &mut self.days[self.selected];
+        let input = match &state.input {
+            Ok(input) => input.clone(),
+            Err(error) => {
+                self.message = Some(error.clone());
+                return;
+            }
+        };
+
+        // a part isn't run twice at once
+        let part_state = &mut state.parts[part as usize - 1];
+        if matches!(part_state, PartState::Running(_)) {
+            return;
+        }
+
+        let solve_part = state.day.solve_part;
+        let (sender, receiver) = mpsc::channel();
+        let spawned = thread::Builder::new().name(PART_THREAD.to_owned()).spawn(move || {
+            let outcome = match panic::catch_unwind(AssertUnwindSafe(|| solve_part(&input, part))) {
+                Ok(Ok(answer)) => Ok(answer),
+                Ok(Err(error)) => Err(error.report()),
+                Err(panic) => Err(format!("panicked: {}", panic_message(&*panic))),
+            };
+            // the explorer may have been closed in the meantime
+            let _ = sender.send(outcome);
+        });
+        match spawned {
+            Ok(_) => *part_state = PartState::Running(receiver),
+            Err(error) => self.message = Some(format!("Unable to run the part: {error}")),
+        }
+    }
+
+    /// Starts the animation of the selected day, if it has one.
+    fn start_animation(&mut self) {
+        let state = &self.days[self.selected];
+        let Some(visualize) = state.day.visualize else {
+            self.message = Some(format!("Day {} doesn't have a visualization.", state.day.number));
+            return;
+        };
+
+        let animation = state.input.clone().and_then(|input| Animation::start(visualize, &input));
+        match animation {
+            Ok(animation) => self.animation = Some(animation),
+            Err(error) => self.message = Some(error),
+        }
+    }
+
+    /// Collects the outcomes of the parts that finished, and moves the animation forward if it is playing.
+    ///
+    /// # Returns
+    ///
+    /// True if something changed, and the screen must be drawn again.
+    fn tick(&mut self) -> bool {
+        let mut changed = false;
+
+        for part_state in self.days.iter_mut().flat_map(|state| &mut state.parts) {
+            if let PartState::Running(receiver) = part_state {
+                match receiver.try_recv() {
+                    Ok(outcome) => *part_state = PartState::Done(outcome),
+                    Err(TryRecvError::Disconnected) => *part_state = PartState::Done(Err("the thread stopped".to_owned())),
+                    Err(TryRecvError::Empty) => continue,
+                }
+                changed = true;
+            }
+        }
+
+        if let Some(animation) = &mut self.animation {
+            if animation.playing && Instant::now() >= animation.next_frame {
+                animation.next_frame += FRAME_DELAY;
+                changed |= animation.forward();
+            }
+        }
+
+        changed
+    }
+}
+
+/// Restores the terminal when it is dropped, even if the explorer panics.
+///
+/// # Fields
+///
+/// * `previous_hook` - The panic hook replaced while the explorer runs, restored along with the terminal.
+struct TerminalGuard {
+    previous_hook: Option<Arc<PanicHook>>,
+}
+
+impl TerminalGuard {
+    /// Switches the terminal to the alternate screen, and reads the keys as soon as they are pressed.
+    ///
+    /// The panics of the parts would be printed over the screen, so they are silenced: the result pane shows them.
+    /// The other panics are still given to the previous hook.
+    fn enter() -> io::Result<Self> {
+        let previous_hook: Arc<PanicHook> = Arc::new(panic::take_hook());
+        let hook = Arc::clone(&previous_hook);
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().name() != Some(PART_THREAD) {
+                hook(info);
+            }
+        }));
+        let guard = Self { previous_hook: Some(previous_hook) };
+
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(guard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        // there is nothing left to do if the terminal can't be restored
+        let _ = execute!(io::stdout(), ResetColor, Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+
+        // the hook can't be changed while panicking, the silent one only holds back the panics of the parts anyway
+        if !thread::panicking() {
+            drop(panic::take_hook());
+            if let Some(Ok(previous_hook)) = self.previous_hook.take().map(Arc::try_unwrap) {
+                panic::set_hook(previous_hook);
+            }
+        }
+    }
+}
+
+/// Runs the explorer until the user quits.
+fn run(explorer: &mut Explorer) -> io::Result<()> {
+    let _guard = TerminalGuard::enter()?;
+    let mut stdout = io::stdout();
+
+    let mut redraw = true;
+    while !explorer.quit {
+        if redraw {
+            draw(explorer, &mut stdout)?;
+            redraw = false;
+        }
+
+        // wait for a key, while checking on the running parts and the animation
+        if event::poll(TICK)? {
+            match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
+                    let (_, height) = terminal::size()?;
+                    explorer.press(key, input_height(height));
+                    redraw = true;
+                }
+                Event::Resize(_, _) => redraw = true,
+                _ => {}
+            }
+        }
+        redraw |= explorer.tick();
+    }
+
+    Ok(())
+}
+
+/// The number of lines of the input displayed at once, below the answers.
+fn input_height(height: u16) -> usize {
+    (height as usize).saturating_sub(11).max(1)
+}
+
+/// Converts a color of a frame into a color of the terminal.
+fn terminal_color(color: Color) -> TerminalColor {
+    TerminalColor::Rgb { r: color.red, g: color.green, b: color.blue }
+}
+
+/// Cuts a line so it fits in the given number of columns.
+fn fit(line: &str, width: usize) -> String {
+    line.chars().take(width).collect()
+}
+
+/// Draws the whole screen: the list of days on the left, the selected day or its animation on the right, and the keys at the bottom.
+fn draw(explorer: &Explorer, output: &mut impl Write) -> io::Result<()> {
+    let (width, height) = terminal::size()?;
+    let pane_x = LIST_WIDTH + 2;
+    let pane_width = width.saturating_sub(pane_x) as usize;
+
+    queue!(output, Clear(ClearType::All), MoveTo(0, 0), SetAttribute(Attribute::Bold), Print("Advent of Code 2022"), SetAttribute(Attribute::Reset))?;
+
+    // the list of days, with the state of their parts and whether they have an animation
+    for (index, state) in explorer.days.iter().enumerate() {
+        let marker = if index == explorer.selected { '>' } else { ' ' };
+        let parts: String = state
+            .parts
+            .iter()
+            .map(|part| match part {
+                PartState::NotRun => '·',
+                PartState::Running(_) => '…',
+                PartState::Done(Ok(_)) => '✓',
+                PartState::Done(Err(_)) => '✗',
+            })
+            .collect();
+        let animated = if state.day.visualize.is_some() { "▶" } else { "" };
+        let line = format!("{marker} Day {:02}  {parts} {animated}", state.day.number);
+
+        if index == explorer.selected {
+            queue!(output, SetAttribute(Attribute::Reverse))?;
+        }
+        queue!(output, MoveTo(0, index as u16 + 2), Print(fit(&line, LIST_WIDTH as usize)), SetAttribute(Attribute::Reset))?;
+    }
+
+    // the selected day, or its animation
+    match &explorer.animation {
+        Some(animation) => draw_animation(animation, explorer.days[explorer.selected].day, output, pane_x, pane_width, height)?,
+        None => draw_day(&explorer.days[explorer.selected], output, pane_x, pane_width, height)?,
+    }
+
+    // the message of the last action and the keys, at the bottom of the screen
+    if let Some(message) = &explorer.message {
+        for (row, line) in message.lines().rev().enumerate() {
+            let y = (height as usize).saturating_sub(3 + row) as u16;
+            queue!(output, MoveTo(0, y), SetForegroundColor(terminal_color(Color::RED)), Print(fit(line, width as usize)), ResetColor)?;
+        }
+    }
+    let keys = match &explorer.animation {
+        Some(_) => "←/→ step  space play/pause  home first frame  esc back",
+        None => "↑/↓ select  1/2 run a part  r run both  v animate  pgup/pgdn scroll  q quit",
+    };
+    queue!(
+        output,
+        MoveTo(0, height.saturating_sub(1)),
+        SetForegroundColor(terminal_color(HINT_COLOR)),
+        Print(fit(keys, width as usize)),
+        ResetColor
+    )?;
+
+    output.flush()
+}
+
+/// Draws the answers of the parts of a day, followed by its input.
+fn draw_day(state: &DayState, output: &mut impl Write, x: u16, width: usize, height: u16) -> io::Result<()> {
+    let mut row = 2;
+    queue!(output, MoveTo(x, row), SetAttribute(Attribute::Bold), Print(format!("Day {}", state.day.number)), SetAttribute(Attribute::Reset))?;
+    row += 2;
+
+    // the answers, multi-line answers start on their own line
+    for (index, part) in state.parts.iter().enumerate() {
+        let (text, color) = match part {
+            PartState::NotRun => (format!("Part {}: press {} to run it", index + 1, index + 1), HINT_COLOR),
+            PartState::Running(_) => (format!("Part {}: running…", index + 1), HINT_COLOR),
+            PartState::Done(Ok((parse_time, answer))) => {
+                let timings = format!("(parsed in {parse_time:.2?}, solved in {:.2?})", answer.elapsed);
+                if answer.answer.contains('\n') {
+                    (format!("Part {}: {timings}\n{}", index + 1, answer.answer), Color::WHITE)
+                } else {
+                    (format!("Part {}: {}  {timings}", index + 1, answer.answer), Color::WHITE)
+                }
+            }
+            PartState::Done(Err(error)) => (format!("Part {}: {error}", index + 1), Color::RED),
+        };
+
+        queue!(output, SetForegroundColor(terminal_color(color)))?;
+        for line in text.lines() {
+            queue!(output, MoveTo(x, row), Print(fit(line, width)))?;
+            row += 1;
+        }
+        queue!(output, ResetColor)?;
+    }
+    row += 1;
+
+    // the input, from the scrolled line until the bottom of the screen
+    match &state.input {
+        Ok(input) => {
+            let line_count = input.lines().count();
+            queue!(
+                output,
+                MoveTo(x, row),
+                SetForegroundColor(terminal_color(HINT_COLOR)),
+                Print(fit(&format!("Input: {line_count} lines, from line {}", state.scroll + 1), width)),
+                ResetColor
+            )?;
+            row += 1;
+
+            let last_row = height.saturating_sub(2);
+            for line in input.lines().skip(state.scroll) {
+                if row >= last_row {
+                    break;
+                }
+                queue!(output, MoveTo(x, row), Print(fit(line, width)))?;
+                row += 1;
+            }
+        }
+        Err(error) => {
+            queue!(output, MoveTo(x, row), SetForegroundColor(terminal_color(Color::RED)), Print(fit(error, width)), ResetColor)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Draws the displayed frame of an animation, cut to fit the screen.
+fn draw_animation(animation: &Animation, day: &Day, output: &mut impl Write, x: u16, width: usize, height: u16) -> io::Result<()> {
+    let count = animation.frame_count().map_or_else(String::new, |count| format!("/{count}"));
+    let state = if animation.playing { "playing" } else { "paused" };
+    let title = format!("Day {}, frame {}{count} ({state})", day.number, animation.current + 1);
+    queue!(output, MoveTo(x, 2), SetAttribute(Attribute::Bold), Print(fit(&title, width)), SetAttribute(Attribute::Reset))?;
+
+    // draw each row of the frame, only changing the color when needed
+    let frame = animation.frame();
+    let rows = frame.height().min(height.saturating_sub(6) as usize);
+    for y in 0..rows {
+        queue!(output, MoveTo(x, y as u16 + 4))?;
+
+        let mut current_color = None;
+        let mut text = String::new();
+        for x in 0..frame.width().min(width) {
+            let cell = frame[(x, y)];
+            if current_color != Some(cell.color) {
+                queue!(output, Print(&text))?;
+                text.clear();
+                queue!(output, SetForegroundColor(terminal_color(cell.color)))?;
+                current_color = Some(cell.color);
+            }
+            text.push(cell.symbol);
+        }
+        queue!(output, Print(&text), ResetColor)?;
+    }
+
+    Ok(())
+}
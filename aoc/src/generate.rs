@@ -0,0 +1,73 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+    process::ExitCode,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use aoc_common::random::Rng;
+use crate::{days::Day, options::Options};
+
+/// The size of the generated inputs, when none is given.
+const DEFAULT_SIZE: usize = 100;
+
+/// Generates random puzzle inputs for the selected days, to stress-test them.
+///
+/// # Options
+///
+/// * `--size N` - The size of the inputs (100 by default), its meaning depends on the day (lines, directories, side of the map, ...).
+/// * `--seed N` - The seed of the random generator, a random one is picked by default. The same seed always generates the same inputs.
+/// * `--output DIR` - Writes the inputs to `DIR/day_NN.txt` instead of displaying them, needed when several days are selected.
+///
+/// # Returns
+///
+/// A failure exit code if an input couldn't be written.
+pub fn generate(days: &[&Day], options: &Options) -> Result<ExitCode, String> {
+    let size = options.parse_value("size", DEFAULT_SIZE)?;
+    let seed = options.parse_value("seed", random_seed())?;
+    let output = options.value("output").map(Path::new);
+
+    // the inputs of several days can't be told apart on the standard output
+    if output.is_none() && days.len() > 1 {
+        return Err("The inputs of several days can only be generated in a directory, using `--output DIR`.".to_owned());
+    }
+
+    // the seed is needed to generate the same inputs again
+    eprintln!("Generating inputs of size {size} with the seed {seed}.");
+
+    if let Some(directory) = output {
+        if let Err(error) = fs::create_dir_all(directory) {
+            eprintln!("Unable to create the directory `{}`: {error}", directory.display());
+            return Ok(ExitCode::FAILURE);
+        }
+    }
+
+    for day in days {
+        // every day starts from the same seed, so its input doesn't depend on the other selected days
+        let input = (day.generate)(&mut Rng::new(seed), size);
+
+        let result = match output {
+            Some(directory) => {
+                let path = directory.join(format!("day_{:02}.txt", day.number));
+                fs::write(&path, input).map_err(|error| format!("Unable to write `{}`: {error}", path.display()))
+            }
+            None => io::stdout()
+                .write_all(input.as_bytes())
+                .map_err(|error| format!("Unable to write the input: {error}")),
+        };
+
+        if let Err(error) = result {
+            eprintln!("{error}");
+            return Ok(ExitCode::FAILURE);
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Picks a seed from the current time.
+fn random_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+}
@@ -0,0 +1,23 @@
+pub mod answers;
+pub mod bench;
+pub mod check;
+pub mod days;
+pub mod explore;
+pub mod generate;
+pub mod options;
+pub mod parallel;
+pub mod selection;
+pub mod table;
+pub mod visualize;
+
+use aoc_common::load_input;
+use days::Day;
+
+/// Reads the input of the day.
+/// 
+/// # Errors
+/// 
+/// Returns why the input can't be read, such as a missing file, so the other days can still run.
+pub fn read_input(day: &Day) -> Result<String, String> {
+    load_input(day.number, None).map_err(|error| format!("Unable to read the input of day {}: {error}", day.number))
+}
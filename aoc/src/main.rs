@@ -0,0 +1,234 @@
+use std::{
+    env,
+    num::NonZeroUsize,
+    process::ExitCode,
+    thread,
+    time::Duration,
+};
+use aoc::{
+    bench, check,
+    days::Day,
+    explore, generate,
+    options::Options,
+    parallel::{self, Outcome},
+    read_input,
+    selection::parse_selection,
+    table::Table,
+    visualize,
+};
+use aoc_common::{memory::{format_bytes, CountingAllocator}, print_record, Format, ParseError};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// How long a day can run before the runner gives up on it, in seconds.
+const DEFAULT_TIMEOUT: u64 = 60;
+
+/// Explains how to use the runner.
+const USAGE: &str = "Usage:
+    aoc run <DAYS>      runs the selected days and displays their answers
+    aoc bench <DAYS>    measures the time taken by each phase of the selected days
+    aoc check <DAYS>    compares the answers of the selected days to the expected ones
+    aoc generate <DAYS> generates random inputs for the selected days
+    aoc visualize <DAY> animates the simulation of the selected day, in the terminal or in a GIF
+    aoc explore <DAYS>  browses the inputs, the answers and the animations of the selected days interactively
+
+The days are selected using `all`, a single day (`7`), or a range of days (`3..9`, both included).
+
+Options of `run`:
+    --format FORMAT     displays the answers as a table (`text`, by default),
+                        as one JSON object per answer (`json`), or as tab-separated values (`raw`)
+    --timings           also displays the time taken and the peak heap used by each phase
+    --jobs N            runs N days at once (one per CPU by default, and only one with `--timings`)
+    --timeout SECONDS   gives up on the days running for longer than that (60 by default, 0 for no limit)
+
+Options of `bench`:
+    --iterations N      runs each phase N times (10 by default), fewer times if the day is slow
+    --save PATH         saves the results as a baseline
+    --baseline PATH     compares the results to a saved baseline
+
+Options of `check`:
+    --answers PATH      reads the expected answers from PATH (`answers.toml` by default)
+    --record            records the current answers as the expected ones
+
+Options of `generate`:
+    --size N            the size of the inputs (100 by default), its meaning depends on the day
+    --seed N            the seed of the random generator (random by default), the same seed generates the same inputs
+    --output DIR        writes the inputs to `DIR/day_NN.txt`, needed when several days are selected
+
+Options of `visualize`:
+    --gif PATH          writes the animation to a GIF instead of displaying it in the terminal
+    --delay MS          displays each frame for MS milliseconds (50 by default)
+    --every N           only shows one frame out of N, to speed up long animations
+    --scale N           draws each cell of a GIF as a square of N by N pixels (4 by default)
+
+Examples:
+    aoc run 7                               runs the 7th day
+    aoc run all                             runs every day
+    aoc run 3..9                            runs every day from the 3rd to the 9th (both included)
+    aoc run all --format json               runs every day and displays the answers as JSON
+    aoc run all --jobs 4 --timeout 10       runs 4 days at once, giving up on the days taking more than 10 seconds
+    aoc run 7 --timings                     runs the 7th day and displays the time and memory taken by each phase
+    aoc bench all --save baseline.txt       benchmarks every day and saves the results
+    aoc bench 6 --baseline baseline.txt     benchmarks the 6th day and compares it to the saved results
+    aoc check all                           checks that no day changed its answers
+    aoc check 7 --record                    records the current answers of the 7th day as the expected ones
+    aoc generate 8 --size 10000 --seed 42   generates a forest of 10,000 by 10,000 trees
+    aoc generate all --output big           generates an input for every day in the `big` directory
+    aoc visualize 14 --every 5              animates the sand of the 14th day in the terminal, 5 units of sand at a time
+    aoc visualize 10 --gif crt.gif          writes the animation of the crt of the 10th day to a GIF
+    aoc explore all                         browses every day in the terminal
+
+The inputs are read from the `inputs` directory, or from the directory given by the `AOC_INPUT_DIR` environment variable.";
+
+/// Runs the selected days and displays their answers in a single table.
+fn main() -> ExitCode {
+    // collect the arguments, skipping the name of the binary
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    // find the command, and the days it applies to
+    let [command, selection, options @ ..] = args.as_slice() else {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    };
+
+    // execute the command
+    let result = parse_selection(selection).and_then(|days| match command.as_str() {
+        "run" => run(&days, &Options::parse(options, &["format", "jobs", "timeout"], &["timings"])?),
+        "bench" => bench::bench(&days, &Options::parse(options, &["iterations", "save", "baseline"], &[])?),
+        "check" => check::check(&days, &Options::parse(options, &["answers"], &["record"])?),
+        "generate" => generate::generate(&days, &Options::parse(options, &["size", "seed", "output"], &[])?),
+        "visualize" => visualize::visualize(&days, &Options::parse(options, &["gif", "delay", "every", "scale"], &[])?),
+        "explore" => explore::explore(&days, &Options::parse(options, &[], &[])?),
+        _ => Err(format!("Unknown command `{command}`.")),
+    });
+
+    // the commands only fail when their arguments are invalid
+    result.unwrap_or_else(|error| {
+        eprintln!("{error}\n\n{USAGE}");
+        ExitCode::from(2)
+    })
+}
+
+/// Why a day couldn't be solved.
+enum Failure {
+    /// The input couldn't be read, with the reason.
+    Unreadable(String),
+    /// The input couldn't be parsed.
+    Parse(ParseError),
+}
+
+/// Runs the selected days and displays their answers in a single table, or in a machine-readable format.
+/// 
+/// The days run concurrently, but their answers are displayed in order.
+/// 
+/// # Options
+/// 
+/// * `--format FORMAT` - How the answers are displayed (`text`, `json` or `raw`).
+/// * `--timings` - Also displays the time taken and the peak heap used by each phase.
+/// * `--jobs N` - How many days run at once, one per CPU by default (and only one at a time with `--timings`, as the heap is shared by every day).
+/// * `--timeout SECONDS` - How long a day can run before giving up on it (60 seconds by default, 0 for no limit).
+fn run(days: &[&'static Day], options: &Options) -> Result<ExitCode, String> {
+    let format = options.parse_value("format", Format::Text)?;
+    let timings = options.flag("timings");
+    let default_jobs = if timings { 1 } else { thread::available_parallelism().map_or(1, NonZeroUsize::get) };
+    let jobs = options.parse_value("jobs", default_jobs)?;
+
+    // the heap used by a day can only be told apart from the heap used by the others when it runs on its own
+    if timings && jobs > 1 {
+        return Err(format!("The option `--timings` measures the heap of a single day at a time, it can't be used with `--jobs {jobs}`."));
+    }
+    let timeout_seconds = options.parse_value("timeout", DEFAULT_TIMEOUT)?;
+    let timeout = (timeout_seconds > 0).then(|| Duration::from_secs(timeout_seconds));
+
+    // create the table that will hold the answers, and the details of each phase if asked to
+    let mut table = if timings {
+        Table::new(&["Day", "Part 1", "Part 2", "Parse", "Part 1 time", "Part 2 time", "Time", "Peak heap"])
+    } else {
+        Table::new(&["Day", "Part 1", "Part 2", "Time"])
+    };
+
+    // remember if a day couldn't be solved, to exit with an error code
+    let mut failed = false;
+
+    // solve a day, measuring the heap used by each phase only when it is displayed
+    let solve = move |day: &'static Day| {
+        let input = read_input(day).map_err(Failure::Unreadable)?;
+        if timings {
+            (day.timings)(&input).map(|timings| (timings.parse.elapsed, timings.answers.clone(), Some(timings)))
+        } else {
+            (day.solve)(&input).map(|(parse_time, answers)| (parse_time, answers, None))
+        }
+        .map_err(Failure::Parse)
+    };
+
+    // run the days concurrently, their outcomes arrive in order
+    parallel::run_parallel(days, jobs, timeout, solve, |day, outcome| {
+        // if a day couldn't be solved, show why and keep running the other days
+        let (parse_time, answers, timings) = match outcome {
+            Outcome::Finished(Ok(solved)) => solved,
+            failure => {
+                let status = match failure {
+                    Outcome::Finished(Err(Failure::Unreadable(error))) => {
+                        eprintln!("{error}\n");
+                        "unreadable input"
+                    }
+                    Outcome::Finished(Err(Failure::Parse(error))) => {
+                        eprintln!("{}\n", error.report());
+                        "parse error"
+                    }
+                    Outcome::Panicked(message) => {
+                        eprintln!("Day {} panicked: {message}\n", day.number);
+                        "panicked"
+                    }
+                    _ => {
+                        eprintln!("Day {} didn't finish within {timeout_seconds} seconds.\n", day.number);
+                        "timed out"
+                    }
+                };
+                failed = true;
+                if format == Format::Text {
+                    let mut row = vec![format!("{:02}", day.number), status.to_owned(), status.to_owned()];
+                    row.resize(table.width(), String::new());
+                    table.push(row);
+                }
+                return;
+            }
+        };
+
+        // machine-readable answers are displayed as soon as they are known, followed by the details of each phase
+        if format != Format::Text {
+            for answer in &answers {
+                print_record(&answer.format(format));
+            }
+            if let Some(timings) = timings {
+                print_record(&timings.format(format));
+            }
+            return;
+        }
+
+        // add the answers to the table
+        let [part_one, part_two] = answers;
+        let total_time = parse_time + part_one.elapsed + part_two.elapsed;
+        let mut row = vec![format!("{:02}", day.number), part_one.answer, part_two.answer];
+        if let Some(timings) = timings {
+            row.extend([
+                format!("{parse_time:.2?}"),
+                format!("{:.2?}", part_one.elapsed),
+                format!("{:.2?}", part_two.elapsed),
+                format!("{total_time:.2?}"),
+                timings.total.peak_heap.map_or_else(|| "-".to_owned(), format_bytes),
+            ]);
+        } else {
+            row.push(format!("{total_time:.2?}"));
+        }
+        table.push(row);
+    });
+
+    // display the answers
+    if format == Format::Text {
+        println!("{table}");
+    }
+
+    Ok(if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
@@ -0,0 +1,69 @@
+use std::str::FromStr;
+
+/// The options given on the command line after the selection of days, such as `--iterations 20`.
+/// 
+/// # Fields
+/// 
+/// * `values` - The name of each option that was given, along with its value (`None` for flags).
+pub struct Options {
+    values: Vec<(String, Option<String>)>,
+}
+
+impl Options {
+    /// Parses the options of a command.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `args` - The arguments to parse.
+    /// * `valued` - The names of the options expecting a value (`--name value`).
+    /// * `flags` - The names of the options without a value (`--name`).
+    pub fn parse(args: &[String], valued: &[&str], flags: &[&str]) -> Result<Self, String> {
+        let mut values = vec![];
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            // every argument must be an option
+            let name = arg
+                .strip_prefix("--")
+                .ok_or_else(|| format!("Unexpected argument `{arg}`."))?;
+
+            // find the value of the option, if it expects one
+            if valued.contains(&name) {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("The option `--{name}` expects a value."))?;
+                values.push((name.to_owned(), Some(value.to_owned())));
+            } else if flags.contains(&name) {
+                values.push((name.to_owned(), None));
+            } else {
+                return Err(format!("Unknown option `--{name}`."));
+            }
+        }
+
+        Ok(Self { values })
+    }
+
+    /// Returns the value of the option, if it was given (the last one wins if it was given several times).
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values
+            .iter()
+            .rev()
+            .find(|(option, _)| option == name)
+            .and_then(|(_, value)| value.as_deref())
+    }
+
+    /// Returns true if the flag was given.
+    pub fn flag(&self, name: &str) -> bool {
+        self.values.iter().any(|(option, _)| option == name)
+    }
+
+    /// Parses the value of the option, or returns the default value if it wasn't given.
+    pub fn parse_value<T: FromStr>(&self, name: &str, default: T) -> Result<T, String> {
+        match self.value(name) {
+            Some(value) => value
+                .parse()
+                .map_err(|_| format!("`{value}` is not a valid value for the option `--{name}`.")),
+            None => Ok(default),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Arc},
+    thread,
+    time::{Duration, Instant},
+};
+
+/// How the task of an item ended.
+#[derive(Debug)]
+pub enum Outcome<T> {
+    /// The task returned a result.
+    Finished(T),
+    /// The task panicked, with the message of the panic.
+    Panicked(String),
+    /// The task didn't finish in time.
+    TimedOut,
+}
+
+/// A task that was started and whose outcome isn't known yet.
+///
+/// # Fields
+///
+/// * `index` - The index of the item the task is about.
+/// * `deadline` - When the task times out, `None` if it can run for as long as it needs.
+struct Running {
+    index: usize,
+    deadline: Option<Instant>,
+}
+
+/// Runs a task for each item on its own thread, with at most `jobs` tasks running at once.
+///
+/// A thread can't be stopped from the outside, so a task that times out is left running in the background until the runner exits,
+/// and no longer counts towards `jobs`. Its result is ignored if it ever finishes.
+///
+/// # Arguments
+///
+/// * `items` - The items to run the task for.
+/// * `jobs` - The maximum number of tasks running at once (at least 1).
+/// * `timeout` - How long each task can run, `None` for no limit.
+/// * `task` - Computes the result of an item.
+/// * `on_outcome` - Receives the outcome of each item, in the order of the items,
+///   as soon as the outcomes of the item and of all the items before it are known.
+pub fn run_parallel<I, T>(
+    items: &[I],
+    jobs: usize,
+    timeout: Option<Duration>,
+    task: impl Fn(I) -> T + Send + Sync + 'static,
+    mut on_outcome: impl FnMut(&I, Outcome<T>),
+) where
+    I: Clone + Send + 'static,
+    T: Send + 'static,
+{
+    let task = Arc::new(task);
+    let (sender, receiver) = mpsc::channel();
+
+    // the outcome of each item, kept until the outcomes of the items before it are reported
+    let mut outcomes: Vec<Option<Outcome<T>>> = items.iter().map(|_| None).collect();
+    let mut running: Vec<Running> = vec![];
+    let mut next_to_start = 0;
+    let mut next_to_report = 0;
+
+    while next_to_report < items.len() {
+        // start tasks until every job is busy
+        while running.len() < jobs.max(1) && next_to_start < items.len() {
+            let index = next_to_start;
+            let item = items[index].clone();
+            let task = Arc::clone(&task);
+            let sender = sender.clone();
+            thread::spawn(move || {
+                let outcome = match panic::catch_unwind(AssertUnwindSafe(|| task(item))) {
+                    Ok(result) => Outcome::Finished(result),
+                    Err(panic) => Outcome::Panicked(panic_message(&*panic)),
+                };
+                // the receiver is gone if the runner stopped waiting, there is nobody left to tell
+                let _ = sender.send((index, outcome));
+            });
+
+            running.push(Running { index, deadline: timeout.map(|timeout| Instant::now() + timeout) });
+            next_to_start += 1;
+        }
+
+        // wait for a task to finish, or for the closest deadline to pass
+        let closest_deadline = running.iter().filter_map(|task| task.deadline).min();
+        let finished = match closest_deadline {
+            Some(deadline) => receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())).ok(),
+            None => receiver.recv().ok(),
+        };
+
+        match finished {
+            // the task may have finished after timing out, its outcome is already known then
+            Some((index, outcome)) => {
+                if let Some(position) = running.iter().position(|task| task.index == index) {
+                    running.swap_remove(position);
+                    outcomes[index] = Some(outcome);
+                }
+            }
+            // give up on the tasks whose deadline passed
+            None => {
+                let now = Instant::now();
+                running.retain(|task| match task.deadline {
+                    Some(deadline) if deadline <= now => {
+                        outcomes[task.index] = Some(Outcome::TimedOut);
+                        false
+                    }
+                    _ => true,
+                });
+            }
+        }
+
+        // report the outcomes that are now in order
+        while let Some(outcome) = outcomes.get_mut(next_to_report).and_then(Option::take) {
+            on_outcome(&items[next_to_report], outcome);
+            next_to_report += 1;
+        }
+    }
+}
+
+/// Finds the message of a panic, which is usually a string.
+pub fn panic_message(panic: &(dyn Any + Send)) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_owned()
+    }
+}
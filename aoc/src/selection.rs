@@ -0,0 +1,37 @@
+use std::ops::RangeInclusive;
+use crate::days::{Day, DAYS};
+
+/// Parses a selection of days given on the command line.
+/// 
+/// A selection is either:
+/// * `all` - every day of the challenge.
+/// * `7` - a single day.
+/// * `3..9` or `3..=9` - every day from the first to the last one (both included).
+pub fn parse_selection(selection: &str) -> Result<Vec<&'static Day>, String> {
+    // find the range of days the selection is about
+    let range: RangeInclusive<u8> = if selection == "all" {
+        1..=DAYS.len() as u8
+    } else if let Some((start, end)) = selection.split_once("..") {
+        let end = end.strip_prefix('=').unwrap_or(end);
+        parse_day_number(start)?..=parse_day_number(end)?
+    } else {
+        let day = parse_day_number(selection)?;
+        day..=day
+    };
+
+    // an empty range is most likely a mistake
+    if range.is_empty() {
+        return Err(format!("The selection `{selection}` does not contain any day."));
+    }
+
+    // find the days in the range
+    Ok(DAYS.iter().filter(|day| range.contains(&day.number)).collect())
+}
+
+/// Parses the number of a day, and makes sure the day exists.
+fn parse_day_number(day: &str) -> Result<u8, String> {
+    match day.trim().parse::<u8>() {
+        Ok(number) if (1..=DAYS.len() as u8).contains(&number) => Ok(number),
+        _ => Err(format!("`{day}` is not a valid day, expected a number between 1 and {}.", DAYS.len())),
+    }
+}
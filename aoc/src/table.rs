@@ -0,0 +1,75 @@
+use std::fmt::{Display, Formatter};
+
+/// A table that can be displayed in the terminal.
+/// Cells spanning multiple lines are displayed over multiple rows of the table.
+/// 
+/// # Fields
+/// 
+/// * `headers` - The titles of the columns.
+/// * `rows` - The content of the table, each row must have as many cells as there are headers.
+pub struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    /// Creates an empty table with the given column titles.
+    pub fn new(headers: &[&str]) -> Self {
+        Self {
+            headers: headers.iter().map(|h| h.to_string()).collect(),
+            rows: vec![],
+        }
+    }
+
+    /// The number of columns.
+    pub fn width(&self) -> usize {
+        self.headers.len()
+    }
+
+    /// Adds a row at the bottom of the table.
+    pub fn push(&mut self, row: Vec<String>) {
+        assert_eq!(row.len(), self.headers.len(), "The row doesn't have as many cells as there are columns.");
+        self.rows.push(row);
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // find the width of each column, which is the width of its widest line
+        let mut widths: Vec<usize> = self.headers.iter().map(|h| h.chars().count()).collect();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                let cell_width = cell.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+                *width = (*width).max(cell_width);
+            }
+        }
+
+        // create a separator line
+        let separator: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+        let separator = format!("+-{}-+", separator.join("-+-"));
+
+        // write a single line of the table
+        let write_line = |f: &mut Formatter<'_>, cells: Vec<&str>| -> std::fmt::Result {
+            let padded: Vec<String> = cells
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect();
+            writeln!(f, "| {} |", padded.join(" | "))
+        };
+
+        // write the headers
+        writeln!(f, "{separator}")?;
+        write_line(f, self.headers.iter().map(String::as_str).collect())?;
+        writeln!(f, "{separator}")?;
+
+        // write the rows, a row is as high as its highest cell
+        for row in &self.rows {
+            let height = row.iter().map(|c| c.lines().count()).max().unwrap_or(1).max(1);
+            for line_index in 0..height {
+                write_line(f, row.iter().map(|c| c.lines().nth(line_index).unwrap_or("")).collect())?;
+            }
+        }
+        write!(f, "{separator}")
+    }
+}
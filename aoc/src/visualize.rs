@@ -0,0 +1,71 @@
+use std::{
+    fs::File,
+    io::BufWriter,
+    process::ExitCode,
+    time::Duration,
+};
+use aoc_visual::{play, GifWriter, Terminal};
+use crate::{days::{Day, DAYS}, options::Options, read_input};
+
+/// How long each frame is displayed, in milliseconds, when no delay is given.
+const DEFAULT_DELAY: u64 = 50;
+
+/// The size of the square of pixels of each cell of a GIF, when no scale is given.
+const DEFAULT_SCALE: usize = 4;
+
+/// Animates the simulation of the selected day, in the terminal or in a GIF.
+/// 
+/// # Options
+/// 
+/// * `--gif PATH` - Writes the animation to a GIF instead of displaying it in the terminal.
+/// * `--delay MS` - How long each frame is displayed, in milliseconds.
+/// * `--every N` - Only shows one frame out of N, to speed up long animations.
+/// * `--scale N` - The size of the square of pixels of each cell of a GIF.
+pub fn visualize(days: &[&Day], options: &Options) -> Result<ExitCode, String> {
+    let delay = Duration::from_millis(options.parse_value("delay", DEFAULT_DELAY)?);
+    let every = options.parse_value("every", 1)?;
+    let scale = options.parse_value("scale", DEFAULT_SCALE)?;
+
+    // only a single day can be animated at once, and only some days have an animation
+    let [day] = days else {
+        return Err("Only a single day can be visualized at once.".to_owned());
+    };
+    let Some(frames) = day.visualize else {
+        let visualized: Vec<String> = DAYS
+            .iter()
+            .filter(|day| day.visualize.is_some())
+            .map(|day| day.number.to_string())
+            .collect();
+        return Err(format!("Day {} doesn't have a visualization, only the days {} do.", day.number, visualized.join(", ")));
+    };
+
+    // parse the input, the frames are then computed one at a time while they are shown
+    let input = match read_input(day) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("{error}");
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+    let frames = match frames(&input) {
+        Ok(frames) => frames,
+        Err(error) => {
+            eprintln!("{}", error.report());
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+
+    // show the frames
+    let result = match options.value("gif") {
+        Some(path) => File::create(path)
+            .and_then(|file| play(frames, &mut GifWriter::new(BufWriter::new(file), scale, delay), every))
+            .map(|count| println!("{count} frames were written to `{path}`.")),
+        None => play(frames, &mut Terminal::stdout(delay), every).map(|_| ()),
+    };
+
+    if let Err(error) = result {
+        eprintln!("Unable to show the animation: {error}");
+        return Ok(ExitCode::FAILURE);
+    }
+    Ok(ExitCode::SUCCESS)
+}
@@ -0,0 +1,63 @@
+use aoc::answers::ExpectedAnswers;
+
+#[test]
+fn parse() {
+    let content = "\
+# The expected answer of each part
+
+[day_01]
+part_1 = \"24000\"
+part_2 = \"45000\"
+
+[day_10]
+  part_2 = \"##..\\n..##\"
+";
+    let expected = ExpectedAnswers::parse(content).unwrap();
+    assert_eq!(expected.get(1, 1), Some("24000"));
+    assert_eq!(expected.get(1, 2), Some("45000"));
+    assert_eq!(expected.get(10, 2), Some("##..\n..##"));
+
+    // the parts that weren't recorded have no expected answer
+    assert_eq!(expected.get(10, 1), None);
+    assert_eq!(expected.get(2, 1), None);
+}
+
+#[test]
+fn invalid_answers() {
+    let invalid_contents = [
+        // the answer doesn't belong to a day
+        ("part_1 = \"1\"", 1),
+        // the table isn't a day
+        ("[day_one]\npart_1 = \"1\"", 1),
+        // the part doesn't exist
+        ("[day_01]\npart_3 = \"1\"", 2),
+        // the line isn't an answer
+        ("[day_01]\npart_1 \"1\"", 2),
+        // the answer isn't a string, or uses an unsupported escape sequence
+        ("[day_01]\npart_1 = 1", 2),
+        ("[day_01]\npart_1 = \"", 2),
+        ("[day_01]\n\npart_1 = \"\\u0041\"", 3),
+    ];
+
+    for (content, line) in invalid_contents {
+        let error = ExpectedAnswers::parse(content).expect_err(content);
+        assert_eq!(error.line, Some(line), "content: {content}");
+    }
+}
+
+#[test]
+fn record() {
+    let mut expected = ExpectedAnswers::default();
+    expected.set(7, 2, "1,234".to_owned());
+    expected.set(1, 1, "24000".to_owned());
+    expected.set(10, 2, "a \"quoted\\path\"\n\tdone".to_owned());
+    expected.set(1, 1, "25000".to_owned());
+
+    // the days are recorded in order, and the recorded answers replace the previous ones
+    let content = expected.to_string();
+    assert!(content.starts_with('#'));
+    assert!(content.ends_with("\n[day_01]\npart_1 = \"25000\"\n\n[day_07]\npart_2 = \"1,234\"\n\n[day_10]\npart_2 = \"a \\\"quoted\\\\path\\\"\\n\\tdone\"\n"));
+
+    // the recorded answers are read back as they were
+    assert_eq!(ExpectedAnswers::parse(&content).unwrap(), expected);
+}
@@ -0,0 +1,81 @@
+use std::{env, fs, process, time::Duration};
+use aoc::bench::{format_time, load_baseline, median, save_baseline, Measure};
+
+/// A path in the temporary directory that no other test uses.
+fn temporary_path(name: &str) -> String {
+    env::temp_dir().join(format!("aoc_bench_{}_{name}.txt", process::id())).display().to_string()
+}
+
+#[test]
+fn baseline_round_trip() {
+    let measures = [
+        (1, Measure { parse: Duration::from_nanos(1_500), part_one: Duration::from_micros(20), part_two: Duration::from_millis(3), runs: 10 }),
+        (15, Measure { parse: Duration::ZERO, part_one: Duration::from_secs(2), part_two: Duration::from_nanos(7), runs: 1 }),
+    ];
+    let path = temporary_path("round_trip");
+    save_baseline(&path, &measures).unwrap();
+    let baseline = load_baseline(&path);
+    fs::remove_file(&path).unwrap();
+
+    // every measure is read back as it was saved
+    let baseline = baseline.unwrap();
+    assert_eq!(baseline.len(), 2);
+    assert_eq!(baseline[&1], measures[0].1);
+    assert_eq!(baseline[&15], measures[1].1);
+}
+
+#[test]
+fn invalid_baselines() {
+    let invalid_lines = [
+        // a value is missing, or there is one too many
+        "1 100 200 300",
+        "1 100 200 300 10 5",
+        // a value isn't a number of nanoseconds
+        "1 100 fast 300 10",
+        "1 -100 200 300 10",
+        // the day doesn't fit in a day number, instead of wrapping around to day 1
+        "257 100 200 300 10",
+    ];
+
+    for (index, line) in invalid_lines.iter().enumerate() {
+        let path = temporary_path(&format!("invalid_{index}"));
+        fs::write(&path, format!("# day parse part_one part_two runs\n\n{line}\n")).unwrap();
+        let baseline = load_baseline(&path);
+        fs::remove_file(&path).unwrap();
+
+        // the error points to the offending line
+        let error = baseline.expect_err(line);
+        assert!(error.starts_with("The line 3 of the baseline"), "{error}");
+        assert!(error.ends_with(&format!("is invalid: `{line}`")), "{error}");
+    }
+
+    // a missing baseline can't be read
+    assert!(load_baseline(&temporary_path("missing")).is_err());
+}
+
+#[test]
+fn median_time() {
+    let mut times = [5, 1, 4, 2, 3].map(Duration::from_millis);
+    assert_eq!(median(&mut times), Duration::from_millis(3));
+
+    // with an even number of times, the upper one is used
+    let mut times = [4, 1, 3, 2].map(Duration::from_millis);
+    assert_eq!(median(&mut times), Duration::from_millis(3));
+
+    let mut times = [Duration::from_nanos(42)];
+    assert_eq!(median(&mut times), Duration::from_nanos(42));
+}
+
+#[test]
+fn time_changes() {
+    // without a baseline, only the time is shown
+    assert_eq!(format_time(Duration::from_micros(1_500), None), "1.50ms");
+
+    // otherwise, along with how it changed
+    assert_eq!(format_time(Duration::from_millis(15), Some(Duration::from_millis(10))), "15.00ms (+50.0%)");
+    assert_eq!(format_time(Duration::from_millis(5), Some(Duration::from_millis(10))), "5.00ms (-50.0%)");
+    assert_eq!(format_time(Duration::from_millis(10), Some(Duration::from_millis(10))), "10.00ms (+0.0%)");
+
+    // a change from nothing can't be measured
+    assert_eq!(format_time(Duration::from_millis(10), Some(Duration::ZERO)), "10.00ms");
+}
@@ -0,0 +1,83 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use aoc::explore::Animation;
+use aoc_common::ParseError;
+use aoc_visual::{Color, Frame, Frames};
+
+/// How many times the animation of the tests was started, including when it started over.
+static STARTS: AtomicUsize = AtomicUsize::new(0);
+
+/// An animation of 10 by 10 frames showing their number, the input is the number of frames.
+fn numbered_frames(input: &str) -> Result<Frames, ParseError> {
+    let count: usize = input.parse().map_err(|_| ParseError::new("expected a number of frames"))?;
+    STARTS.fetch_add(1, Ordering::SeqCst);
+
+    Ok(Box::new((0..count).map(|number| {
+        let mut frame = Frame::new(10, 10);
+        frame.write_text((0, 0), &number.to_string(), Color::WHITE);
+        frame
+    })))
+}
+
+/// An animation without any frame.
+fn no_frames(_input: &str) -> Result<Frames, ParseError> {
+    Ok(Box::new(std::iter::empty()))
+}
+
+/// The number shown by the displayed frame of the animation.
+fn frame_number(animation: &Animation) -> usize {
+    animation.frame().to_string().split_whitespace().next().unwrap().parse().unwrap()
+}
+
+#[test]
+fn animation() {
+    // the history keeps 3 frames of 100 cells
+    let mut animation = Animation::start(numbered_frames, "6").unwrap().with_history_cells(300);
+    assert_eq!(STARTS.load(Ordering::SeqCst), 1);
+    assert_eq!(frame_number(&animation), 0);
+    assert_eq!(animation.frame_count(), None);
+
+    // nothing comes before the first frame
+    animation.backward();
+    assert_eq!(frame_number(&animation), 0);
+
+    // the frames are computed as the animation moves forward, until the last one
+    for number in 1..6 {
+        assert!(animation.forward());
+        assert_eq!(frame_number(&animation), number);
+    }
+    assert!(!animation.forward());
+    assert_eq!(frame_number(&animation), 5);
+    assert_eq!(animation.frame_count(), Some(6));
+
+    // the frames kept in the history are displayed again without starting over
+    animation.backward();
+    animation.backward();
+    assert_eq!(frame_number(&animation), 3);
+    assert!(animation.forward());
+    assert_eq!(frame_number(&animation), 4);
+    assert_eq!(STARTS.load(Ordering::SeqCst), 1);
+
+    // the frames older than the history are computed again
+    animation.seek(1);
+    assert_eq!(frame_number(&animation), 1);
+    assert_eq!(STARTS.load(Ordering::SeqCst), 2);
+    animation.backward();
+    assert_eq!(frame_number(&animation), 0);
+    assert_eq!(STARTS.load(Ordering::SeqCst), 2);
+
+    // the frames forgotten when starting over are computed again as well
+    for number in 1..6 {
+        assert!(animation.forward());
+        assert_eq!(frame_number(&animation), number);
+    }
+    assert!(!animation.forward());
+}
+
+#[test]
+fn invalid_animations() {
+    // the input can't be parsed
+    assert!(Animation::start(numbered_frames, "many").is_err());
+
+    // the animation doesn't have any frame
+    assert_eq!(Animation::start(no_frames, "").err(), Some("The animation doesn't have any frame.".to_owned()));
+}
@@ -0,0 +1,50 @@
+use std::{thread, time::{Duration, Instant}};
+use aoc::parallel::{run_parallel, Outcome};
+
+#[test]
+fn ordered_outcomes() {
+    // the first items take the longest, so the tasks finish in the reverse order
+    let items: Vec<u64> = (0..8).collect();
+    let mut reported = vec![];
+    run_parallel(&items, 4, None, |item| {
+        thread::sleep(Duration::from_millis(10 * (8 - item)));
+        item * item
+    }, |item, outcome| match outcome {
+        Outcome::Finished(square) => reported.push((*item, square)),
+        _ => panic!("the task of {item} didn't finish"),
+    });
+
+    // the outcomes are reported in the order of the items
+    assert_eq!(reported, (0..8).map(|item| (item, item * item)).collect::<Vec<_>>());
+}
+
+#[test]
+fn failed_tasks() {
+    let items = ["quick", "slow", "panics", "quick"];
+    let start = Instant::now();
+    let mut reported = vec![];
+    run_parallel(&items, 2, Some(Duration::from_millis(200)), |item| {
+        match item {
+            "slow" => thread::sleep(Duration::from_secs(10)),
+            "panics" => panic!("the task of {item} panicked"),
+            _ => {}
+        }
+        item.len()
+    }, |item, outcome| reported.push((*item, outcome)));
+
+    // the runner doesn't wait for the task that timed out
+    assert!(start.elapsed() < Duration::from_secs(5));
+
+    // the failures don't stop the other tasks
+    assert_eq!(reported.len(), 4);
+    assert!(matches!(reported[0], ("quick", Outcome::Finished(5))));
+    assert!(matches!(reported[1], ("slow", Outcome::TimedOut)));
+    assert!(matches!(&reported[2], ("panics", Outcome::Panicked(message)) if message == "the task of panics panicked"));
+    assert!(matches!(reported[3], ("quick", Outcome::Finished(5))));
+}
+
+#[test]
+fn no_items() {
+    let items: [u8; 0] = [];
+    run_parallel(&items, 4, Some(Duration::from_millis(10)), |item| item, |_, _| panic!("there is no item to report"));
+}
@@ -0,0 +1,34 @@
+use aoc::{days::DAYS, selection::parse_selection};
+
+/// The numbers of the selected days.
+fn selected(selection: &str) -> Result<Vec<u8>, String> {
+    parse_selection(selection).map(|days| days.iter().map(|day| day.number).collect())
+}
+
+#[test]
+fn selections() {
+    assert_eq!(selected("all").unwrap(), (1..=DAYS.len() as u8).collect::<Vec<_>>());
+    assert_eq!(selected("7").unwrap(), [7]);
+    assert_eq!(selected(" 7 ").unwrap(), [7]);
+
+    // both ends of a range are included, with or without `=`
+    assert_eq!(selected("3..5").unwrap(), [3, 4, 5]);
+    assert_eq!(selected("3..=5").unwrap(), [3, 4, 5]);
+    assert_eq!(selected("9..9").unwrap(), [9]);
+    assert_eq!(selected(&format!("1..{}", DAYS.len())).unwrap().len(), DAYS.len());
+}
+
+#[test]
+fn invalid_selections() {
+    // the days must exist
+    for selection in ["0", "16", "256", "-1", "seven", "", "1..16", "0..3"] {
+        assert!(selected(selection).is_err(), "selection: {selection}");
+    }
+    assert_eq!(selected("16").unwrap_err(), "`16` is not a valid day, expected a number between 1 and 15.");
+
+    // the ranges must have both ends, in order
+    for selection in ["..3", "3..", "3..=", "3...5", "3..5..7"] {
+        assert!(selected(selection).is_err(), "selection: {selection}");
+    }
+    assert_eq!(selected("9..3").unwrap_err(), "The selection `9..3` does not contain any day.");
+}
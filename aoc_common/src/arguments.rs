@@ -0,0 +1,216 @@
+use std::{env, path::Path, process, str::FromStr};
+use crate::output::Format;
+
+/// The parts of a day that are run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    /// Runs both parts (the default).
+    Both,
+    /// Only runs the first part.
+    PartOne,
+    /// Only runs the second part.
+    PartTwo,
+}
+
+impl Command {
+    /// Checks if the given part (`1` or `2`) is run.
+    pub fn runs(self, part: u8) -> bool {
+        match self {
+            Command::Both => true,
+            Command::PartOne => part == 1,
+            Command::PartTwo => part == 2,
+        }
+    }
+}
+
+impl FromStr for Command {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "both" => Ok(Command::Both),
+            "part1" => Ok(Command::PartOne),
+            "part2" => Ok(Command::PartTwo),
+            _ => Err(format!("`{s}` is not a valid command, expected `part1`, `part2` or `both`.")),
+        }
+    }
+}
+
+/// The parameters of a day that can be changed on the command line, such as the number of rounds of a simulation.
+///
+/// The puzzle bakes them into its questions, so the parameters that aren't given keep the values of the puzzle.
+pub trait Parameters: Sized {
+    /// The name of each parameter (given as `--name VALUE`), along with a description of what it changes.
+    const OPTIONS: &'static [(&'static str, &'static str)];
+
+    /// Reads the parameters from the arguments, using the values of the puzzle for the parameters that weren't given.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value of a parameter is invalid.
+    fn from_arguments(arguments: &Arguments) -> Result<Self, String>;
+}
+
+/// The days without any parameter.
+impl Parameters for () {
+    const OPTIONS: &'static [(&'static str, &'static str)] = &[];
+
+    fn from_arguments(_arguments: &Arguments) -> Result<Self, String> {
+        Ok(())
+    }
+}
+
+/// The arguments given to the binary of a day.
+///
+/// ```text
+/// day_NN [part1|part2|both] [INPUT] [--format text|json|raw] [--timings] [--PARAMETER VALUE]...
+/// day_NN --help
+/// ```
+///
+/// # Fields
+///
+/// * `command` - The parts that are run, both by default.
+/// * `input` - The path of the input file (`-` for the standard input), if one was given.
+/// * `format` - How the answers are displayed.
+/// * `timings` - Whether the time taken and the heap used by each phase are reported after the answers.
+/// * `help` - Whether the usage was asked for (with `--help` or `-h`), instead of running the day.
+/// * `parameters` - The name and the value of each parameter of the day that was given, in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Arguments {
+    pub command: Command,
+    pub input: Option<String>,
+    pub format: Format,
+    pub timings: bool,
+    pub help: bool,
+    pub parameters: Vec<(String, String)>,
+}
+
+impl Arguments {
+    /// Parses the arguments of the binary of a day without parameters, exits with a usage message if they are invalid.
+    pub fn from_env() -> Self {
+        Self::from_env_with::<()>().0
+    }
+
+    /// Parses the arguments of the binary along with the parameters of the day, exits with a usage message if they are invalid.
+    ///
+    /// Prints the usage and exits successfully if it was asked for.
+    pub fn from_env_with<P: Parameters>() -> (Self, P) {
+        let args: Vec<String> = env::args().skip(1).collect();
+        let (arguments, parameters) = Self::parse_with::<P>(&args).unwrap_or_else(|error| {
+            eprintln!("{error}\n\n{}", usage(&binary_name(), P::OPTIONS));
+            process::exit(2);
+        });
+
+        if arguments.help {
+            println!("{}", usage(&binary_name(), P::OPTIONS));
+            process::exit(0);
+        }
+        (arguments, parameters)
+    }
+
+    /// Parses the given arguments of a day without parameters, not including the name of the binary.
+    pub fn parse(args: &[String]) -> Result<Self, String> {
+        Self::parse_with::<()>(args).map(|(arguments, ())| arguments)
+    }
+
+    /// Parses the given arguments along with the parameters of the day, not including the name of the binary.
+    ///
+    /// `--help` (or `-h`) ignores the other arguments, and gives the values of the puzzle to the parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an option or a parameter is unknown or invalid, if there are too many arguments,
+    /// or if `--timings` is used to run a single part (the timings always measure both parts).
+    pub fn parse_with<P: Parameters>(args: &[String]) -> Result<(Self, P), String> {
+        let mut arguments =
+            Self { command: Command::Both, input: None, format: Format::Text, timings: false, help: false, parameters: vec![] };
+
+        // the usage is asked for, whatever the other arguments are
+        if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+            arguments.help = true;
+            let parameters = P::from_arguments(&arguments)?;
+            return Ok((arguments, parameters));
+        }
+
+        let mut args = args.iter().peekable();
+
+        // the command is optional, it comes before the input
+        if let Some(parsed) = args.peek().and_then(|arg| arg.parse::<Command>().ok()) {
+            arguments.command = parsed;
+            args.next();
+        }
+
+        while let Some(arg) = args.next() {
+            if arg == "--format" {
+                let format = args.next().ok_or("The option `--format` expects a value.")?;
+                arguments.format = format.parse()?;
+            } else if arg == "--timings" {
+                arguments.timings = true;
+            } else if let Some(name) = arg.strip_prefix("--") {
+                // the other options are the parameters of the day
+                if !P::OPTIONS.iter().any(|(parameter, _)| *parameter == name) {
+                    return Err(format!("Unknown option `{arg}`."));
+                }
+                let value = args.next().ok_or_else(|| format!("The option `{arg}` expects a value."))?;
+                arguments.parameters.push((name.to_owned(), value.to_owned()));
+            } else if arguments.input.is_none() {
+                arguments.input = Some(arg.to_owned());
+            } else {
+                return Err(format!("Unexpected argument `{arg}`."));
+            }
+        }
+
+        // the timings are about the whole day
+        if arguments.timings && arguments.command != Command::Both {
+            return Err("The option `--timings` measures both parts, it can't be used with `part1` or `part2`.".to_owned());
+        }
+
+        let parameters = P::from_arguments(&arguments)?;
+        Ok((arguments, parameters))
+    }
+
+    /// Parses the value of a parameter, or returns the default value if it wasn't given (the last one wins if it was given several times).
+    pub fn parameter<T: FromStr>(&self, name: &str, default: T) -> Result<T, String> {
+        Ok(self.optional_parameter(name)?.unwrap_or(default))
+    }
+
+    /// Parses the value of a parameter, if it was given (the last one wins if it was given several times).
+    pub fn optional_parameter<T: FromStr>(&self, name: &str) -> Result<Option<T>, String> {
+        let value = self.parameters.iter().rev().find(|(parameter, _)| parameter == name);
+        match value {
+            Some((_, value)) => value
+                .parse()
+                .map(Some)
+                .map_err(|_| format!("`{value}` is not a valid value for the option `--{name}`.")),
+            None => Ok(None),
+        }
+    }
+}
+
+/// The name of the running binary, such as `day_07`, without the directories leading to it.
+pub fn binary_name() -> String {
+    env::args()
+        .next()
+        .and_then(|path| Path::new(&path).file_name().map(|name| name.to_string_lossy().into_owned()))
+        .unwrap_or_else(|| "day_NN".to_owned())
+}
+
+/// Explains how to use the binary of a day, along with its parameters.
+///
+/// # Arguments
+///
+/// * `binary` - The name of the binary, as it is shown in the usage.
+/// * `parameters` - The name and the description of each parameter of the day.
+pub fn usage(binary: &str, parameters: &[(&str, &str)]) -> String {
+    let mut usage = format!("Usage: {binary} [part1|part2|both] [INPUT] [--format text|json|raw] [--timings]");
+    if parameters.is_empty() {
+        return usage;
+    }
+
+    usage.push_str(" [--PARAMETER VALUE]...\n\nParameters:");
+    let longest = parameters.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+    for (name, description) in parameters {
+        usage.push_str(&format!("\n    --{name:<longest$} VALUE  {description}"));
+    }
+    usage
+}
@@ -0,0 +1,181 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+    process,
+};
+
+/// An error that occurred while parsing a puzzle input.
+/// 
+/// The error knows where it occurred, so it can display the offending part of the input.
+/// Parsers working on a fragment of a line (a number, a point, a packet, ...) create the error relative to that fragment,
+/// and their callers then place it in the line using [`ParseError::within`] and in the input using [`ParseError::at_line`].
+/// 
+/// # Fields
+/// 
+/// * `day` - The day whose input couldn't be parsed, if known.
+/// * `line` - The number of the offending line (starting at 1), if known.
+/// * `column` - The column where the offending text starts (starting at 1, counted in characters), if known.
+/// * `length` - The length of the offending text, in characters.
+/// * `text` - The content of the offending line, if known.
+/// * `message` - Explains what went wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub length: usize,
+    pub text: Option<String>,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Creates an error whose location is not known yet.
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            line: None,
+            column: None,
+            length: 0,
+            text: None,
+            message: message.into(),
+        }
+    }
+
+    /// Creates an error spanning `length` characters, starting at the given column (starting at 1).
+    pub fn at(column: usize, length: usize, message: impl Into<String>) -> Self {
+        Self {
+            column: Some(column),
+            length,
+            ..Self::new(message)
+        }
+    }
+
+    /// Places an error that occurred while parsing `inner` inside of `outer`, where `inner` is a slice of `outer`.
+    /// 
+    /// If the error had no column yet, it spans the whole `inner` fragment.
+    /// Otherwise its column is shifted by the position of `inner` in `outer`.
+    pub fn within(mut self, outer: &str, inner: &str) -> Self {
+        // find the position of the fragment in the outer string, in characters
+        let start = inner.as_ptr() as usize;
+        let outer_start = outer.as_ptr() as usize;
+        let offset = match start.checked_sub(outer_start) {
+            Some(offset) if offset + inner.len() <= outer.len() && outer.is_char_boundary(offset) => {
+                outer[..offset].chars().count()
+            }
+            // the fragment doesn't belong to the outer string, we can't locate the error any better
+            _ => return self,
+        };
+
+        match self.column {
+            Some(column) => self.column = Some(column + offset),
+            None => {
+                self.column = Some(offset + 1);
+                self.length = inner.chars().count();
+            }
+        }
+
+        self
+    }
+
+    /// Places the error on the given line of the input (starting at 1).
+    /// 
+    /// If the error had no column yet, it spans the whole line.
+    pub fn at_line(mut self, line: usize, text: &str) -> Self {
+        if self.column.is_none() {
+            self.column = Some(1);
+            self.length = text.chars().count();
+        }
+
+        self.line = Some(line);
+        self.text = Some(text.to_owned());
+        self
+    }
+
+    /// Sets the day whose input couldn't be parsed, unless it was already set.
+    pub fn with_day(mut self, day: u8) -> Self {
+        self.day.get_or_insert(day);
+        self
+    }
+
+    /// Returns the offending part of the line, if it is known.
+    pub fn offending_text(&self) -> Option<&str> {
+        let text = self.text.as_deref()?;
+        let start = self.column?.checked_sub(1)?;
+
+        // convert the columns, counted in characters, into byte indices
+        let mut indices = text.char_indices().map(|(index, _)| index).chain([text.len()]);
+        let start_index = indices.nth(start)?;
+        let end_index = if self.length == 0 {
+            start_index
+        } else {
+            indices.nth(self.length - 1).unwrap_or(text.len())
+        };
+
+        Some(&text[start_index..end_index])
+    }
+
+    /// Builds a report of the error, showing the offending line with the offending text underlined.
+    /// 
+    /// ```text
+    /// error: expected a number, found `x`
+    ///  --> day 13, line 4, column 7
+    ///   |
+    /// 4 | [1,[2,x]]
+    ///   |       ^
+    /// ```
+    pub fn report(&self) -> String {
+        let mut report = format!("error: {}", self.message);
+
+        // add the location of the error
+        let location = self.location();
+        if !location.is_empty() {
+            report.push_str(&format!("\n --> {location}"));
+        }
+
+        // add the snippet of the offending line
+        if let (Some(line), Some(text)) = (self.line, &self.text) {
+            let gutter = " ".repeat(line.to_string().len());
+            let column = self.column.unwrap_or(1).max(1);
+            let carets = "^".repeat(self.length.max(1));
+            let padding = " ".repeat(column - 1);
+
+            report.push_str(&format!("\n{gutter} |\n{line} | {text}\n{gutter} | {padding}{carets}"));
+        }
+
+        report
+    }
+
+    /// Prints the report of the error, and exits the process with an error code.
+    pub fn exit(&self) -> ! {
+        eprintln!("{}", self.report());
+        process::exit(1)
+    }
+
+    /// Describes where the error occurred, such as `day 13, line 4, column 7`.
+    fn location(&self) -> String {
+        let mut location = vec![];
+        if let Some(day) = self.day {
+            location.push(format!("day {day}"));
+        }
+        if let Some(line) = self.line {
+            location.push(format!("line {line}"));
+        }
+        if let (Some(column), Some(_)) = (self.column, self.line) {
+            location.push(format!("column {column}"));
+        }
+        location.join(", ")
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let location = self.location();
+        if location.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{location}: {}", self.message)
+        }
+    }
+}
+
+impl Error for ParseError {}
@@ -0,0 +1,171 @@
+use std::{
+    env,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+    process,
+};
+
+/// The environment variable that can be used to change the directory containing the puzzle inputs.
+pub const INPUT_DIR_VARIABLE: &str = "AOC_INPUT_DIR";
+
+/// The puzzle inputs embedded in the binary, indexed by day (the first element is the 1st day).
+#[cfg(feature = "embedded-inputs")]
+const EMBEDDED_INPUTS: [&str; 15] = [
+    include_str!("../../inputs/day_01.txt"),
+    include_str!("../../inputs/day_02.txt"),
+    include_str!("../../inputs/day_03.txt"),
+    include_str!("../../inputs/day_04.txt"),
+    include_str!("../../inputs/day_05.txt"),
+    include_str!("../../inputs/day_06.txt"),
+    include_str!("../../inputs/day_07.txt"),
+    include_str!("../../inputs/day_08.txt"),
+    include_str!("../../inputs/day_09.txt"),
+    include_str!("../../inputs/day_10.txt"),
+    include_str!("../../inputs/day_11.txt"),
+    include_str!("../../inputs/day_12.txt"),
+    include_str!("../../inputs/day_13.txt"),
+    include_str!("../../inputs/day_14.txt"),
+    include_str!("../../inputs/day_15.txt"),
+];
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The input is read from the given file.
+    File(PathBuf),
+    /// The input is read from the standard input.
+    Stdin,
+    /// The input was embedded in the binary when it was compiled.
+    Embedded,
+}
+
+impl InputSource {
+    /// Finds where the puzzle input of the given day should be read from.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `day` - The number of the day, starting at 1.
+    /// * `argument` - The input given on the command line, if any. `-` stands for the standard input.
+    /// 
+    /// # Resolution order
+    /// 
+    /// 1. The input given on the command line.
+    /// 2. The `day_NN.txt` file of the directory given by the `AOC_INPUT_DIR` environment variable.
+    /// 3. The input embedded in the binary, when the `embedded-inputs` feature is enabled.
+    /// 4. The `inputs/day_NN.txt` file of the current directory, or of the repository if it doesn't exist.
+    pub fn resolve(day: u8, argument: Option<&str>) -> Self {
+        // the command line has the priority over everything else
+        match argument {
+            Some("-") => return InputSource::Stdin,
+            Some(path) => return InputSource::File(PathBuf::from(path)),
+            None => {}
+        }
+
+        // then comes the environment variable
+        if let Some(directory) = env::var_os(INPUT_DIR_VARIABLE) {
+            return InputSource::File(Path::new(&directory).join(input_file_name(day)));
+        }
+
+        // then the embedded inputs
+        if cfg!(feature = "embedded-inputs") {
+            return InputSource::Embedded;
+        }
+
+        // finally, look for the inputs directory, first relative to the current directory, then to the repository
+        let relative_path = Path::new("inputs").join(input_file_name(day));
+        if relative_path.exists() {
+            InputSource::File(relative_path)
+        } else {
+            InputSource::File(Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(relative_path))
+        }
+    }
+
+    /// Reads the puzzle input of the given day from the source.
+    pub fn read(&self, day: u8) -> io::Result<String> {
+        match self {
+            InputSource::File(path) => fs::read_to_string(path).map_err(|error| {
+                io::Error::new(error.kind(), format!("unable to read `{}`: {error}", path.display()))
+            }),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            InputSource::Embedded => embedded_input(day).map(str::to_owned),
+        }
+    }
+
+    /// Opens the puzzle input of the given day from the source, so it can be read one line at a time instead of being loaded at once.
+    pub fn open(&self, day: u8) -> io::Result<Box<dyn BufRead>> {
+        match self {
+            InputSource::File(path) => {
+                let file = File::open(path).map_err(|error| {
+                    io::Error::new(error.kind(), format!("unable to read `{}`: {error}", path.display()))
+                })?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::Embedded => Ok(Box::new(embedded_input(day)?.as_bytes())),
+        }
+    }
+}
+
+/// Returns the name of the file containing the puzzle input of the given day.
+pub fn input_file_name(day: u8) -> String {
+    format!("day_{day:02}.txt")
+}
+
+/// Loads the puzzle input of the given day.
+/// 
+/// # Arguments
+/// 
+/// * `day` - The number of the day, starting at 1.
+/// * `argument` - The input given on the command line, if any. `-` stands for the standard input.
+pub fn load_input(day: u8, argument: Option<&str>) -> io::Result<String> {
+    InputSource::resolve(day, argument).read(day)
+}
+
+/// Opens the puzzle input of the given day, so it can be read one line at a time.
+/// 
+/// # Arguments
+/// 
+/// * `day` - The number of the day, starting at 1.
+/// * `argument` - The input given on the command line, if any. `-` stands for the standard input.
+pub fn open_input(day: u8, argument: Option<&str>) -> io::Result<Box<dyn BufRead>> {
+    InputSource::resolve(day, argument).open(day)
+}
+
+/// Loads the puzzle input of the given day like [`load_input`] does, prints the error and exits with an error code if it can't be read.
+pub fn load_input_or_exit(day: u8, argument: Option<&str>) -> String {
+    load_input(day, argument).unwrap_or_else(|error| exit_unreadable_input(day, &error))
+}
+
+/// Opens the puzzle input of the given day like [`open_input`] does, prints the error and exits with an error code if it can't be opened.
+pub fn open_input_or_exit(day: u8, argument: Option<&str>) -> Box<dyn BufRead> {
+    open_input(day, argument).unwrap_or_else(|error| exit_unreadable_input(day, &error))
+}
+
+/// Reports that the input of the day can't be read, and exits the process with an error code.
+fn exit_unreadable_input(day: u8, error: &io::Error) -> ! {
+    eprintln!("Unable to read the input of day {day}: {error}");
+    process::exit(1)
+}
+
+/// Returns the puzzle input embedded in the binary.
+#[cfg(feature = "embedded-inputs")]
+fn embedded_input(day: u8) -> io::Result<&'static str> {
+    EMBEDDED_INPUTS
+        .get(usize::from(day).wrapping_sub(1))
+        .copied()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("no input is embedded for day {day}")))
+}
+
+/// Returns the puzzle input embedded in the binary.
+#[cfg(not(feature = "embedded-inputs"))]
+fn embedded_input(_day: u8) -> io::Result<&'static str> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "the inputs are not embedded, enable the `embedded-inputs` feature",
+    ))
+}
@@ -0,0 +1,22 @@
+//! Code shared between every day of the challenge.
+
+pub mod arguments;
+pub mod error;
+pub mod input;
+pub mod memory;
+pub mod output;
+pub mod parse;
+pub mod random;
+pub mod solution;
+pub mod timings;
+pub mod trace;
+
+pub use arguments::{Arguments, Command, Parameters};
+pub use error::ParseError;
+pub use input::{load_input, load_input_or_exit, open_input, open_input_or_exit};
+pub use output::{
+    print_answers, print_answers_parsed_with, print_answers_with, print_record, print_streamed_answers, print_streamed_answers_with,
+    Format,
+};
+pub use solution::{MaybeAnswer, Solution, Streaming};
+pub use timings::{print_timings, print_timings_parsed_with, print_timings_with};
@@ -0,0 +1,120 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+/// The number of bytes currently allocated on the heap.
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+
+/// The highest number of bytes allocated on the heap at once, since the last call to [`reset_peak`].
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// Whether the counting allocator is the global allocator, it is set by its first allocation.
+static COUNTING: AtomicBool = AtomicBool::new(false);
+
+/// A global allocator counting the bytes allocated on the heap, on top of the system allocator.
+/// 
+/// It is installed by the binaries, so they can report the peak heap usage of each phase of a day:
+/// 
+/// ```
+/// use aoc_common::memory::CountingAllocator;
+/// 
+/// #[global_allocator]
+/// static ALLOCATOR: CountingAllocator = CountingAllocator;
+/// ```
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    /// Adds the allocated bytes to the current usage, and updates the peak.
+    fn allocated(size: usize) {
+        let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(current, Ordering::Relaxed);
+    }
+
+    /// Removes the freed bytes from the current usage.
+    fn freed(size: usize) {
+        CURRENT.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        COUNTING.store(true, Ordering::Relaxed);
+        let pointer = unsafe { System.alloc(layout) };
+        if !pointer.is_null() {
+            Self::allocated(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        COUNTING.store(true, Ordering::Relaxed);
+        let pointer = unsafe { System.alloc_zeroed(layout) };
+        if !pointer.is_null() {
+            Self::allocated(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(pointer, layout) };
+        Self::freed(layout.size());
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = unsafe { System.realloc(pointer, layout, new_size) };
+        // the old block is left untouched if the new one couldn't be allocated
+        if !new_pointer.is_null() {
+            if new_size > layout.size() {
+                Self::allocated(new_size - layout.size());
+            } else {
+                Self::freed(layout.size() - new_size);
+            }
+        }
+        new_pointer
+    }
+}
+
+/// Checks if the heap is being counted, which is only the case when [`CountingAllocator`] is the global allocator.
+pub fn is_counting() -> bool {
+    COUNTING.load(Ordering::Relaxed)
+}
+
+/// The number of bytes currently allocated on the heap.
+pub fn current() -> usize {
+    CURRENT.load(Ordering::Relaxed)
+}
+
+/// The highest number of bytes allocated on the heap at once, since the last call to [`reset_peak`].
+pub fn peak() -> usize {
+    PEAK.load(Ordering::Relaxed)
+}
+
+/// Starts measuring a new peak from the current heap usage.
+/// 
+/// # Returns
+/// 
+/// The current heap usage, so the peak can be told apart from what was already allocated.
+pub fn reset_peak() -> usize {
+    let current = current();
+    PEAK.store(current, Ordering::Relaxed);
+    current
+}
+
+/// Formats a number of bytes using the most suitable binary unit, such as `12.3 KiB`.
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    // divide by 1024 until the size fits the unit
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
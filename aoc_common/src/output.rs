@@ -0,0 +1,256 @@
+use std::{
+    fmt::Display,
+    hint::black_box,
+    io::{self, BufRead, ErrorKind, Write},
+    process,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+use crate::{arguments::Command, error::ParseError, solution::{Solution, Streaming}};
+
+/// How the answers are displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Sentences meant to be read by a human (the default).
+    Text,
+    /// One JSON object per answer and per line, such as `{"day":1,"part":1,"answer":"24000","elapsed_ms":0.012}`.
+    Json,
+    /// One answer per line, with the day, the part, the answer and the elapsed milliseconds separated by tabulations.
+    Raw,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "raw" => Ok(Format::Raw),
+            _ => Err(format!("`{s}` is not a valid format, expected `text`, `json` or `raw`.")),
+        }
+    }
+}
+
+/// The answer of a part of a day.
+/// 
+/// # Fields
+/// 
+/// * `day` - The number of the day, starting at 1.
+/// * `part` - The number of the part, 1 or 2.
+/// * `answer` - The answer, without any thousands separator.
+/// * `elapsed` - The time taken to compute the answer, not counting the parsing of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+impl Answer {
+    /// Creates the answer of a part.
+    pub fn new(day: u8, part: u8, answer: impl Display, elapsed: Duration) -> Self {
+        Self { day, part, answer: answer.to_string(), elapsed }
+    }
+
+    /// The time taken to compute the answer, in milliseconds (with a precision of a nanosecond).
+    pub fn elapsed_ms(&self) -> String {
+        format!("{:.6}", self.elapsed.as_secs_f64() * 1000.0)
+    }
+
+    /// Formats the answer as a JSON object, on a single line.
+    /// 
+    /// The answer is always a string, as some answers are not numbers and the others can be too big for some JSON parsers.
+    pub fn to_json(&self) -> String {
+        format!(
+            r#"{{"day":{},"part":{},"answer":{},"elapsed_ms":{}}}"#,
+            self.day,
+            self.part,
+            json_string(&self.answer),
+            self.elapsed_ms()
+        )
+    }
+
+    /// Formats the answer as a single line of tab-separated values.
+    /// 
+    /// The line breaks of multi-line answers are written as `\n`, so the answer stays on a single line.
+    pub fn to_raw(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}",
+            self.day,
+            self.part,
+            self.answer.replace('\\', "\\\\").replace('\n', "\\n"),
+            self.elapsed_ms()
+        )
+    }
+
+    /// Formats the answer using the given machine-readable format.
+    /// 
+    /// The text format is specific to each day, so the raw format is used instead.
+    pub fn format(&self, format: Format) -> String {
+        match format {
+            Format::Json => self.to_json(),
+            Format::Text | Format::Raw => self.to_raw(),
+        }
+    }
+}
+
+/// Converts a string into a JSON string literal, escaping the characters that need to be.
+pub(crate) fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Writes a record of a machine-readable format, followed by a line break, and flushes it so the records are read as soon as they are known.
+pub fn write_record(mut writer: impl Write, record: &str) -> io::Result<()> {
+    writeln!(writer, "{record}")?;
+    writer.flush()
+}
+
+/// Prints a record of a machine-readable format to the standard output.
+/// 
+/// Scripts reading the records may stop before the end (such as `| head -1`), so a closed standard output quietly exits the process.
+/// The other errors are reported, and exit with an error code.
+pub fn print_record(record: &str) {
+    if let Err(error) = write_record(io::stdout().lock(), record) {
+        if error.kind() == ErrorKind::BrokenPipe {
+            process::exit(0);
+        }
+        eprintln!("Unable to write to the standard output: {error}");
+        process::exit(1);
+    }
+}
+
+/// Parses the input and computes the answers of both parts, measuring the time taken by each phase.
+/// 
+/// # Returns
+/// 
+/// The time taken to parse the input, and the answers of both parts.
+pub fn solve_timed<S: Solution>(input: &str) -> Result<(Duration, [Answer; 2]), ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse_input(input)?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let part_one = black_box(S::part_one(&parsed));
+    let part_one = Answer::new(S::DAY, 1, part_one, start.elapsed());
+
+    let start = Instant::now();
+    let part_two = black_box(S::part_two(&parsed));
+    let part_two = Answer::new(S::DAY, 2, part_two, start.elapsed());
+
+    Ok((parse_time, [part_one, part_two]))
+}
+
+/// Parses the input and computes the answer of a single part, measuring the time taken by each step.
+/// 
+/// # Arguments
+/// 
+/// * `input` - The puzzle input.
+/// * `part` - The part to compute, `1` or `2`.
+/// 
+/// # Returns
+/// 
+/// The time taken to parse the input, and the answer of the part.
+/// 
+/// # Panics
+/// 
+/// Panics if the part isn't `1` or `2`.
+pub fn solve_part<S: Solution>(input: &str, part: u8) -> Result<(Duration, Answer), ParseError> {
+    let start = Instant::now();
+    let parsed = S::parse_input(input)?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        1 => black_box(S::part_one(&parsed)).to_string(),
+        2 => black_box(S::part_two(&parsed)).to_string(),
+        _ => panic!("there is no part {part}, only the parts 1 and 2"),
+    };
+    let answer = Answer::new(S::DAY, part, answer, start.elapsed());
+
+    Ok((parse_time, answer))
+}
+
+/// Solves the selected parts of the day and prints their answers using a machine-readable format, exits if the input can't be parsed.
+pub fn print_answers<S: Solution>(input: &str, format: Format, command: Command) {
+    print_answers_with::<S>(input, format, command, S::part_one, S::part_two);
+}
+
+/// Solves the selected parts of the day using the given functions, such as the parts of a day computed with other parameters,
+/// and prints their answers using a machine-readable format. Exits if the input can't be parsed.
+pub fn print_answers_with<S: Solution>(
+    input: &str,
+    format: Format,
+    command: Command,
+    part_one: impl FnOnce(&S::Input) -> S::PartOne,
+    part_two: impl FnOnce(&S::Input) -> S::PartTwo,
+) {
+    print_answers_parsed_with::<S>(input, format, command, S::parse, part_one, part_two);
+}
+
+/// Parses the input and solves the selected parts of the day using the given functions, such as a day whose input depends on its parameters,
+/// and prints their answers using a machine-readable format. Exits if the input can't be parsed.
+pub fn print_answers_parsed_with<S: Solution>(
+    input: &str,
+    format: Format,
+    command: Command,
+    parse: impl FnOnce(&str) -> Result<S::Input, ParseError>,
+    part_one: impl FnOnce(&S::Input) -> S::PartOne,
+    part_two: impl FnOnce(&S::Input) -> S::PartTwo,
+) {
+    let parsed = parse(input).unwrap_or_else(|error| error.with_day(S::DAY).exit());
+
+    if command.runs(1) {
+        let start = Instant::now();
+        let answer = black_box(part_one(&parsed));
+        print_record(&Answer::new(S::DAY, 1, answer, start.elapsed()).format(format));
+    }
+
+    if command.runs(2) {
+        let start = Instant::now();
+        let answer = black_box(part_two(&parsed));
+        print_record(&Answer::new(S::DAY, 2, answer, start.elapsed()).format(format));
+    }
+}
+
+/// Solves the day while reading its input, and prints the answers of the selected parts using a machine-readable format.
+/// Exits if the input can't be read or parsed.
+pub fn print_streamed_answers<S: Streaming>(reader: impl BufRead, format: Format, command: Command) {
+    print_streamed_answers_with::<S>(format, command, || S::solve_reader(reader));
+}
+
+/// Solves the day using the given function, such as a day streamed with other parameters,
+/// and prints the answers of the selected parts using a machine-readable format. Exits if the input can't be read or parsed.
+/// 
+/// Both parts are computed together while the input is read, so both answers report the time taken by the whole pass.
+pub fn print_streamed_answers_with<S: Solution>(
+    format: Format,
+    command: Command,
+    solve: impl FnOnce() -> Result<(S::PartOne, S::PartTwo), ParseError>,
+) {
+    let start = Instant::now();
+    let (part_one, part_two) = solve().unwrap_or_else(|error| error.with_day(S::DAY).exit());
+    let elapsed = start.elapsed();
+
+    if command.runs(1) {
+        print_record(&Answer::new(S::DAY, 1, part_one, elapsed).format(format));
+    }
+    if command.runs(2) {
+        print_record(&Answer::new(S::DAY, 2, part_two, elapsed).format(format));
+    }
+}
@@ -0,0 +1,107 @@
+use std::{convert::Infallible, io::BufRead, str::FromStr};
+use crate::error::ParseError;
+
+/// Parses a number, the error spans the whole fragment.
+/// 
+/// # Arguments
+/// 
+/// * `fragment` - The text to parse.
+/// * `description` - What the number represents, used in the error message (for example `a number of calories`).
+pub fn parse_number<T: FromStr>(fragment: &str, description: &str) -> Result<T, ParseError> {
+    fragment.parse().map_err(|_| {
+        if fragment.is_empty() {
+            ParseError::new(format!("expected {description}, found nothing"))
+        } else {
+            ParseError::new(format!("expected {description}, found `{fragment}`"))
+        }
+        .within(fragment, fragment)
+    })
+}
+
+/// Iterates over the lines of the input, along with their number (starting at 1).
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.lines().enumerate().map(|(index, line)| (index + 1, line))
+}
+
+/// Reads the lines of the input one at a time, along with their number (starting at 1).
+/// 
+/// Only the current line is kept in memory, so the input can be bigger than the memory.
+/// The line endings are removed like [`str::lines`] does, including the carriage returns of `\r\n`.
+/// 
+/// # Errors
+/// 
+/// Yields an error placed on the line that couldn't be read, such as a line that isn't valid UTF-8.
+pub fn numbered_reader_lines(reader: impl BufRead) -> impl Iterator<Item = Result<(usize, String), ParseError>> {
+    reader.lines().enumerate().map(|(index, line)| {
+        line.map(|line| (index + 1, line)).map_err(|error| ParseError {
+            line: Some(index + 1),
+            ..ParseError::new(format!("unable to read the input: {error}"))
+        })
+    })
+}
+
+/// Splits numbered lines into groups separated by blank lines, such as the items carried by each elf.
+/// 
+/// The separators are handled explicitly:
+/// * a line is blank if it is empty, the line endings (`\n` or `\r\n`) being already removed from the lines.
+/// * several blank lines in a row are a single separator, so they don't create empty groups.
+/// * the blank lines before the first group and after the last one are ignored, so there is never an empty group.
+/// 
+/// The lines of the current group are the only ones kept in memory, so the lines can be read from a [`BufRead`].
+/// The first error yielded by the lines is yielded as is, and ends the groups.
+pub struct BlankLineGroups<I> {
+    lines: I,
+    done: bool,
+}
+
+impl<I> BlankLineGroups<I> {
+    /// Splits the given lines, along with their number, into groups.
+    pub fn new(lines: I) -> Self {
+        Self { lines, done: false }
+    }
+}
+
+impl<I, S, E> Iterator for BlankLineGroups<I>
+where
+    I: Iterator<Item = Result<(usize, S), E>>,
+    S: AsRef<str>,
+{
+    type Item = Result<Vec<(usize, S)>, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut group = vec![];
+
+        while !self.done {
+            match self.lines.next() {
+                // a blank line ends the current group, or is skipped if the group hasn't started yet
+                Some(Ok((_, line))) if line.as_ref().is_empty() => {
+                    if !group.is_empty() {
+                        return Some(Ok(group));
+                    }
+                }
+                Some(Ok(line)) => group.push(line),
+                Some(Err(error)) => {
+                    self.done = true;
+                    return Some(Err(error));
+                }
+                None => self.done = true,
+            }
+        }
+
+        // the last group doesn't need a separator
+        (!group.is_empty()).then_some(Ok(group))
+    }
+}
+
+/// Splits the lines of the input into groups separated by blank lines, see [`BlankLineGroups`].
+pub fn blank_line_groups(input: &str) -> impl Iterator<Item = Vec<(usize, &str)>> {
+    BlankLineGroups::new(numbered_lines(input).map(Ok::<_, Infallible>)).map(|group| match group {
+        Ok(group) => group,
+        Err(never) => match never {},
+    })
+}
+
+/// Reads the lines of the input one group at a time, the groups being separated by blank lines, see [`BlankLineGroups`].
+pub fn reader_blank_line_groups(reader: impl BufRead) -> impl Iterator<Item = Result<Vec<(usize, String)>, ParseError>> {
+    BlankLineGroups::new(numbered_reader_lines(reader))
+}
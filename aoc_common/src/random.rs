@@ -0,0 +1,128 @@
+use std::{
+    env,
+    ops::Range,
+    panic::{self, AssertUnwindSafe},
+};
+
+/// The environment variable used to replay a single case of a property.
+const SEED_VARIABLE: &str = "AOC_SEED";
+
+/// A small pseudo-random number generator (SplitMix64), used to generate puzzle inputs.
+///
+/// It isn't suitable for cryptography, but it is fast, and the same seed always produces the same numbers on every platform.
+///
+/// # Fields
+///
+/// * `state` - The internal state, advanced every time a number is generated.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a generator from a seed.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Generates a number, uniformly distributed over every `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Generates a number in `0..bound`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is 0.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "cannot generate a number below 0");
+
+        // reject the numbers of the last incomplete cycle, so every number is equally likely
+        let limit = u64::MAX - u64::MAX % bound;
+        loop {
+            let number = self.next_u64();
+            if number < limit {
+                return number % bound;
+            }
+        }
+    }
+
+    /// Generates a number in the given range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    pub fn usize(&mut self, range: Range<usize>) -> usize {
+        assert!(!range.is_empty(), "cannot generate a number in an empty range");
+        range.start + self.below((range.end - range.start) as u64) as usize
+    }
+
+    /// Generates a number in the given range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    pub fn i64(&mut self, range: Range<i64>) -> i64 {
+        assert!(!range.is_empty(), "cannot generate a number in an empty range");
+        let width = range.end.abs_diff(range.start);
+        range.start.wrapping_add(self.below(width) as i64)
+    }
+
+    /// Returns `true` with the given probability (between 0 and 1).
+    pub fn chance(&mut self, probability: f64) -> bool {
+        // use the 53 high bits, the precision of a f64
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    /// Picks an element of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.usize(0..items.len())]
+    }
+
+    /// Shuffles the slice in place, every order being equally likely.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.usize(0..index + 1));
+        }
+    }
+
+    /// Generates a name made of lowercase letters, with a length in the given range.
+    pub fn name(&mut self, length: Range<usize>) -> String {
+        let length = self.usize(length);
+        (0..length).map(|_| (b'a' + self.below(26) as u8) as char).collect()
+    }
+}
+
+/// Checks that a property holds for many randomly generated cases.
+///
+/// Each case gets its own generator, seeded with the number of the case.
+/// When a case fails, its seed is printed, and the case can be replayed alone by setting the `AOC_SEED` environment variable.
+///
+/// # Arguments
+///
+/// * `cases` - The number of cases to check.
+/// * `property` - Generates a case using the given generator, and panics if the property doesn't hold.
+pub fn check_property(cases: u64, property: impl Fn(&mut Rng)) {
+    // replay a single case if a seed is given
+    let seeds = match env::var(SEED_VARIABLE).ok().and_then(|seed| seed.parse().ok()) {
+        Some(seed) => seed..seed + 1,
+        None => 0..cases,
+    };
+
+    for seed in seeds {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| property(&mut Rng::new(seed))));
+        if let Err(panic) = result {
+            eprintln!("The property doesn't hold for the seed {seed}, replay it with `{SEED_VARIABLE}={seed}`.");
+            panic::resume_unwind(panic);
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    io::BufRead,
+};
+use crate::error::ParseError;
+
+/// The solution to a day of the challenge.
+/// 
+/// Solving a day is split into three steps:
+/// * `parse` - converts the puzzle input into a representation that is easier to work with.
+/// * `part_one` - computes the answer of the first part using the parsed input.
+/// * `part_two` - computes the answer of the second part using the parsed input.
+pub trait Solution {
+    /// The number of the day, starting at 1.
+    const DAY: u8;
+
+    /// The representation of the puzzle input once parsed.
+    type Input;
+
+    /// The answer of the first part.
+    type PartOne: Display;
+
+    /// The answer of the second part.
+    type PartTwo: Display;
+
+    /// Parses the puzzle input.
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Computes the answer of the first part.
+    fn part_one(input: &Self::Input) -> Self::PartOne;
+
+    /// Computes the answer of the second part.
+    fn part_two(input: &Self::Input) -> Self::PartTwo;
+
+    /// Parses the puzzle input, and marks the errors as coming from this day.
+    fn parse_input(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse(input).map_err(|error| error.with_day(Self::DAY))
+    }
+
+    /// Parses the puzzle input and computes the answers of both parts.
+    fn solve(input: &str) -> Result<(Self::PartOne, Self::PartTwo), ParseError> {
+        let input = Self::parse_input(input)?;
+        Ok((Self::part_one(&input), Self::part_two(&input)))
+    }
+}
+
+/// A solution that can solve the puzzle input while reading it, one line at a time, instead of loading it first.
+/// 
+/// Only the state needed by the answers is kept in memory, so the input can come from a pipe or be bigger than the memory.
+pub trait Streaming: Solution {
+    /// Reads the puzzle input and computes the answers of both parts in a single pass.
+    fn stream(reader: impl BufRead) -> Result<(Self::PartOne, Self::PartTwo), ParseError>;
+
+    /// Reads the puzzle input and computes the answers of both parts, and marks the errors as coming from this day.
+    fn solve_reader(reader: impl BufRead) -> Result<(Self::PartOne, Self::PartTwo), ParseError> {
+        Self::stream(reader).map_err(|error| error.with_day(Self::DAY))
+    }
+}
+
+/// The answer of a part whose question may have no answer, such as a variant of the puzzle asked with other parameters,
+/// or an input that wasn't made for the puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaybeAnswer<T> {
+    /// The answer of the part.
+    Answer(T),
+    /// The question has no answer, along with the reason, such as `no directory is big enough`.
+    NoAnswer(&'static str),
+}
+
+impl<T> MaybeAnswer<T> {
+    /// Creates the answer of a part, or explains why there is none if it is missing.
+    pub fn from_option(answer: Option<T>, reason: &'static str) -> Self {
+        answer.map_or(Self::NoAnswer(reason), Self::Answer)
+    }
+
+    /// The answer of the part, if there is one.
+    pub fn answer(self) -> Option<T> {
+        match self {
+            Self::Answer(answer) => Some(answer),
+            Self::NoAnswer(_) => None,
+        }
+    }
+}
+
+/// A missing answer is displayed as the reason why there is none.
+impl<T: Display> Display for MaybeAnswer<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Answer(answer) => answer.fmt(f),
+            Self::NoAnswer(reason) => write!(f, "{reason}"),
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+use crate::{
+    error::ParseError,
+    memory::{self, format_bytes},
+    output::{print_record, Answer, Format},
+    solution::Solution,
+};
+
+/// The time taken and the heap used by a phase of a day.
+/// 
+/// # Fields
+/// 
+/// * `elapsed` - The time taken by the phase.
+/// * `peak_heap` - The highest number of bytes allocated at once during the phase, on top of what was allocated before it.
+///   `None` if the heap isn't counted, because the binary doesn't use [`memory::CountingAllocator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Phase {
+    pub elapsed: Duration,
+    pub peak_heap: Option<usize>,
+}
+
+/// The time taken and the heap used by each phase of a day, along with its answers.
+/// 
+/// # Fields
+/// 
+/// * `day` - The number of the day, starting at 1.
+/// * `parse` - The parsing of the input, the heap it uses includes the parsed input.
+/// * `part_one` - The computation of the first answer.
+/// * `part_two` - The computation of the second answer.
+/// * `total` - The three phases together, its peak heap is the highest heap usage of the whole day.
+/// * `answers` - The answers of both parts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timings {
+    pub day: u8,
+    pub parse: Phase,
+    pub part_one: Phase,
+    pub part_two: Phase,
+    pub total: Phase,
+    pub answers: [Answer; 2],
+}
+
+impl Timings {
+    /// The phases along with their names: `parse`, `part 1`, `part 2` and `total`.
+    pub fn phases(&self) -> [(&'static str, Phase); 4] {
+        [("parse", self.parse), ("part 1", self.part_one), ("part 2", self.part_two), ("total", self.total)]
+    }
+
+    /// Formats the timings as a small table, with one phase per line.
+    pub fn to_text(&self) -> String {
+        let mut lines = vec![format!("{:<8}  {:>12}  {:>12}", "Phase", "Time", "Peak heap")];
+        for (name, phase) in self.phases() {
+            let peak_heap = phase.peak_heap.map_or_else(|| "-".to_owned(), format_bytes);
+            lines.push(format!("{name:<8}  {:>12}  {peak_heap:>12}", format!("{:.2?}", phase.elapsed)));
+        }
+        lines.join("\n")
+    }
+
+    /// Formats the timings as one JSON object per phase and per line,
+    /// such as `{"day":7,"phase":"parse","elapsed_ms":0.153000,"peak_heap_bytes":86016}`.
+    ///
+    /// The peak heap is `null` if the heap isn't counted.
+    pub fn to_json(&self) -> String {
+        let lines: Vec<String> = self
+            .phases()
+            .iter()
+            .map(|(name, phase)| {
+                format!(
+                    r#"{{"day":{},"phase":"{name}","elapsed_ms":{},"peak_heap_bytes":{}}}"#,
+                    self.day,
+                    elapsed_ms(phase.elapsed),
+                    phase.peak_heap.map_or_else(|| "null".to_owned(), |bytes| bytes.to_string())
+                )
+            })
+            .collect();
+        lines.join("\n")
+    }
+
+    /// Formats the timings as one line of tab-separated values per phase: the day, the phase, the elapsed milliseconds and the peak heap in bytes.
+    ///
+    /// The peak heap is `-` if the heap isn't counted.
+    pub fn to_raw(&self) -> String {
+        let lines: Vec<String> = self
+            .phases()
+            .iter()
+            .map(|(name, phase)| {
+                format!(
+                    "{}\t{name}\t{}\t{}",
+                    self.day,
+                    elapsed_ms(phase.elapsed),
+                    phase.peak_heap.map_or_else(|| "-".to_owned(), |bytes| bytes.to_string())
+                )
+            })
+            .collect();
+        lines.join("\n")
+    }
+
+    /// Formats the timings using the given format.
+    pub fn format(&self, format: Format) -> String {
+        match format {
+            Format::Text => self.to_text(),
+            Format::Json => self.to_json(),
+            Format::Raw => self.to_raw(),
+        }
+    }
+}
+
+/// Formats a time in milliseconds, with a precision of a nanosecond.
+fn elapsed_ms(elapsed: Duration) -> String {
+    format!("{:.6}", elapsed.as_secs_f64() * 1000.0)
+}
+
+/// Runs a phase, measuring the time it takes and the heap it uses.
+/// 
+/// # Returns
+/// 
+/// The result of the phase, the time it took, the heap usage before it started, and the peak heap usage while it ran.
+fn run_phase<T>(phase: impl FnOnce() -> T) -> (T, Duration, usize, usize) {
+    let heap_before = memory::reset_peak();
+    let start = Instant::now();
+    let result = black_box(phase());
+    let elapsed = start.elapsed();
+
+    (result, elapsed, heap_before, memory::peak())
+}
+
+/// Parses the input and computes the answers of both parts, measuring the time taken and the heap used by each phase.
+pub fn measure<S: Solution>(input: &str) -> Result<Timings, ParseError> {
+    measure_with::<S>(input, S::part_one, S::part_two)
+}
+
+/// Parses the input and computes the answers of both parts using the given functions, such as the parts of a day computed with other parameters,
+/// measuring the time taken and the heap used by each phase.
+pub fn measure_with<S: Solution>(
+    input: &str,
+    part_one: impl FnOnce(&S::Input) -> S::PartOne,
+    part_two: impl FnOnce(&S::Input) -> S::PartTwo,
+) -> Result<Timings, ParseError> {
+    measure_parsed_with::<S>(input, S::parse, part_one, part_two)
+}
+
+/// Parses the input and computes the answers of both parts using the given functions, such as a day whose input depends on its parameters,
+/// measuring the time taken and the heap used by each phase.
+pub fn measure_parsed_with<S: Solution>(
+    input: &str,
+    parse: impl FnOnce(&str) -> Result<S::Input, ParseError>,
+    part_one: impl FnOnce(&S::Input) -> S::PartOne,
+    part_two: impl FnOnce(&S::Input) -> S::PartTwo,
+) -> Result<Timings, ParseError> {
+    let counting = memory::is_counting();
+    let start_heap = memory::current();
+
+    let (parsed, parse_time, parse_before, parse_peak) = run_phase(|| parse(black_box(input)));
+    let parsed = parsed.map_err(|error| error.with_day(S::DAY))?;
+    let (part_one, part_one_time, part_one_before, part_one_peak) = run_phase(|| part_one(&parsed));
+    let (part_two, part_two_time, part_two_before, part_two_peak) = run_phase(|| part_two(&parsed));
+
+    // the peaks are only meaningful when the heap is counted
+    let heap = |peak: usize, before: usize| counting.then(|| peak.saturating_sub(before));
+    let highest_peak = parse_peak.max(part_one_peak).max(part_two_peak);
+
+    Ok(Timings {
+        day: S::DAY,
+        parse: Phase { elapsed: parse_time, peak_heap: heap(parse_peak, parse_before) },
+        part_one: Phase { elapsed: part_one_time, peak_heap: heap(part_one_peak, part_one_before) },
+        part_two: Phase { elapsed: part_two_time, peak_heap: heap(part_two_peak, part_two_before) },
+        total: Phase {
+            elapsed: parse_time + part_one_time + part_two_time,
+            peak_heap: heap(highest_peak, start_heap),
+        },
+        answers: [
+            Answer::new(S::DAY, 1, part_one, part_one_time),
+            Answer::new(S::DAY, 2, part_two, part_two_time),
+        ],
+    })
+}
+
+/// Solves the day and prints its answers, followed by the time taken and the heap used by each phase.
+/// Exits if the input can't be parsed.
+pub fn print_timings<S: Solution>(input: &str, format: Format) {
+    print_timings_with::<S>(input, format, S::part_one, S::part_two);
+}
+
+/// Solves the day using the given functions, such as the parts of a day computed with other parameters,
+/// and prints its answers followed by the time taken and the heap used by each phase. Exits if the input can't be parsed.
+pub fn print_timings_with<S: Solution>(
+    input: &str,
+    format: Format,
+    part_one: impl FnOnce(&S::Input) -> S::PartOne,
+    part_two: impl FnOnce(&S::Input) -> S::PartTwo,
+) {
+    print_timings_parsed_with::<S>(input, format, S::parse, part_one, part_two);
+}
+
+/// Parses the input and solves the day using the given functions, such as a day whose input depends on its parameters,
+/// and prints its answers followed by the time taken and the heap used by each phase. Exits if the input can't be parsed.
+pub fn print_timings_parsed_with<S: Solution>(
+    input: &str,
+    format: Format,
+    parse: impl FnOnce(&str) -> Result<S::Input, ParseError>,
+    part_one: impl FnOnce(&S::Input) -> S::PartOne,
+    part_two: impl FnOnce(&S::Input) -> S::PartTwo,
+) {
+    let timings = measure_parsed_with::<S>(input, parse, part_one, part_two).unwrap_or_else(|error| error.exit());
+
+    // the text answers are displayed above the table of the phases
+    for answer in &timings.answers {
+        match format {
+            // multi-line answers start on their own line, so they aren't misaligned
+            Format::Text if answer.answer.contains('\n') => println!("Part {}:\n{}", answer.part, answer.answer),
+            Format::Text => println!("Part {}: {}", answer.part, answer.answer),
+            Format::Json | Format::Raw => print_record(&answer.format(format)),
+        }
+    }
+    if format == Format::Text {
+        println!();
+        println!("{}", timings.format(format));
+    } else {
+        print_record(&timings.format(format));
+    }
+}
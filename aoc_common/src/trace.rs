@@ -0,0 +1,165 @@
+use std::{
+    env,
+    fs::File,
+    io::{self, LineWriter, Write},
+    sync::{Mutex, OnceLock},
+};
+use crate::output::json_string;
+
+/// The environment variable that can be used to write the trace to a file, instead of the standard error.
+pub const TRACE_FILE_VARIABLE: &str = "AOC_TRACE";
+
+/// Where the events are written, opened by the first event.
+static OUTPUT: OnceLock<Mutex<Box<dyn Write + Send>>> = OnceLock::new();
+
+/// The value of a field of an event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Bool(bool),
+    Integer(i128),
+    String(String),
+    List(Vec<Value>),
+}
+
+impl Value {
+    /// Converts the value into JSON.
+    pub fn to_json(&self) -> String {
+        match self {
+            Value::Bool(value) => value.to_string(),
+            Value::Integer(value) => value.to_string(),
+            Value::String(value) => json_string(value),
+            Value::List(values) => format!("[{}]", values.iter().map(Value::to_json).collect::<Vec<_>>().join(",")),
+        }
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+/// Converts the integers into a value, every integer type fits in an `i128` except the largest `u128`.
+macro_rules! integer_values {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Value {
+                fn from(value: $integer) -> Self {
+                    Value::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+integer_values!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<char> for Value {
+    fn from(value: char) -> Self {
+        Value::String(value.to_string())
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::String(value.to_owned())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::String(value)
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(values: Vec<T>) -> Self {
+        Value::List(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Into<Value>, const N: usize> From<[T; N]> for Value {
+    fn from(values: [T; N]) -> Self {
+        Value::List(values.into_iter().map(Into::into).collect())
+    }
+}
+
+/// Formats an event as a single line of JSON, such as `{"day":5,"event":"move","quantity":3,"from":1,"to":3}`.
+/// 
+/// # Arguments
+/// 
+/// * `day` - The number of the day emitting the event, so the events of days running at the same time can be told apart.
+/// * `event` - The name of the event.
+/// * `fields` - The names and the values of the fields of the event, in order.
+pub fn format_event(day: u8, event: &str, fields: &[(&str, Value)]) -> String {
+    let mut json = format!(r#"{{"day":{day},"event":{}"#, json_string(event));
+    for (name, value) in fields {
+        json.push_str(&format!(",{}:{}", json_string(name), value.to_json()));
+    }
+    json.push('}');
+    json
+}
+
+/// Writes an event to the trace, on its own line.
+/// 
+/// The trace is written to the file given by the `AOC_TRACE` environment variable, or to the standard error if it isn't set.
+/// Events emitted from several threads at once never share a line.
+pub fn emit(day: u8, event: &str, fields: &[(&str, Value)]) {
+    let output = OUTPUT.get_or_init(|| Mutex::new(open_output()));
+    let line = format_event(day, event, fields);
+
+    // a trace is only a debugging help, failing to write it shouldn't stop the day
+    if let Ok(mut output) = output.lock() {
+        let _ = writeln!(output, "{line}");
+    }
+}
+
+/// Opens the file the trace is written to, falling back to the standard error.
+fn open_output() -> Box<dyn Write + Send> {
+    let Ok(path) = env::var(TRACE_FILE_VARIABLE) else {
+        return Box::new(io::stderr());
+    };
+
+    match File::create(&path) {
+        // the lines are written as soon as they are complete, so the trace is whole even if the day panics
+        Ok(file) => Box::new(LineWriter::new(file)),
+        Err(error) => {
+            eprintln!("Unable to create the trace file `{path}`, tracing to the standard error instead: {error}");
+            Box::new(io::stderr())
+        }
+    }
+}
+
+/// Emits a structured event from the inner loop of a day, when the `trace` feature is enabled.
+/// 
+/// Without the feature, the event is compiled out: its fields are still type-checked, but never evaluated.
+/// 
+/// ```
+/// let (quantity, from, to) = (3, 1, 3);
+/// aoc_common::trace!(5, "move", quantity = quantity, from = from, to = to);
+/// ```
+#[cfg(feature = "trace")]
+#[macro_export]
+macro_rules! trace {
+    ($day:expr, $event:expr $(, $field:ident = $value:expr)* $(,)?) => {
+        $crate::trace::emit($day, $event, &[$((stringify!($field), $crate::trace::Value::from($value))),*])
+    };
+}
+
+/// Emits a structured event from the inner loop of a day, when the `trace` feature is enabled.
+/// 
+/// Without the feature, the event is compiled out: its fields are still type-checked, but never evaluated.
+/// 
+/// ```
+/// let (quantity, from, to) = (3, 1, 3);
+/// aoc_common::trace!(5, "move", quantity = quantity, from = from, to = to);
+/// ```
+#[cfg(not(feature = "trace"))]
+#[macro_export]
+macro_rules! trace {
+    ($day:expr, $event:expr $(, $field:ident = $value:expr)* $(,)?) => {
+        if false {
+            $crate::trace::emit($day, $event, &[$((stringify!($field), $crate::trace::Value::from($value))),*])
+        }
+    };
+}
@@ -0,0 +1,77 @@
+use aoc_common::{arguments::usage, Arguments, Command, Format, Parameters};
+
+/// The parameters of a small simulation.
+#[derive(Debug, PartialEq, Eq)]
+struct Simulation {
+    rounds: u32,
+    relief: bool,
+}
+
+impl Parameters for Simulation {
+    const OPTIONS: &'static [(&'static str, &'static str)] = &[("rounds", "the number of rounds"), ("relief", "whether relief is on")];
+
+    fn from_arguments(arguments: &Arguments) -> Result<Self, String> {
+        Ok(Self { rounds: arguments.parameter("rounds", 20)?, relief: arguments.parameter("relief", true)? })
+    }
+}
+
+/// Converts the arguments into owned strings.
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn defaults() {
+    let arguments = Arguments::parse(&[]).unwrap();
+    assert_eq!(arguments.command, Command::Both);
+    assert_eq!(arguments.input, None);
+    assert_eq!(arguments.format, Format::Text);
+    assert!(!arguments.timings);
+}
+
+#[test]
+fn command() {
+    let arguments = Arguments::parse(&args(&["part2", "input.txt", "--format", "json"])).unwrap();
+    assert_eq!(arguments.command, Command::PartTwo);
+    assert_eq!(arguments.input.as_deref(), Some("input.txt"));
+    assert_eq!(arguments.format, Format::Json);
+    assert!(Command::PartTwo.runs(2) && !Command::PartTwo.runs(1));
+
+    // the command is only recognized before the input
+    assert!(Arguments::parse(&args(&["input.txt", "part1"])).is_err());
+
+    // the timings are about both parts
+    assert!(Arguments::parse(&args(&["part1", "--timings"])).is_err());
+    assert!(Arguments::parse(&args(&["both", "--timings"])).is_ok());
+}
+
+#[test]
+fn parameters() {
+    let (arguments, simulation) = Arguments::parse_with::<Simulation>(&args(&["part1", "--rounds", "5", "-"])).unwrap();
+    assert_eq!(arguments.input.as_deref(), Some("-"));
+    assert_eq!(simulation, Simulation { rounds: 5, relief: true });
+
+    // the parameters are only known by the days declaring them, and their values are checked
+    assert!(Arguments::parse(&args(&["--rounds", "5"])).is_err());
+    assert!(Arguments::parse_with::<Simulation>(&args(&["--rounds", "many"])).is_err());
+    assert!(Arguments::parse_with::<Simulation>(&args(&["--rounds"])).is_err());
+
+    // the parameters that weren't given can be told apart from the default values
+    assert_eq!(arguments.optional_parameter::<u32>("rounds"), Ok(Some(5)));
+    assert_eq!(arguments.optional_parameter::<bool>("relief"), Ok(None));
+    assert!(arguments.optional_parameter::<bool>("rounds").is_err());
+
+    // the usage lists the parameters
+    assert!(usage("day_11", Simulation::OPTIONS).starts_with("Usage: day_11 [part1|part2|both]"));
+    assert!(usage("day_11", Simulation::OPTIONS).ends_with("Parameters:\n    --rounds VALUE  the number of rounds\n    --relief VALUE  whether relief is on"));
+}
+
+#[test]
+fn help() {
+    // the usage is asked for, even among invalid arguments
+    let (arguments, simulation) = Arguments::parse_with::<Simulation>(&args(&["part1", "--help", "--timings", "--unknown"])).unwrap();
+    assert!(arguments.help);
+    assert_eq!(simulation, Simulation { rounds: 20, relief: true });
+    assert!(Arguments::parse(&args(&["-h"])).unwrap().help);
+    assert!(!Arguments::parse(&args(&["input.txt"])).unwrap().help);
+}
@@ -0,0 +1,88 @@
+use std::{
+    io::{self, ErrorKind, Write},
+    time::Duration,
+};
+use aoc_common::{output::{solve_part, write_record, Answer}, Format, ParseError, Solution};
+
+/// A standard output whose reader went away, such as `head` after its first line.
+struct ClosedPipe;
+
+impl Write for ClosedPipe {
+    fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+        Err(io::Error::from(ErrorKind::BrokenPipe))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn json() {
+    let answer = Answer::new(11, 2, 2_713_310_158u64, Duration::from_micros(1_500));
+    assert_eq!(answer.to_json(), r#"{"day":11,"part":2,"answer":"2713310158","elapsed_ms":1.500000}"#);
+
+    // multi-line answers and special characters are escaped
+    let answer = Answer::new(10, 2, "█ \"█\"\n\\", Duration::ZERO);
+    assert_eq!(answer.to_json(), r#"{"day":10,"part":2,"answer":"█ \"█\"\n\\","elapsed_ms":0.000000}"#);
+}
+
+#[test]
+fn raw() {
+    let answer = Answer::new(5, 1, "CMZ", Duration::from_nanos(42));
+    assert_eq!(answer.format(Format::Raw), "5\t1\tCMZ\t0.000042");
+
+    // multi-line answers stay on a single line
+    let answer = Answer::new(10, 2, "##\n..", Duration::ZERO);
+    assert_eq!(answer.to_raw(), "10\t2\t##\\n..\t0.000000");
+}
+
+#[test]
+fn records() {
+    let mut output = vec![];
+    write_record(&mut output, "1\t1\t24000\t0.001000").unwrap();
+    write_record(&mut output, "1\t2\t45000\t0.001000").unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "1\t1\t24000\t0.001000\n1\t2\t45000\t0.001000\n");
+
+    // a closed output is reported as such, so it can be told apart from the other errors
+    assert_eq!(write_record(ClosedPipe, "1\t1\t24000\t0.001000").unwrap_err().kind(), ErrorKind::BrokenPipe);
+}
+
+#[test]
+fn format() {
+    assert_eq!("json".parse(), Ok(Format::Json));
+    assert_eq!("raw".parse(), Ok(Format::Raw));
+    assert_eq!("text".parse(), Ok(Format::Text));
+    assert!("xml".parse::<Format>().is_err());
+}
+
+/// A small solution, counting the words of the input and the words longer than 3 characters.
+struct Words;
+
+impl Solution for Words {
+    const DAY: u8 = 6;
+    type Input = Vec<String>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.split_whitespace().map(str::to_owned).collect())
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        input.len()
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        input.iter().filter(|word| word.len() > 3).count()
+    }
+}
+
+#[test]
+fn single_part() {
+    let (_, answer) = solve_part::<Words>("a tuning trouble", 1).unwrap();
+    assert_eq!((answer.day, answer.part, answer.answer.as_str()), (6, 1, "3"));
+
+    let (_, answer) = solve_part::<Words>("a tuning trouble", 2).unwrap();
+    assert_eq!((answer.day, answer.part, answer.answer.as_str()), (6, 2, "2"));
+}
@@ -0,0 +1,52 @@
+use aoc_common::parse::{blank_line_groups, numbered_lines, numbered_reader_lines, reader_blank_line_groups};
+
+#[test]
+fn reader_lines() {
+    let input = "first\nsecond\r\n\nlast";
+    let lines: Vec<(usize, String)> = numbered_reader_lines(input.as_bytes()).collect::<Result<_, _>>().unwrap();
+
+    // the lines are the same as the lines of a string
+    let expected: Vec<(usize, String)> = numbered_lines(input).map(|(number, line)| (number, line.to_owned())).collect();
+    assert_eq!(lines, expected);
+}
+
+#[test]
+fn reader_lines_invalid_utf8() {
+    let input: &[u8] = b"first\nsec\xffond\nlast\n";
+    let lines: Vec<_> = numbered_reader_lines(input).collect();
+
+    // the error is placed on the line that couldn't be read
+    assert_eq!(lines[0].as_ref().unwrap(), &(1, "first".to_owned()));
+    let error = lines[1].as_ref().unwrap_err();
+    assert_eq!(error.line, Some(2));
+    assert!(error.message.starts_with("unable to read the input"));
+}
+
+#[test]
+fn groups() {
+    let input = "\n\na\nb\n\nc\n\n\n\nd\r\ne\r\n\r\n";
+    let groups: Vec<Vec<(usize, &str)>> = blank_line_groups(input).collect();
+
+    // the separators never create empty groups, and the lines keep their number
+    assert_eq!(groups, [vec![(3, "a"), (4, "b")], vec![(6, "c")], vec![(10, "d"), (11, "e")]]);
+
+    // the groups read from a reader are the same
+    let read: Vec<Vec<(usize, String)>> = reader_blank_line_groups(input.as_bytes()).collect::<Result<_, _>>().unwrap();
+    let read: Vec<Vec<(usize, &str)>> = read.iter().map(|group| group.iter().map(|(number, line)| (*number, line.as_str())).collect()).collect();
+    assert_eq!(read, groups);
+
+    // there are no groups without lines
+    assert_eq!(blank_line_groups("").count(), 0);
+    assert_eq!(blank_line_groups("\n\n").count(), 0);
+}
+
+#[test]
+fn groups_error() {
+    let input: &[u8] = b"a\n\nb\xff\nc\n";
+    let groups: Vec<_> = reader_blank_line_groups(input).collect();
+
+    // the error ends the groups
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].as_ref().unwrap(), &vec![(1, "a".to_owned())]);
+    assert_eq!(groups[1].as_ref().unwrap_err().line, Some(3));
+}
@@ -0,0 +1,68 @@
+use std::time::Duration;
+use aoc_common::{
+    memory::format_bytes,
+    output::Answer,
+    timings::{measure, Phase, Timings},
+    Format, ParseError, Solution,
+};
+
+/// A small solution, counting the lines of the input and their total length.
+struct Lines;
+
+impl Solution for Lines {
+    const DAY: u8 = 3;
+    type Input = Vec<String>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(str::to_owned).collect())
+    }
+
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        input.len()
+    }
+
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        input.iter().map(String::len).sum()
+    }
+}
+
+#[test]
+fn measure_phases() {
+    let timings = measure::<Lines>("ab\ncde\n").unwrap();
+    assert_eq!(timings.day, 3);
+    assert_eq!(timings.answers.each_ref().map(|answer| answer.answer.as_str()), ["2", "5"]);
+    assert_eq!(timings.total.elapsed, timings.parse.elapsed + timings.part_one.elapsed + timings.part_two.elapsed);
+
+    // the tests don't use the counting allocator, so the heap isn't measured
+    assert!(timings.phases().iter().all(|(_, phase)| phase.peak_heap.is_none()));
+}
+
+#[test]
+fn formats() {
+    let phase = |micros, peak_heap| Phase { elapsed: Duration::from_micros(micros), peak_heap };
+    let timings = Timings {
+        day: 7,
+        parse: phase(1_500, Some(2_048)),
+        part_one: phase(20, Some(0)),
+        part_two: phase(30, None),
+        total: phase(1_550, Some(2_048)),
+        answers: [Answer::new(7, 1, 95_437, Duration::ZERO), Answer::new(7, 2, 24_933_642, Duration::ZERO)],
+    };
+
+    assert_eq!(
+        timings.format(Format::Json).lines().next(),
+        Some(r#"{"day":7,"phase":"parse","elapsed_ms":1.500000,"peak_heap_bytes":2048}"#)
+    );
+    assert_eq!(timings.to_raw().lines().nth(2), Some("7\tpart 2\t0.030000\t-"));
+    assert_eq!(timings.to_text().lines().nth(1), Some("parse           1.50ms       2.0 KiB"));
+}
+
+#[test]
+fn bytes() {
+    assert_eq!(format_bytes(0), "0 B");
+    assert_eq!(format_bytes(1_023), "1023 B");
+    assert_eq!(format_bytes(1_536), "1.5 KiB");
+    assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+}
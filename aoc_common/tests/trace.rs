@@ -0,0 +1,33 @@
+use aoc_common::trace::{format_event, Value};
+
+#[test]
+fn event() {
+    let fields = [("quantity", Value::from(3usize)), ("from", Value::from(1u8)), ("moved", Value::from("ZND"))];
+    assert_eq!(format_event(5, "move", &fields), r#"{"day":5,"event":"move","quantity":3,"from":1,"moved":"ZND"}"#);
+
+    // an event may have no fields
+    assert_eq!(format_event(14, "blocked", &[]), r#"{"day":14,"event":"blocked"}"#);
+}
+
+#[test]
+fn values() {
+    assert_eq!(Value::from(-4i64).to_json(), "-4");
+    assert_eq!(Value::from(u64::MAX).to_json(), "18446744073709551615");
+    assert_eq!(Value::from(true).to_json(), "true");
+    assert_eq!(Value::from('"').to_json(), r#""\"""#);
+
+    // lists can be nested, such as the positions of the knots of a rope
+    let knots = Value::from(vec![[0i64, 0], [1, -2]]);
+    assert_eq!(knots.to_json(), "[[0,0],[1,-2]]");
+}
+
+#[test]
+fn compiled_out() {
+    // without the `trace` feature, the fields are never evaluated
+    let mut evaluated = false;
+    aoc_common::trace!(1, "test", evaluated = {
+        evaluated = true;
+        evaluated
+    });
+    assert_eq!(evaluated, cfg!(feature = "trace"));
+}
@@ -0,0 +1,299 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Index, IndexMut},
+};
+use aoc_common::{parse::numbered_lines, ParseError};
+use aoc_point::Point;
+
+/// The offsets of the 4 orthogonal neighbours of a cell: north, south, west and east.
+const ORTHOGONAL_OFFSETS: [(isize, isize); 4] = [(0, -1), (0, 1), (-1, 0), (1, 0)];
+
+/// The offsets of the 8 neighbours of a cell, diagonals included, from the north-west to the south-east.
+const ALL_OFFSETS: [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+/// A rectangular grid of cells, stored row by row in a single vector.
+///
+/// The cells are addressed by their `(x, y)` position (or a `Point<usize>`), starting at `(0, 0)` in the top left corner,
+/// with `x` growing to the right and `y` growing downward.
+///
+/// # Fields
+///
+/// * `cells` - The cells of the grid, row by row.
+/// * `width` - The number of cells in a row.
+/// * `height` - The number of rows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid where every cell holds the same value.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Creates a grid where each cell is computed from its `(x, y)` position.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut((usize, usize)) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(&mut cell)
+            .collect();
+
+        Self { cells, width, height }
+    }
+
+    /// Parses a grid from a map of characters, one row per line.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The map, every line must have the same number of characters.
+    /// * `parse_cell` - Converts a character into a cell, given its `(x, y)` position.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the map is empty, if the rows don't all have the same length, or if `parse_cell` fails.
+    /// The errors of `parse_cell` are placed on the offending character, unless they already have a column.
+    pub fn parse(
+        input: &str,
+        mut parse_cell: impl FnMut((usize, usize), char) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        // for each row of the map
+        for (line_number, line) in numbered_lines(input) {
+            let y = line_number - 1;
+            let mut row_width = 0;
+
+            // parse each character of the row
+            for (x, (index, char)) in line.char_indices().enumerate() {
+                let cell = parse_cell((x, y), char).map_err(|error| {
+                    error
+                        .within(line, &line[index..index + char.len_utf8()])
+                        .at_line(line_number, line)
+                })?;
+                cells.push(cell);
+                row_width += 1;
+            }
+
+            // the grid is a rectangle, so every row must have the same length as the first one
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::new(format!("expected a row of {width} cells, found {row_width} cells"))
+                        .at_line(line_number, line));
+                }
+                Some(_) => {}
+            }
+
+            height += 1;
+        }
+
+        // a grid without any cell is useless to every puzzle
+        let width = width.filter(|&width| width > 0).ok_or_else(|| ParseError::new("the map doesn't contain any cell"))?;
+
+        Ok(Self { cells, width, height })
+    }
+
+    /// The number of cells in a row.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Checks if the position is inside of the grid.
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Returns the cell at the given position, or `None` if the position is outside of the grid.
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    /// Returns a mutable reference to the cell at the given position, or `None` if the position is outside of the grid.
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// Iterates over the cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Iterates mutably over the cells, row by row.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    /// Iterates over the positions of the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Iterates over the cells along with their positions, row by row.
+    pub fn enumerate(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Returns the row at the given `y`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is outside of the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(y < self.height, "row {y} is outside of a grid of height {}", self.height);
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns the mutable row at the given `y`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is outside of the grid.
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        assert!(y < self.height, "row {y} is outside of a grid of height {}", self.height);
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Iterates over the rows, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // a grid of width 0 has no cell to split into rows
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Iterates over the cells of the column at the given `x`, from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the column is outside of the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {x} is outside of a grid of width {}", self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Iterates over the columns, from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Iterates over the positions of the 4 orthogonal neighbours of a cell (north, south, west and east) that are inside of the grid.
+    pub fn neighbours(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offset_positions(position, &ORTHOGONAL_OFFSETS)
+    }
+
+    /// Iterates over the positions of the 8 neighbours of a cell, diagonals included, that are inside of the grid.
+    pub fn all_neighbours(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.offset_positions(position, &ALL_OFFSETS)
+    }
+
+    /// Creates a grid of the same size, whose cells are computed from the cells of this grid.
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(cell).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Finds the index of a position in the cells, or `None` if the position is outside of the grid.
+    fn index_of(&self, (x, y): (usize, usize)) -> Option<usize> {
+        self.contains((x, y)).then_some(y * self.width + x)
+    }
+
+    /// Applies the offsets to the position, keeping only the positions inside of the grid.
+    fn offset_positions<'a>(
+        &'a self,
+        (x, y): (usize, usize),
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets.iter().filter_map(move |&(dx, dy)| {
+            let neighbour = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+            self.contains(neighbour).then_some(neighbour)
+        })
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    /// Returns the cell at the given `(x, y)` position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is outside of the grid.
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        match self.index_of((x, y)) {
+            Some(index) => &self.cells[index],
+            None => panic!("({x}, {y}) is outside of a {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    /// Returns a mutable reference to the cell at the given `(x, y)` position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the position is outside of the grid.
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        match self.index_of((x, y)) {
+            Some(index) => &mut self.cells[index],
+            None => panic!("({x}, {y}) is outside of a {}x{} grid", self.width, self.height),
+        }
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    /// Returns the cell at the given point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the point is outside of the grid.
+    fn index(&self, point: Point<usize>) -> &Self::Output {
+        &self[(point.x, point.y)]
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    /// Returns a mutable reference to the cell at the given point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the point is outside of the grid.
+    fn index_mut(&mut self, point: Point<usize>) -> &mut Self::Output {
+        &mut self[(point.x, point.y)]
+    }
+}
+
+/// Displays the cells row by row, with one line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
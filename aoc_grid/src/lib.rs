@@ -0,0 +1,5 @@
+//! A two-dimensional grid shared by the days working on maps.
+
+pub mod grid;
+
+pub use grid::Grid;
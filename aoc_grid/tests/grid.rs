@@ -0,0 +1,61 @@
+use aoc_common::ParseError;
+use aoc_grid::Grid;
+
+/// Parses a map of digits.
+fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(input, |_, char| char.to_digit(10).ok_or_else(|| ParseError::new(format!("expected a digit, found `{char}`"))))
+}
+
+#[test]
+fn parse_and_index() {
+    let grid = digits("123\n456").unwrap();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(0, 0)], 1);
+    assert_eq!(grid[(2, 1)], 6);
+    assert_eq!(grid.get((1, 1)), Some(&5));
+    assert_eq!(grid.get((3, 0)), None);
+    assert_eq!(grid.get((0, 2)), None);
+}
+
+#[test]
+fn parse_errors() {
+    // the offending character is underlined
+    let error = digits("123\n4x6").unwrap_err();
+    assert_eq!((error.line, error.column, error.length), (Some(2), Some(2), 1));
+
+    // ragged rows span the whole line
+    let error = digits("123\n45").unwrap_err();
+    assert_eq!((error.line, error.column, error.length), (Some(2), Some(1), 2));
+    assert_eq!(error.message, "expected a row of 3 cells, found 2 cells");
+
+    assert!(digits("").is_err());
+}
+
+#[test]
+fn rows_and_columns() {
+    let grid = digits("123\n456").unwrap();
+    assert_eq!(grid.row(1), &[4, 5, 6]);
+    assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&[1, 2, 3][..], &[4, 5, 6][..]]);
+    assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+    assert_eq!(grid.columns().map(|column| column.sum::<u32>()).collect::<Vec<_>>(), vec![5, 7, 9]);
+    assert_eq!(grid.enumerate().nth(4), Some(((1, 1), &5)));
+}
+
+#[test]
+fn neighbours() {
+    let grid = Grid::new(3, 3, 0);
+
+    // the neighbours outside of the grid are skipped
+    assert_eq!(grid.neighbours((0, 0)).collect::<Vec<_>>(), vec![(0, 1), (1, 0)]);
+    assert_eq!(grid.neighbours((1, 1)).count(), 4);
+    assert_eq!(grid.all_neighbours((2, 2)).collect::<Vec<_>>(), vec![(1, 1), (2, 1), (1, 2)]);
+    assert_eq!(grid.all_neighbours((1, 1)).count(), 8);
+}
+
+#[test]
+fn display() {
+    let mut grid = Grid::from_fn(3, 2, |(x, y)| x + y);
+    grid[(0, 0)] = 9;
+    assert_eq!(grid.to_string(), "912\n123");
+    assert_eq!(grid.map(|&cell| if cell > 1 { '#' } else { '.' }).to_string(), "#.#\n.##");
+}
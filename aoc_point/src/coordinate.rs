@@ -0,0 +1,93 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    ops::{Add, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+/// A number that can be used as the coordinate of a point.
+///
+/// It is implemented for every primitive integer, signed or not.
+pub trait Coordinate:
+    Copy + Ord + Hash + Default + Debug + Display + FromStr + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    /// The coordinate of the origin.
+    const ZERO: Self;
+    /// The length of a single step.
+    const ONE: Self;
+
+    /// The absolute difference between two coordinates.
+    fn distance(self, other: Self) -> Self;
+
+    /// Adds two coordinates, or returns `None` if the result doesn't fit.
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Subtracts two coordinates, or returns `None` if the result doesn't fit.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+}
+
+/// A coordinate that can be negative, so a point using it can also represent a direction.
+pub trait SignedCoordinate: Coordinate + Neg<Output = Self> {
+    /// The absolute value of the coordinate.
+    fn abs(self) -> Self;
+
+    /// `-1`, `0` or `1` depending on the sign of the coordinate.
+    fn signum(self) -> Self;
+}
+
+/// Implements `Coordinate` for unsigned integers.
+macro_rules! unsigned_coordinate {
+    ($($type:ty),*) => {$(
+        impl Coordinate for $type {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn distance(self, other: Self) -> Self {
+                self.abs_diff(other)
+            }
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$type>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$type>::checked_sub(self, other)
+            }
+        }
+    )*};
+}
+
+/// Implements `Coordinate` and `SignedCoordinate` for signed integers.
+macro_rules! signed_coordinate {
+    ($($type:ty),*) => {$(
+        impl Coordinate for $type {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn distance(self, other: Self) -> Self {
+                (self - other).abs()
+            }
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$type>::checked_add(self, other)
+            }
+
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$type>::checked_sub(self, other)
+            }
+        }
+
+        impl SignedCoordinate for $type {
+            fn abs(self) -> Self {
+                <$type>::abs(self)
+            }
+
+            fn signum(self) -> Self {
+                <$type>::signum(self)
+            }
+        }
+    )*};
+}
+
+unsigned_coordinate!(u8, u16, u32, u64, u128, usize);
+signed_coordinate!(i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,108 @@
+use crate::{coordinate::SignedCoordinate, point::Point};
+
+/// One of the 4 orthogonal directions.
+///
+/// The `y` axis grows downward, like the lines of a puzzle input: going north decreases `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Every direction, clockwise from the north.
+    pub const ALL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// The direction pointing the other way.
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// The direction after a quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+        }
+    }
+
+    /// The direction after a quarter turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        self.opposite().turn_right()
+    }
+
+    /// The vector of a single step in this direction.
+    pub fn offset<T: SignedCoordinate>(self) -> Point<T> {
+        Direction8::from(self).offset()
+    }
+}
+
+/// One of the 8 directions, diagonals included.
+///
+/// The `y` axis grows downward, like the lines of a puzzle input: going north decreases `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Every direction, clockwise from the north.
+    pub const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    /// The signs of the `x` and `y` components of a step in this direction.
+    pub fn unit(self) -> (i8, i8) {
+        match self {
+            Self::North => (0, -1),
+            Self::NorthEast => (1, -1),
+            Self::East => (1, 0),
+            Self::SouthEast => (1, 1),
+            Self::South => (0, 1),
+            Self::SouthWest => (-1, 1),
+            Self::West => (-1, 0),
+            Self::NorthWest => (-1, -1),
+        }
+    }
+
+    /// The vector of a single step in this direction.
+    pub fn offset<T: SignedCoordinate>(self) -> Point<T> {
+        let component = |sign: i8| match sign {
+            -1 => -T::ONE,
+            1 => T::ONE,
+            _ => T::ZERO,
+        };
+
+        let (x, y) = self.unit();
+        Point::new(component(x), component(y))
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::North => Self::North,
+            Direction::East => Self::East,
+            Direction::South => Self::South,
+            Direction::West => Self::West,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+//! A two-dimensional point, or vector, shared by the days working with coordinates.
+
+pub mod coordinate;
+pub mod direction;
+pub mod point;
+
+pub use coordinate::{Coordinate, SignedCoordinate};
+pub use direction::{Direction, Direction8};
+pub use point::Point;
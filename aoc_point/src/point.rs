@@ -0,0 +1,170 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+use aoc_common::{parse::parse_number, ParseError};
+use crate::{
+    coordinate::{Coordinate, SignedCoordinate},
+    direction::Direction8,
+};
+
+/// A point in a 2D space, or the vector between two points.
+///
+/// # Fields
+///
+/// * `x` - The x coordinate, growing to the right.
+/// * `y` - The y coordinate, growing downward like the lines of a puzzle input.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    /// Creates a new point using the given coordinates.
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// Converts the coordinates to another type, such as from `isize` to `usize`.
+    ///
+    /// # Returns
+    ///
+    /// The converted point, or `None` if a coordinate doesn't fit in the new type.
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Point<U>> {
+        Some(Point::new(U::try_from(self.x).ok()?, U::try_from(self.y).ok()?))
+    }
+}
+
+impl<T: Coordinate> Point<T> {
+    /// The origin, `(0, 0)`.
+    pub const ORIGIN: Self = Self::new(T::ZERO, T::ZERO);
+
+    /// The Manhattan distance to another point: the number of orthogonal steps needed to reach it.
+    pub fn manhattan_distance(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    /// The Chebyshev distance to another point: the number of steps needed to reach it when diagonal steps are allowed.
+    pub fn chebyshev_distance(self, other: Self) -> T {
+        self.x.distance(other.x).max(self.y.distance(other.y))
+    }
+
+    /// Moves a single step in the given direction.
+    ///
+    /// # Returns
+    ///
+    /// The new point, or `None` if a coordinate overflows, such as when going north from `y = 0` with unsigned coordinates.
+    pub fn checked_step(self, direction: impl Into<Direction8>) -> Option<Self> {
+        let step = |coordinate: T, sign: i8| match sign {
+            -1 => coordinate.checked_sub(T::ONE),
+            1 => coordinate.checked_add(T::ONE),
+            _ => Some(coordinate),
+        };
+
+        let (x, y) = direction.into().unit();
+        Some(Self::new(step(self.x, x)?, step(self.y, y)?))
+    }
+}
+
+impl<T: SignedCoordinate> Point<T> {
+    /// Moves a single step in the given direction.
+    pub fn step(self, direction: impl Into<Direction8>) -> Self {
+        self + direction.into().offset()
+    }
+
+    /// Reduces the vector to at most one step on each axis, keeping its direction.
+    pub fn signum(self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Self;
+
+    /// Scales the vector.
+    fn mul(self, factor: T) -> Self::Output {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+/// Parses a point, such as `498,4` or `x=-2, y=15`.
+///
+/// The column of the error is relative to the given string.
+impl<T: Coordinate> FromStr for Point<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // split the coordinates, using whichever form the point is written in
+        let coordinates = match s.strip_prefix("x=") {
+            Some(assignments) => assignments.split_once(", y="),
+            None => s.split_once(','),
+        };
+        let (x, y) = coordinates
+            .ok_or_else(|| ParseError::new(format!("expected a point such as `498,4` or `x=-2, y=15`, found `{s}`")).within(s, s))?;
+
+        // try to parse the x and y coordinates
+        let x = parse_number(x, "an x coordinate").map_err(|error| error.within(s, x))?;
+        let y = parse_number(y, "a y coordinate").map_err(|error| error.within(s, y))?;
+
+        Ok(Self::new(x, y))
+    }
+}
+
+/// Displays the point as `x,y`.
+impl<T: Display> Display for Point<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
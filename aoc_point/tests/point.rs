@@ -0,0 +1,67 @@
+use aoc_common::ParseError;
+use aoc_point::{Direction, Direction8, Point};
+
+#[test]
+fn arithmetic() {
+    let a = Point::new(3, -2);
+    let b = Point::new(-1, 5);
+    assert_eq!(a + b, Point::new(2, 3));
+    assert_eq!(a - b, Point::new(4, -7));
+    assert_eq!(-a, Point::new(-3, 2));
+    assert_eq!(a * 3, Point::new(9, -6));
+    assert_eq!((a - b).signum(), Point::new(1, -1));
+
+    let mut c = Point::ORIGIN;
+    c += a;
+    c -= b;
+    assert_eq!(c, a - b);
+}
+
+#[test]
+fn distances() {
+    let a = Point::new(8_isize, 7);
+    let b = Point::new(2, 10);
+    assert_eq!(a.manhattan_distance(b), 9);
+    assert_eq!(a.chebyshev_distance(b), 6);
+
+    // unsigned points don't underflow
+    assert_eq!(Point::new(0_usize, 5).manhattan_distance(Point::new(3, 1)), 7);
+}
+
+#[test]
+fn directions() {
+    let point = Point::new(0_i64, 0);
+    assert_eq!(point.step(Direction::North), Point::new(0, -1));
+    assert_eq!(point.step(Direction8::SouthEast), Point::new(1, 1));
+    assert_eq!(Direction::North.turn_right(), Direction::East);
+    assert_eq!(Direction::North.turn_left(), Direction::West);
+    assert_eq!(Direction::East.opposite(), Direction::West);
+
+    // unsigned points can't go below 0
+    let corner = Point::new(0_usize, 0);
+    assert_eq!(corner.checked_step(Direction::North), None);
+    assert_eq!(corner.checked_step(Direction::South), Some(Point::new(0, 1)));
+    assert_eq!(Point::new(u8::MAX, 0).checked_step(Direction8::NorthEast), None);
+}
+
+#[test]
+fn conversions() {
+    assert_eq!(Point::new(3_isize, 4).try_cast::<usize>(), Some(Point::new(3, 4)));
+    assert_eq!(Point::new(-3_isize, 4).try_cast::<usize>(), None);
+    assert_eq!(Point::new(300_i64, 4).try_cast::<u8>(), None);
+    assert_eq!(<(i32, i32)>::from(Point::new(1, 2)), (1, 2));
+}
+
+#[test]
+fn parse() {
+    assert_eq!("498,4".parse(), Ok(Point::new(498_usize, 4)));
+    assert_eq!("x=-2, y=15".parse(), Ok(Point::new(-2_isize, 15)));
+    assert_eq!(Point::new(498, 4).to_string(), "498,4");
+
+    // the errors point at the offending coordinate
+    let error = "x=2, y=a".parse::<Point<isize>>().unwrap_err();
+    assert_eq!((error.column, error.length), (Some(8), 1));
+    let error = "-1,4".parse::<Point<usize>>().unwrap_err();
+    assert_eq!((error.column, error.length), (Some(1), 2));
+    assert!("498".parse::<Point<usize>>().is_err_and(|error: ParseError| error.length == 3));
+}
@@ -0,0 +1,68 @@
+/// A color, made of its red, green and blue components.
+/// 
+/// # Fields
+/// 
+/// * `red` - The red component, from 0 to 255.
+/// * `green` - The green component, from 0 to 255.
+/// * `blue` - The blue component, from 0 to 255.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+impl Color {
+    pub const BLACK: Self = Self::rgb(0, 0, 0);
+    pub const WHITE: Self = Self::rgb(255, 255, 255);
+    pub const GRAY: Self = Self::rgb(128, 128, 128);
+    pub const DARK_GRAY: Self = Self::rgb(64, 64, 64);
+    pub const RED: Self = Self::rgb(220, 50, 47);
+    pub const GREEN: Self = Self::rgb(80, 200, 80);
+    pub const BLUE: Self = Self::rgb(38, 139, 210);
+    pub const YELLOW: Self = Self::rgb(240, 200, 40);
+    pub const SAND: Self = Self::rgb(230, 190, 120);
+
+    /// Creates a color from its components.
+    pub const fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self { red, green, blue }
+    }
+
+    /// Mixes two colors.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The color to mix with.
+    /// * `ratio` - How much of the other color is used, from 0 (only this color) to 1 (only the other color).
+    pub fn mix(self, other: Self, ratio: f64) -> Self {
+        let ratio = ratio.clamp(0.0, 1.0);
+        let mix = |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * ratio).round() as u8;
+        Self::rgb(mix(self.red, other.red), mix(self.green, other.green), mix(self.blue, other.blue))
+    }
+
+    /// Picks a bright color for a label, so that different labels are easy to tell apart, and the same label always has the same color.
+    pub fn of_label(label: impl Into<u32>) -> Self {
+        // spread the hues using the golden angle
+        let hue = (label.into() as f64 * 137.508) % 360.0;
+        Self::from_hue(hue)
+    }
+
+    /// Creates a bright color from its hue, in degrees.
+    fn from_hue(hue: f64) -> Self {
+        // convert from HSV, with a high saturation and value
+        let (saturation, value) = (0.65, 0.95);
+        let chroma = value * saturation;
+        let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+        let (red, green, blue) = match (hue / 60.0) as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let offset = value - chroma;
+        let component = |c: f64| ((c + offset) * 255.0).round() as u8;
+        Self::rgb(component(red), component(green), component(blue))
+    }
+}
@@ -0,0 +1,123 @@
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Index, IndexMut},
+};
+use aoc_grid::Grid;
+use crate::color::Color;
+
+/// A cell of a frame: a character displayed in a color.
+/// 
+/// In the terminal, the character is displayed in its color. In a GIF, the whole cell is filled with its color.
+/// 
+/// # Fields
+/// 
+/// * `symbol` - The character displayed in the terminal.
+/// * `color` - The color of the character, and of the whole cell in a GIF.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub symbol: char,
+    pub color: Color,
+}
+
+impl Cell {
+    /// A cell with nothing in it.
+    pub const EMPTY: Self = Self::new(' ', Color::BLACK);
+
+    /// Creates a cell.
+    pub const fn new(symbol: char, color: Color) -> Self {
+        Self { symbol, color }
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+/// Displays the symbol of the cell, without its color.
+impl Display for Cell {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol)
+    }
+}
+
+/// A single image of an animation, made of cells.
+/// 
+/// The cells are addressed by their `(x, y)` position, starting at `(0, 0)` in the top left corner.
+/// 
+/// # Fields
+/// 
+/// * `cells` - The cells of the frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    cells: Grid<Cell>,
+}
+
+impl Frame {
+    /// Creates an empty frame.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self { cells: Grid::new(width, height, Cell::EMPTY) }
+    }
+
+    /// Creates a frame from a grid of cells.
+    pub fn from_grid(cells: Grid<Cell>) -> Self {
+        Self { cells }
+    }
+
+    /// The number of cells in a row.
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    /// The number of rows.
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    /// The cells of the frame.
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    /// Writes a text on a row, starting at the given position.
+    /// The characters that don't fit in the frame are left out.
+    pub fn write_text(&mut self, (x, y): (usize, usize), text: &str, color: Color) {
+        for (offset, symbol) in text.chars().enumerate() {
+            if let Some(cell) = self.cells.get_mut((x + offset, y)) {
+                *cell = Cell::new(symbol, color);
+            }
+        }
+    }
+}
+
+impl Index<(usize, usize)> for Frame {
+    type Output = Cell;
+
+    /// Returns the cell at the given position.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the position is outside of the frame.
+    fn index(&self, position: (usize, usize)) -> &Self::Output {
+        &self.cells[position]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Frame {
+    /// Returns a mutable reference to the cell at the given position.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if the position is outside of the frame.
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut Self::Output {
+        &mut self.cells[position]
+    }
+}
+
+/// Displays the symbols of the frame, one line per row, without their colors.
+impl Display for Frame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.cells)
+    }
+}
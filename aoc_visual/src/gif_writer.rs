@@ -0,0 +1,128 @@
+use std::{
+    collections::HashMap,
+    io::{self, Write},
+    iter,
+    time::Duration,
+};
+use gif::{Encoder, Repeat};
+use crate::{color::Color, frame::Frame, visualizer::Visualizer};
+
+/// The highest number of colors a frame of a GIF can use.
+const MAX_COLORS: usize = 256;
+
+/// Writes the frames of an animation to an animated GIF, which loops forever.
+/// 
+/// Each cell of a frame becomes a square of `scale` by `scale` pixels, filled with the color of the cell.
+/// 
+/// # Fields
+/// 
+/// * `output` - Where the GIF is written, until the first frame creates the encoder.
+/// * `encoder` - The encoder, created with the size of the first frame.
+/// * `scale` - The size of the square of pixels of each cell.
+/// * `delay` - How long each frame is displayed, in hundredths of a second.
+/// * `size` - The size of the frames, in cells, once the first frame is known.
+pub struct GifWriter<W: Write> {
+    output: Option<W>,
+    encoder: Option<Encoder<W>>,
+    scale: usize,
+    delay: u16,
+    size: Option<(usize, usize)>,
+}
+
+impl<W: Write> GifWriter<W> {
+    /// Creates a visualizer writing a GIF to the given output.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `output` - Where the GIF is written.
+    /// * `scale` - The size of the square of pixels of each cell (at least 1).
+    /// * `delay` - How long each frame is displayed, GIFs have a precision of a hundredth of a second.
+    pub fn new(output: W, scale: usize, delay: Duration) -> Self {
+        Self {
+            output: Some(output),
+            encoder: None,
+            scale: scale.max(1),
+            delay: (delay.as_millis() / 10).min(u16::MAX as u128) as u16,
+            size: None,
+        }
+    }
+
+    /// Creates the encoder, using the size of the first frame.
+    fn start(&mut self, width: usize, height: usize) -> io::Result<&mut Encoder<W>> {
+        let too_big = || io::Error::new(io::ErrorKind::InvalidInput, "the frames are too big for a GIF");
+        let pixel_width = u16::try_from(width * self.scale).map_err(|_| too_big())?;
+        let pixel_height = u16::try_from(height * self.scale).map_err(|_| too_big())?;
+
+        let output = self.output.take().ok_or_else(|| io::Error::other("the GIF was already finished"))?;
+        let mut encoder = Encoder::new(output, pixel_width, pixel_height, &[]).map_err(io::Error::other)?;
+        encoder.set_repeat(Repeat::Infinite).map_err(io::Error::other)?;
+
+        self.size = Some((width, height));
+        Ok(self.encoder.insert(encoder))
+    }
+}
+
+impl<W: Write> Visualizer for GifWriter<W> {
+    fn show(&mut self, frame: &Frame) -> io::Result<()> {
+        // every frame must have the size of the first one
+        let (width, height) = (frame.width(), frame.height());
+        match self.size {
+            Some(size) if size != (width, height) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("a frame of {width}x{height} cells doesn't fit in a GIF of {}x{} cells", size.0, size.1),
+                ));
+            }
+            _ => {}
+        }
+
+        // each frame has its own palette, holding the colors it uses
+        let mut palette: Vec<Color> = vec![];
+        let mut palette_indexes: HashMap<Color, u8> = HashMap::new();
+        let mut cell_indexes = Vec::with_capacity(width * height);
+        for cell in frame.cells().iter() {
+            let index = match palette_indexes.get(&cell.color) {
+                Some(&index) => index,
+                None if palette.len() < MAX_COLORS => {
+                    palette.push(cell.color);
+                    palette_indexes.insert(cell.color, (palette.len() - 1) as u8);
+                    (palette.len() - 1) as u8
+                }
+                None => {
+                    return Err(io::Error::new(io::ErrorKind::InvalidInput, "a frame of a GIF can't use more than 256 colors"));
+                }
+            };
+            cell_indexes.push(index);
+        }
+
+        // scale each cell up into a square of pixels
+        let scale = self.scale;
+        let mut pixels = Vec::with_capacity(cell_indexes.len() * scale * scale);
+        for row in cell_indexes.chunks(width.max(1)) {
+            let scaled_row: Vec<u8> = row.iter().flat_map(|&index| iter::repeat_n(index, scale)).collect();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&scaled_row);
+            }
+        }
+
+        // write the frame
+        let delay = self.delay;
+        let encoder = match self.encoder.as_mut() {
+            Some(encoder) => encoder,
+            None => self.start(width, height)?,
+        };
+        let colors: Vec<u8> = palette.iter().flat_map(|color| [color.red, color.green, color.blue]).collect();
+        let mut gif_frame =
+            gif::Frame::from_palette_pixels((width * scale) as u16, (height * scale) as u16, pixels, colors, None);
+        gif_frame.delay = delay;
+        encoder.write_frame(&gif_frame).map_err(io::Error::other)
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        // dropping the encoder writes the end of the GIF
+        if let Some(encoder) = self.encoder.take() {
+            encoder.into_inner()?.flush()?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+//! Animations of the days simulating something, displayed in the terminal or written to a GIF.
+
+pub mod color;
+pub mod frame;
+pub mod gif_writer;
+pub mod terminal;
+pub mod visualizer;
+
+pub use color::Color;
+pub use frame::{Cell, Frame};
+pub use gif_writer::GifWriter;
+pub use terminal::Terminal;
+pub use visualizer::{parse_frames, play, Frames, Visualize, Visualizer};
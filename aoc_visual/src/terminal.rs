@@ -0,0 +1,79 @@
+use std::{
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+use crate::{color::Color, frame::Frame, visualizer::Visualizer};
+
+/// Displays the frames of an animation in the terminal, using ANSI escape codes.
+/// 
+/// Each frame is drawn over the previous one, in the top left corner of the terminal.
+/// 
+/// # Fields
+/// 
+/// * `output` - Where the escape codes are written, usually the standard output.
+/// * `delay` - How long each frame stays on screen.
+/// * `started` - Whether the screen was already cleared for the first frame.
+pub struct Terminal<W: Write> {
+    output: W,
+    delay: Duration,
+    started: bool,
+}
+
+impl Terminal<io::Stdout> {
+    /// Creates a visualizer drawing on the standard output.
+    pub fn stdout(delay: Duration) -> Self {
+        Self::new(io::stdout(), delay)
+    }
+}
+
+impl<W: Write> Terminal<W> {
+    /// Creates a visualizer writing the escape codes to the given output.
+    pub fn new(output: W, delay: Duration) -> Self {
+        Self { output, delay, started: false }
+    }
+
+    /// Returns the output, once the animation is over.
+    pub fn into_inner(self) -> W {
+        self.output
+    }
+}
+
+impl<W: Write> Visualizer for Terminal<W> {
+    fn show(&mut self, frame: &Frame) -> io::Result<()> {
+        let mut screen = String::new();
+
+        // clear the screen and hide the cursor before the first frame, then draw each frame over the previous one
+        if !self.started {
+            screen.push_str("\x1b[?25l\x1b[2J");
+            self.started = true;
+        }
+        screen.push_str("\x1b[H");
+
+        // draw each row, only changing the color when needed
+        for row in frame.cells().rows() {
+            let mut current_color = None;
+            for cell in row {
+                if current_color != Some(cell.color) {
+                    let Color { red, green, blue } = cell.color;
+                    screen.push_str(&format!("\x1b[38;2;{red};{green};{blue}m"));
+                    current_color = Some(cell.color);
+                }
+                screen.push(cell.symbol);
+            }
+            screen.push_str("\x1b[0m\n");
+        }
+
+        // the frame is written at once, so it doesn't flicker
+        self.output.write_all(screen.as_bytes())?;
+        self.output.flush()?;
+        thread::sleep(self.delay);
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        // show the cursor again
+        self.output.write_all(b"\x1b[0m\x1b[?25h")?;
+        self.output.flush()
+    }
+}
@@ -0,0 +1,55 @@
+use std::io;
+use aoc_common::{ParseError, Solution};
+use crate::frame::Frame;
+
+/// The frames of an animation, computed one at a time.
+pub type Frames = Box<dyn Iterator<Item = Frame>>;
+
+/// A day whose simulation can be animated.
+pub trait Visualize: Solution {
+    /// Creates the frames of the simulation, from the parsed puzzle input.
+    /// All the frames must have the same size.
+    fn frames(input: Self::Input) -> Frames;
+}
+
+/// Parses the puzzle input, and creates the frames of the simulation of the day.
+pub fn parse_frames<V: Visualize>(input: &str) -> Result<Frames, ParseError> {
+    Ok(V::frames(V::parse_input(input)?))
+}
+
+/// Where the frames of an animation are displayed or saved.
+pub trait Visualizer {
+    /// Displays or saves the next frame.
+    fn show(&mut self, frame: &Frame) -> io::Result<()>;
+
+    /// Finishes the animation, once every frame was shown.
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Shows the frames using the visualizer.
+/// 
+/// # Arguments
+/// 
+/// * `frames` - The frames of the animation.
+/// * `visualizer` - Where the frames are shown.
+/// * `every` - Only one frame out of `every` is shown, to speed up long animations. The last frame is always shown.
+/// 
+/// # Returns
+/// 
+/// The number of frames that were shown.
+pub fn play(frames: impl Iterator<Item = Frame>, visualizer: &mut dyn Visualizer, every: usize) -> io::Result<usize> {
+    let mut frames = frames.enumerate().peekable();
+    let mut shown = 0;
+
+    while let Some((index, frame)) = frames.next() {
+        if index % every.max(1) == 0 || frames.peek().is_none() {
+            visualizer.show(&frame)?;
+            shown += 1;
+        }
+    }
+
+    visualizer.finish()?;
+    Ok(shown)
+}
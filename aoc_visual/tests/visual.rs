@@ -0,0 +1,102 @@
+use std::{io, time::Duration};
+use aoc_visual::{play, Cell, Color, Frame, GifWriter, Terminal, Visualizer};
+
+/// A visualizer remembering the frames it was shown.
+#[derive(Default)]
+struct Recorder {
+    frames: Vec<String>,
+    finished: bool,
+}
+
+impl Visualizer for Recorder {
+    fn show(&mut self, frame: &Frame) -> io::Result<()> {
+        self.frames.push(frame.to_string());
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.finished = true;
+        Ok(())
+    }
+}
+
+/// Creates a frame showing a number.
+fn numbered_frame(number: usize) -> Frame {
+    let mut frame = Frame::new(2, 1);
+    frame.write_text((0, 0), &format!("{number:02}"), Color::WHITE);
+    frame
+}
+
+#[test]
+fn frame() {
+    let mut frame = Frame::new(4, 2);
+    frame.write_text((1, 0), "abcdef", Color::RED);
+    frame[(0, 1)] = Cell::new('#', Color::GRAY);
+
+    // the text is cut at the edge of the frame
+    assert_eq!(frame.to_string(), " abc\n#   ");
+    assert_eq!(frame[(3, 0)], Cell::new('c', Color::RED));
+    assert_eq!(frame[(3, 1)], Cell::EMPTY);
+}
+
+#[test]
+fn colors() {
+    assert_eq!(Color::BLACK.mix(Color::WHITE, 0.5), Color::rgb(128, 128, 128));
+    assert_eq!(Color::RED.mix(Color::BLUE, 0.0), Color::RED);
+    assert_eq!(Color::of_label(7u8), Color::of_label(7u8));
+    assert_ne!(Color::of_label(1u8), Color::of_label(2u8));
+}
+
+#[test]
+fn skipped_frames() {
+    let mut recorder = Recorder::default();
+    let shown = play((0..10).map(numbered_frame), &mut recorder, 4).unwrap();
+
+    // the last frame is always shown
+    assert_eq!(shown, 4);
+    assert_eq!(recorder.frames, ["00", "04", "08", "09"]);
+    assert!(recorder.finished);
+}
+
+#[test]
+fn terminal() {
+    let mut terminal = Terminal::new(vec![], Duration::ZERO);
+    play([numbered_frame(42)].into_iter(), &mut terminal, 1).unwrap();
+    let output = String::from_utf8(terminal.into_inner()).unwrap();
+
+    assert!(output.starts_with("\x1b[?25l\x1b[2J\x1b[H"));
+    assert!(output.contains("\x1b[38;2;255;255;255m42\x1b[0m\n"));
+    assert!(output.ends_with("\x1b[?25h"));
+}
+
+#[test]
+fn gif() {
+    // write two frames of 2x1 cells, scaled up to 6x3 pixels
+    let mut bytes = vec![];
+    let mut first = Frame::new(2, 1);
+    first[(1, 0)] = Cell::new('#', Color::RED);
+    play([first, Frame::new(2, 1)].into_iter(), &mut GifWriter::new(&mut bytes, 3, Duration::from_millis(50)), 1).unwrap();
+
+    // decode the GIF to check its pixels
+    let mut options = gif::DecodeOptions::new();
+    options.set_color_output(gif::ColorOutput::RGBA);
+    let mut decoder = options.read_info(bytes.as_slice()).unwrap();
+    assert_eq!((decoder.width(), decoder.height()), (6, 3));
+
+    let frame = decoder.read_next_frame().unwrap().unwrap();
+    assert_eq!(frame.delay, 5);
+    let pixel = |x: usize, y: usize| frame.buffer[(y * 6 + x) * 4..(y * 6 + x) * 4 + 3].to_vec();
+    assert_eq!(pixel(2, 2), [0, 0, 0]);
+    assert_eq!(pixel(3, 0), [220, 50, 47]);
+    assert_eq!(pixel(5, 2), [220, 50, 47]);
+
+    assert!(decoder.read_next_frame().unwrap().is_some());
+    assert!(decoder.read_next_frame().unwrap().is_none());
+}
+
+#[test]
+fn gif_frames_of_different_sizes() {
+    let mut writer = GifWriter::new(vec![], 1, Duration::ZERO);
+    writer.show(&Frame::new(2, 2)).unwrap();
+    assert!(writer.show(&Frame::new(3, 2)).is_err());
+}
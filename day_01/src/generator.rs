@@ -0,0 +1,14 @@
+use aoc_common::random::Rng;
+
+/// Generates a valid input made of `size` elves (at least 3), each carrying 1 to 15 food items.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let elves: Vec<String> = (0..size.max(3))
+        .map(|_| {
+            (0..rng.usize(1..16))
+                .map(|_| format!("{}\n", rng.usize(1_000..70_000)))
+                .collect()
+        })
+        .collect();
+
+    elves.join("\n")
+}
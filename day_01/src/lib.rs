@@ -0,0 +1,204 @@
+pub mod generator;
+pub mod ranking;
+pub mod statistics;
+
+use std::io::BufRead;
+use aoc_common::{parse::{blank_line_groups, parse_number, reader_blank_line_groups}, Arguments, Parameters, ParseError, Solution, Streaming};
+use ranking::{top_k, TopElves, TopK};
+
+/// A struct to store how many calories an elf is carrying.
+/// 
+/// # Fields
+/// 
+/// * `elf` - The index of the elf the data is about. The index starts at 1.
+/// * `calories` - The number of calories the elf is carrying.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElfCalories {
+    pub elf: usize,
+    pub calories: usize,
+}
+
+/// The food items an elf is carrying.
+/// 
+/// # Fields
+/// 
+/// * `elf` - The index of the elf the data is about. The index starts at 1.
+/// * `items` - The calories of each food item, in the order of the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElfInventory {
+    pub elf: usize,
+    pub items: Vec<usize>,
+}
+
+impl ElfInventory {
+    /// The number of calories the elf is carrying.
+    /// 
+    /// The calories must fit in a `usize`, which [`parse_elves_inventories`] checks.
+    pub fn calories(&self) -> ElfCalories {
+        ElfCalories { elf: self.elf, calories: self.items.iter().sum() }
+    }
+}
+
+/// Parses the items carried by an elf, one item per line, and checks that their calories can be added up.
+/// 
+/// # Arguments
+/// 
+/// * `elf` - The index of the elf, starting at 1.
+/// * `lines` - The lines listing the items of the elf, along with their number.
+fn parse_elf_inventory(elf: usize, lines: &[(usize, impl AsRef<str>)]) -> Result<ElfInventory, ParseError> {
+    let mut items = Vec::with_capacity(lines.len());
+    let mut calories: usize = 0;
+
+    for (line_number, line) in lines {
+        let line = line.as_ref();
+        let item: usize = parse_number(line, "a number of calories").map_err(|error| error.at_line(*line_number, line))?;
+
+        // the total of the elf must fit, so it is checked while the items are added up
+        calories = calories.checked_add(item).ok_or_else(|| {
+            ParseError::new(format!("the calories carried by elf {elf} are too big to be added up")).at_line(*line_number, line)
+        })?;
+        items.push(item);
+    }
+
+    Ok(ElfInventory { elf, items })
+}
+
+/// Parses the input into the list of the items of each elf, in the order of the input.
+/// 
+/// The elves are separated by blank lines. Several blank lines in a row are a single separator,
+/// and the blank lines at the start and at the end of the input are ignored, so there is no elf without any item.
+/// 
+/// # Errors
+/// 
+/// Returns an error pointing at the first item that isn't a number, or whose calories can't be added up to the other items of its elf,
+/// or an error if the input has no elf at all.
+pub fn parse_elves_inventories(input: &str) -> Result<Vec<ElfInventory>, ParseError> {
+    let elves_inventories = blank_line_groups(input)
+        .enumerate()
+        .map(|(index, lines)| parse_elf_inventory(index + 1, &lines))
+        .collect::<Result<Vec<ElfInventory>, ParseError>>()?;
+
+    // the answers are about the elves, there must be at least one
+    if elves_inventories.is_empty() {
+        return Err(ParseError::new("expected at least one elf, found no item"));
+    }
+
+    Ok(elves_inventories)
+}
+
+/// Parses the input into a list of elves, in the order of the input.
+pub fn parse_elves_calories(input: &str) -> Result<Vec<ElfCalories>, ParseError> {
+    let elves_inventories = parse_elves_inventories(input)?;
+    Ok(elves_inventories.iter().map(ElfInventory::calories).collect())
+}
+
+/// Reads the input one elf at a time, and only keeps the `k` elves carrying the most calories.
+/// 
+/// The elves are separated like [`parse_elves_inventories`] does, and ranked like [`top_k`] does,
+/// so the result is the same as ranking the elves of [`parse_elves_calories`].
+pub fn read_top_elves_calories(reader: impl BufRead, k: usize) -> Result<TopElves, ParseError> {
+    // the elves carrying the most calories seen so far
+    let mut ranking = TopK::new(k);
+    let mut elves_count = 0;
+
+    // only the items of the current elf are kept
+    for (index, lines) in reader_blank_line_groups(reader).enumerate() {
+        let elf_inventory = parse_elf_inventory(index + 1, &lines?)?;
+        ranking.push(elf_inventory.calories());
+        elves_count += 1;
+    }
+
+    // the answers are about the elves, there must be at least one
+    if elves_count == 0 {
+        return Err(ParseError::new("expected at least one elf, found no item"));
+    }
+
+    Ok(ranking.into_top_elves())
+}
+
+/// Our goal is to find which elves are carrying the most calories.
+pub struct Day01;
+
+/// The number of elves whose calories are added up in the second part.
+/// 
+/// # Fields
+/// 
+/// * `top` - The number of elves carrying the most calories that are added up, 3 in the puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ranking {
+    pub top: usize,
+}
+
+impl Default for Ranking {
+    fn default() -> Self {
+        Self { top: 3 }
+    }
+}
+
+impl Parameters for Ranking {
+    const OPTIONS: &'static [(&'static str, &'static str)] = &[
+        ("top", "the number of elves carrying the most calories that are added up in the second part (3 by default)"),
+    ];
+
+    fn from_arguments(arguments: &Arguments) -> Result<Self, String> {
+        let ranking = Self { top: arguments.parameter("top", Self::default().top)? };
+
+        // an empty ranking has nothing to report
+        if ranking.top == 0 {
+            return Err("At least one elf is ranked.".to_owned());
+        }
+        Ok(ranking)
+    }
+}
+
+impl Day01 {
+    /// The elves carrying the most calories, and the calories they carry together.
+    /// 
+    /// There are less elves than asked if the input doesn't have enough elves.
+    pub fn top_elves(elves_calories: &[ElfCalories], ranking: &Ranking) -> TopElves {
+        top_k(elves_calories.iter().copied(), ranking.top)
+    }
+
+    /// The total calories carried by the elves carrying the most calories.
+    pub fn part_two_with(elves_calories: &[ElfCalories], ranking: &Ranking) -> u128 {
+        Self::top_elves(elves_calories, ranking).total
+    }
+
+    /// Reads the input one line at a time, and computes the answers of both parts.
+    pub fn stream_with(reader: impl BufRead, ranking: &Ranking) -> Result<(usize, u128), ParseError> {
+        // the best elf is always part of the ranking
+        let top_elves = read_top_elves_calories(reader, ranking.top.max(1))?;
+        let most_calories = top_elves.elves.first().map_or(0, |elf| elf.calories);
+        let total = top_elves.elves.iter().take(ranking.top).map(|elf| elf.calories as u128).sum();
+
+        Ok((most_calories, total))
+    }
+}
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Vec<ElfCalories>;
+    type PartOne = usize;
+    type PartTwo = u128;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_elves_calories(input)
+    }
+
+    /// The calories carried by the elf with the most calories.
+    fn part_one(elves_calories: &Self::Input) -> Self::PartOne {
+        top_k(elves_calories.iter().copied(), 1).elves.first().map_or(0, |elf| elf.calories)
+    }
+
+    /// The total calories carried by the top three elves.
+    fn part_two(elves_calories: &Self::Input) -> Self::PartTwo {
+        Self::part_two_with(elves_calories, &Ranking::default())
+    }
+}
+
+impl Streaming for Day01 {
+    fn stream(reader: impl BufRead) -> Result<(Self::PartOne, Self::PartTwo), ParseError> {
+        Self::stream_with(reader, &Ranking::default())
+    }
+}
@@ -1,62 +1,113 @@
-use std::fs;
+use std::{env, process};
+use aoc_common::{
+    arguments::binary_name, load_input_or_exit, memory::CountingAllocator, open_input_or_exit, print_record,
+    print_streamed_answers_with, print_timings_with, Arguments, Command, Format, Parameters, Solution,
+};
+use day_01::{
+    parse_elves_inventories, read_top_elves_calories,
+    statistics::{CalorieStatistics, StatisticsOptions},
+    Day01, ElfCalories, Ranking,
+};
 use thousands::Separable;
 
-/// A struct to store how many calories an elf is carrying.
-/// 
-/// # Fields
-/// 
-/// * `elf` - The index of the elf the data is about. The index starts at 1.
-/// * `calories` - The number of calories the elf is carrying.
-#[derive(Debug, Clone, Copy)]
-struct ElfCalories {
-    elf: usize,
-    calories: usize,
-}
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
 
 /// Our goal is to find which elves are carrying the most calories.
 fn main() {
-    // read the input file
-    let input = fs::read_to_string("inputs/day_01.txt").expect("Unable to read the input file");
+    // the statistics of the calories have their own command, such as `stats --buckets 20`
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.first().is_some_and(|arg| arg == "stats") {
+        return print_statistics(&args[1..]);
+    }
 
-    // create a vector of ElfCalories to store the parsed data
-    let mut elves_calories = Vec::new();
+    // read the input file, its path can be given as an argument (`-` for the standard input)
+    // the number of elves added up in the second part can be changed, such as `--top 5`
+    let (arguments, ranking) = Arguments::from_env_with::<Ranking>();
 
-    // create two variable that will store the data about the current elf
-    let mut current_elf = ElfCalories { elf: 1, calories: 0 };
+    // the time taken and the heap used by each phase are measured on the whole input, which is loaded at once
+    if arguments.timings {
+        let input = load_input_or_exit(Day01::DAY, arguments.input.as_deref());
 
-    // parse the data
-    for line in input.lines() {
-        if !line.is_empty() {
-            current_elf.calories += line.parse::<usize>().unwrap();
-        } else {
-            elves_calories.push(current_elf);
-            current_elf.elf += 1;
-            current_elf.calories = 0;
-        }
+        // compute the second part with the given ranking
+        let part_two = |elves_calories: &Vec<ElfCalories>| Day01::part_two_with(elves_calories, &ranking);
+        return print_timings_with::<Day01>(&input, arguments.format, Day01::part_one, part_two);
     }
-    elves_calories.push(current_elf);
 
-    // sort the elves by the number of calories they are carrying
-    elves_calories.sort_by(|e1, e2| e2.calories.cmp(&e1.calories));
+    // the input is solved while it is read, so inputs of any size can be solved with a constant memory
+    let reader = open_input_or_exit(Day01::DAY, arguments.input.as_deref());
+
+    // the answers can be displayed in a machine-readable format instead
+    if arguments.format != Format::Text {
+        let stream = || Day01::stream_with(reader, &ranking);
+        return print_streamed_answers_with::<Day01>(arguments.format, arguments.command, stream);
+    }
 
-    // find the three elf with the most calories
-    let first_elf = elves_calories[0];
-    let second_elf = elves_calories[1];
-    let third_elf = elves_calories[2];
+    // only the elves with the most calories are kept
+    let top_elves = read_top_elves_calories(reader, ranking.top).unwrap_or_else(|error| error.with_day(Day01::DAY).exit());
 
     // display the first elf
-    println!(
-        "The {first}th elf has the most calories: {first_elf_calories}",
-        first = first_elf.elf,
-        first_elf_calories = first_elf.calories.separate_with_commas()
-    );
-
-    // display the total of top three elves
-    println!(
-        "The top three elves are elf {first}, elf {second}, and elf {third}, with a total of {total} calories.",
-        first = first_elf.elf,
-        second = second_elf.elf,
-        third = third_elf.elf,
-        total = (first_elf.calories + second_elf.calories + third_elf.calories).separate_with_commas()
-    )
+    if arguments.command.runs(1) {
+        let first_elf = top_elves.elves[0];
+        println!(
+            "The {first}th elf has the most calories: {first_elf_calories}",
+            first = first_elf.elf,
+            first_elf_calories = first_elf.calories.separate_with_commas()
+        );
+    }
+
+    // display the total of the top elves
+    if arguments.command.runs(2) {
+        let elves: Vec<String> = top_elves.elves.iter().map(|elf| format!("elf {}", elf.elf)).collect();
+        println!(
+            "The top {count} elves are {elves}, with a total of {total} calories.",
+            count = elves.len(),
+            elves = elves.join(", "),
+            total = top_elves.total.separate_with_commas()
+        )
+    }
+}
+
+/// Explains how to use the statistics, along with their parameters.
+fn statistics_usage() -> String {
+    let mut usage = format!("Usage: {} stats [INPUT] [--format text|json] [--PARAMETER VALUE]...\n\nParameters:", binary_name());
+    for (name, description) in StatisticsOptions::OPTIONS {
+        usage.push_str(&format!("\n    --{name:<9} VALUE  {description}"));
+    }
+    usage
+}
+
+/// Prints the statistics of the calories carried by the elves, exits with a usage message if the arguments are invalid.
+/// 
+/// ```text
+/// day_01 stats [INPUT] [--format text|json] [--buckets N] [--bar-width N]
+/// ```
+fn print_statistics(args: &[String]) {
+    let (arguments, options) = Arguments::parse_with::<StatisticsOptions>(args)
+        .and_then(|(arguments, options)| {
+            // the statistics are about the whole input, and are a single JSON object
+            if arguments.command != Command::Both || arguments.timings || arguments.format == Format::Raw {
+                return Err("The statistics only accept an input, `--format text|json` and their parameters.".to_owned());
+            }
+            Ok((arguments, options))
+        })
+        .unwrap_or_else(|error| {
+            eprintln!("{error}\n\n{}", statistics_usage());
+            process::exit(2);
+        });
+
+    if arguments.help {
+        println!("{}", statistics_usage());
+        process::exit(0);
+    }
+
+    // the statistics need the individual items of each elf
+    let input = load_input_or_exit(Day01::DAY, arguments.input.as_deref());
+    let elves_inventories = parse_elves_inventories(&input).unwrap_or_else(|error| error.with_day(Day01::DAY).exit());
+    let statistics = CalorieStatistics::compute(&elves_inventories, options.buckets);
+
+    match arguments.format {
+        Format::Json => print_record(&statistics.to_json()),
+        Format::Text | Format::Raw => println!("{}", statistics.report(options.bar_width)),
+    }
 }
@@ -0,0 +1,79 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+};
+use crate::ElfCalories;
+
+/// The elves carrying the most calories, and the calories they carry together.
+/// 
+/// # Fields
+/// 
+/// * `elves` - The elves, ranked from the one carrying the most calories. Elves carrying as many calories are ranked by index.
+/// * `total` - The total calories carried by these elves. The calories of each elf fit in a `usize`, but their total may not.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopElves {
+    pub elves: Vec<ElfCalories>,
+    pub total: u128,
+}
+
+/// An elf as compared by the ranking: an elf is greater if it carries more calories, or as many calories with a smaller index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct RankedElf(ElfCalories);
+
+impl Ord for RankedElf {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.calories.cmp(&other.0.calories).then_with(|| other.0.elf.cmp(&self.0.elf))
+    }
+}
+
+impl PartialOrd for RankedElf {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Selects the `k` elves carrying the most calories, one elf at a time.
+/// 
+/// The selected elves are kept in a min-heap of at most `k` elves, whose top is the lowest ranked of them.
+/// Adding an elf takes O(log k), so ranking `n` elves takes O(n log k) and only uses the memory of `k` elves.
+#[derive(Debug, Clone)]
+pub struct TopK {
+    k: usize,
+    heap: BinaryHeap<Reverse<RankedElf>>,
+}
+
+impl TopK {
+    /// Creates an empty ranking, which keeps the `k` elves carrying the most calories.
+    pub fn new(k: usize) -> Self {
+        Self { k, heap: BinaryHeap::with_capacity(k + 1) }
+    }
+
+    /// Adds an elf to the ranking, it is only kept if it ranks among the `k` best elves so far.
+    pub fn push(&mut self, elf: ElfCalories) {
+        // the heap can't grow past `k` elves, so the lowest ranked elf is dropped if needed
+        self.heap.push(Reverse(RankedElf(elf)));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// Ends the ranking, and reports the selected elves along with their total calories.
+    /// 
+    /// There are less than `k` elves if less than `k` elves were added.
+    pub fn into_top_elves(self) -> TopElves {
+        // sorting the heap in ascending order of `Reverse` puts the best elf first
+        let elves: Vec<ElfCalories> = self.heap.into_sorted_vec().into_iter().map(|Reverse(RankedElf(elf))| elf).collect();
+        let total = elves.iter().map(|elf| elf.calories as u128).sum();
+
+        TopElves { elves, total }
+    }
+}
+
+/// Selects the `k` elves carrying the most calories, see [`TopK`].
+pub fn top_k(elves: impl IntoIterator<Item = ElfCalories>, k: usize) -> TopElves {
+    let mut ranking = TopK::new(k);
+    for elf in elves {
+        ranking.push(elf);
+    }
+    ranking.into_top_elves()
+}
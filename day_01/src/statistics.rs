@@ -0,0 +1,354 @@
+use aoc_common::{Arguments, Parameters};
+use thousands::Separable;
+use crate::{ranking::top_k, ElfCalories, ElfInventory};
+
+/// The percentiles given by the statistics.
+pub const PERCENTILES: [u8; 6] = [10, 25, 50, 75, 90, 99];
+
+/// The largest width of the bars of the histogram, in characters.
+pub const MAX_BAR_WIDTH: usize = 1_000;
+
+/// A food item, along with the elf carrying it.
+/// 
+/// # Fields
+/// 
+/// * `elf` - The index of the elf carrying the item. The index starts at 1.
+/// * `calories` - The calories of the item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Snack {
+    pub elf: usize,
+    pub calories: usize,
+}
+
+/// The number of food items an elf is carrying.
+/// 
+/// # Fields
+/// 
+/// * `elf` - The index of the elf. The index starts at 1.
+/// * `items` - The number of food items the elf is carrying.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ElfItems {
+    pub elf: usize,
+    pub items: usize,
+}
+
+/// A bucket of the histogram of the calories carried by the elves.
+/// 
+/// # Fields
+/// 
+/// * `start` - The smallest number of calories of the bucket.
+/// * `end` - The largest number of calories of the bucket (included).
+/// * `count` - The number of elves carrying a number of calories between `start` and `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket {
+    pub start: usize,
+    pub end: usize,
+    pub count: usize,
+}
+
+/// The statistics of the calories carried by the elves.
+/// 
+/// # Fields
+/// 
+/// * `elves` - The number of elves.
+/// * `total` - The total calories carried by the elves. The calories of each elf fit in a `usize`, but their total may not.
+/// * `mean` - The mean of the calories carried by an elf.
+/// * `median` - The median of the calories carried by an elf, the mean of the two middle elves if there is an even number of elves.
+/// * `standard_deviation` - The standard deviation of the calories carried by an elf, over the whole population of elves.
+/// * `least` - The elf carrying the least calories (the first one in case of a tie).
+/// * `most` - The elf carrying the most calories (the first one in case of a tie).
+/// * `percentiles` - Each percentile of [`PERCENTILES`] along with its value, using the nearest-rank method.
+/// * `histogram` - The number of elves in buckets of calories of equal size, from the fewest calories.
+/// * `outliers` - The elves outside of Tukey's fences (1.5 interquartile range below the first quartile or above the third one), in the order of the input.
+/// * `item_counts` - The number of items carried by each elf, in the order of the input.
+/// * `largest_snack` - The food item with the most calories (the first one in case of a tie), if any elf carries an item.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalorieStatistics {
+    pub elves: usize,
+    pub total: u128,
+    pub mean: f64,
+    pub median: f64,
+    pub standard_deviation: f64,
+    pub least: ElfCalories,
+    pub most: ElfCalories,
+    pub percentiles: Vec<(u8, usize)>,
+    pub histogram: Vec<Bucket>,
+    pub outliers: Vec<ElfCalories>,
+    pub item_counts: Vec<ElfItems>,
+    pub largest_snack: Option<Snack>,
+}
+
+impl CalorieStatistics {
+    /// Computes the statistics of the calories carried by the elves.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `elves_inventories` - The items carried by each elf.
+    /// * `buckets` - The maximum number of buckets of the histogram.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if there are no elves, or no buckets.
+    pub fn compute(elves_inventories: &[ElfInventory], buckets: usize) -> Self {
+        assert!(!elves_inventories.is_empty(), "There are no elves to compute statistics about");
+        assert!(buckets > 0, "The histogram needs at least one bucket");
+
+        // sort the calories carried by each elf, most of the statistics are about their distribution
+        let elves_calories: Vec<ElfCalories> = elves_inventories.iter().map(ElfInventory::calories).collect();
+        let mut sorted_calories: Vec<usize> = elves_calories.iter().map(|elf| elf.calories).collect();
+        sorted_calories.sort_unstable();
+
+        // compute the moments of the distribution
+        let elves = sorted_calories.len();
+        let total: u128 = sorted_calories.iter().map(|&calories| calories as u128).sum();
+        let mean = total as f64 / elves as f64;
+        let variance = sorted_calories.iter().map(|&calories| (calories as f64 - mean).powi(2)).sum::<f64>() / elves as f64;
+        let median = if elves % 2 == 1 {
+            sorted_calories[elves / 2] as f64
+        } else {
+            // the middle values are only added up once halved, so they can't overflow
+            let (lower, upper) = (sorted_calories[elves / 2 - 1], sorted_calories[elves / 2]);
+            lower as f64 + (upper - lower) as f64 / 2.0
+        };
+
+        // find the extremes, the ranking puts the first elf ahead in case of a tie
+        let least = *elves_calories.iter().min_by_key(|elf| elf.calories).expect("There is at least one elf");
+        let most = top_k(elves_calories.iter().copied(), 1).elves[0];
+
+        // the elves outside of Tukey's fences are outliers
+        let first_quartile = nearest_rank(&sorted_calories, 25) as f64;
+        let third_quartile = nearest_rank(&sorted_calories, 75) as f64;
+        let interquartile_range = third_quartile - first_quartile;
+        let (lower_fence, upper_fence) = (first_quartile - 1.5 * interquartile_range, third_quartile + 1.5 * interquartile_range);
+        let outliers = elves_calories
+            .iter()
+            .filter(|elf| (elf.calories as f64) < lower_fence || (elf.calories as f64) > upper_fence)
+            .copied()
+            .collect();
+
+        // the statistics of the items need the individual items
+        let item_counts = elves_inventories.iter().map(|elf| ElfItems { elf: elf.elf, items: elf.items.len() }).collect();
+        let largest_snack = elves_inventories
+            .iter()
+            .flat_map(|elf| elf.items.iter().map(|&calories| Snack { elf: elf.elf, calories }))
+            .reduce(|largest, snack| if snack.calories > largest.calories { snack } else { largest });
+
+        Self {
+            elves,
+            total,
+            mean,
+            median,
+            standard_deviation: variance.sqrt(),
+            least,
+            most,
+            percentiles: PERCENTILES.iter().map(|&percentile| (percentile, nearest_rank(&sorted_calories, percentile))).collect(),
+            histogram: histogram(&sorted_calories, buckets),
+            outliers,
+            item_counts,
+            largest_snack,
+        }
+    }
+
+    /// Builds a report of the statistics meant to be read by a human, with the histogram drawn using bars of `#`.
+    /// 
+    /// The items carried by each elf are summed up, [`CalorieStatistics::to_json`] lists them for every elf.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `bar_width` - The width of the longest bar of the histogram, in characters.
+    pub fn report(&self, bar_width: usize) -> String {
+        let mut report = vec![];
+
+        // the distribution of the calories
+        report.push(format!(
+            "{elves} {are} carrying {total} calories.",
+            elves = self.elves.separate_with_commas(),
+            are = if self.elves == 1 { "elf is" } else { "elves are" },
+            total = self.total.separate_with_commas()
+        ));
+        report.push(format!(
+            "Mean: {mean}, median: {median}, standard deviation: {standard_deviation}.",
+            mean = format!("{:.1}", self.mean).separate_with_commas(),
+            median = format!("{:.1}", self.median).separate_with_commas(),
+            standard_deviation = format!("{:.1}", self.standard_deviation).separate_with_commas()
+        ));
+        report.push(format!(
+            "Least calories: elf {} with {}, most calories: elf {} with {}.",
+            self.least.elf,
+            self.least.calories.separate_with_commas(),
+            self.most.elf,
+            self.most.calories.separate_with_commas()
+        ));
+        let percentiles: Vec<String> = self
+            .percentiles
+            .iter()
+            .map(|(percentile, calories)| format!("p{percentile} {}", calories.separate_with_commas()))
+            .collect();
+        report.push(format!("Percentiles: {}.", percentiles.join(", ")));
+
+        // the histogram, the bars are scaled so the longest one is `bar_width` characters long
+        report.push(String::new());
+        report.push("Histogram:".to_owned());
+        let largest_count = self.histogram.iter().map(|bucket| bucket.count).max().unwrap_or(0).max(1);
+        let ranges: Vec<String> = self
+            .histogram
+            .iter()
+            .map(|bucket| format!("{} - {}", bucket.start.separate_with_commas(), bucket.end.separate_with_commas()))
+            .collect();
+        let range_width = ranges.iter().map(String::len).max().unwrap_or(0);
+        for (bucket, range) in self.histogram.iter().zip(&ranges) {
+            let bar = "#".repeat(bucket.count.saturating_mul(bar_width).div_ceil(largest_count));
+            report.push(format!("{range:>range_width$} | {bar:<bar_width$} {}", bucket.count));
+        }
+        report.push(String::new());
+
+        // the elves that don't look like the others
+        let outliers: Vec<String> = self
+            .outliers
+            .iter()
+            .map(|elf| format!("elf {} ({})", elf.elf, elf.calories.separate_with_commas()))
+            .collect();
+        if outliers.is_empty() {
+            report.push("Outliers: none.".to_owned());
+        } else {
+            report.push(format!("Outliers: {}.", outliers.join(", ")));
+        }
+
+        // the items
+        let fewest_items = self.item_counts.iter().map(|elf| elf.items).min().unwrap_or(0);
+        let most_items = self.item_counts.iter().copied().reduce(|most, elf| if elf.items > most.items { elf } else { most });
+        let items: usize = self.item_counts.iter().map(|elf| elf.items).sum();
+        if let Some(most_items) = most_items {
+            report.push(format!(
+                "Items per elf: {fewest_items} to {}, {:.1} on average, elf {} carries the most items.",
+                most_items.items,
+                items as f64 / self.item_counts.len() as f64,
+                most_items.elf
+            ));
+        }
+        match self.largest_snack {
+            Some(snack) => report.push(format!(
+                "Largest snack: {} calories, carried by elf {}.",
+                snack.calories.separate_with_commas(),
+                snack.elf
+            )),
+            None => report.push("Largest snack: none, the elves aren't carrying any item.".to_owned()),
+        }
+
+        report.join("\n")
+    }
+
+    /// Formats the statistics as a JSON object, on a single line.
+    pub fn to_json(&self) -> String {
+        let elf_calories = |elf: &ElfCalories| format!(r#"{{"elf":{},"calories":{}}}"#, elf.elf, elf.calories);
+
+        let percentiles: Vec<String> = self.percentiles.iter().map(|(percentile, calories)| format!(r#""p{percentile}":{calories}"#)).collect();
+        let histogram: Vec<String> = self
+            .histogram
+            .iter()
+            .map(|bucket| format!(r#"{{"start":{},"end":{},"count":{}}}"#, bucket.start, bucket.end, bucket.count))
+            .collect();
+        let outliers: Vec<String> = self.outliers.iter().map(elf_calories).collect();
+        let item_counts: Vec<String> = self.item_counts.iter().map(|elf| format!(r#"{{"elf":{},"items":{}}}"#, elf.elf, elf.items)).collect();
+        let largest_snack = match self.largest_snack {
+            Some(snack) => format!(r#"{{"elf":{},"calories":{}}}"#, snack.elf, snack.calories),
+            None => "null".to_owned(),
+        };
+
+        format!(
+            r#"{{"elves":{},"total":{},"mean":{},"median":{},"standard_deviation":{},"least":{},"most":{},"percentiles":{{{}}},"histogram":[{}],"outliers":[{}],"item_counts":[{}],"largest_snack":{}}}"#,
+            self.elves,
+            self.total,
+            self.mean,
+            self.median,
+            self.standard_deviation,
+            elf_calories(&self.least),
+            elf_calories(&self.most),
+            percentiles.join(","),
+            histogram.join(","),
+            outliers.join(","),
+            item_counts.join(","),
+            largest_snack
+        )
+    }
+}
+
+/// Finds the value of a percentile using the nearest-rank method:
+/// the smallest value such that at least `percentile` percents of the values are lower or equal to it.
+/// 
+/// # Panics
+/// 
+/// Panics if there are no values.
+pub fn nearest_rank(sorted_values: &[usize], percentile: u8) -> usize {
+    let rank = (usize::from(percentile) * sorted_values.len()).div_ceil(100);
+    sorted_values[rank.max(1) - 1]
+}
+
+/// Counts the values in buckets of equal size, covering the values from the smallest to the largest.
+/// 
+/// There are less buckets than asked when there are less different values than buckets.
+/// The values can span the whole range of `usize`, so the bounds of the buckets never overflow.
+fn histogram(sorted_values: &[usize], buckets: usize) -> Vec<Bucket> {
+    // compute the size of the buckets, so `buckets` buckets cover every value
+    // (this is `(range + 1).div_ceil(buckets)`, without adding 1 to a range that may be `usize::MAX`)
+    let (smallest, largest) = (sorted_values[0], sorted_values[sorted_values.len() - 1]);
+    let range = largest - smallest;
+    let bucket_size = range / buckets + 1;
+
+    // create the buckets, then count the values in each of them, the last bucket may end past the largest `usize`
+    let mut histogram: Vec<Bucket> = (0..=range / bucket_size)
+        .map(|index| {
+            let start = smallest + index * bucket_size;
+            Bucket { start, end: start.saturating_add(bucket_size - 1), count: 0 }
+        })
+        .collect();
+    for value in sorted_values {
+        histogram[(value - smallest) / bucket_size].count += 1;
+    }
+
+    histogram
+}
+
+/// The options of the statistics that can be changed on the command line.
+/// 
+/// # Fields
+/// 
+/// * `buckets` - The maximum number of buckets of the histogram, 10 by default.
+/// * `bar_width` - The width of the longest bar of the histogram, 50 characters by default and at most `MAX_BAR_WIDTH`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatisticsOptions {
+    pub buckets: usize,
+    pub bar_width: usize,
+}
+
+impl Default for StatisticsOptions {
+    fn default() -> Self {
+        Self { buckets: 10, bar_width: 50 }
+    }
+}
+
+impl Parameters for StatisticsOptions {
+    const OPTIONS: &'static [(&'static str, &'static str)] = &[
+        ("buckets", "the maximum number of buckets of the histogram (10 by default)"),
+        ("bar-width", "the width of the longest bar of the histogram, in characters (50 by default, 1,000 at most)"),
+    ];
+
+    fn from_arguments(arguments: &Arguments) -> Result<Self, String> {
+        let default = Self::default();
+        let options = Self {
+            buckets: arguments.parameter("buckets", default.buckets)?,
+            bar_width: arguments.parameter("bar-width", default.bar_width)?,
+        };
+
+        // the values need a bucket
+        if options.buckets == 0 {
+            return Err("The histogram has at least one bucket.".to_owned());
+        }
+
+        // the bars fit in a terminal, and their lengths don't overflow
+        if options.bar_width > MAX_BAR_WIDTH {
+            return Err(format!("The bars of the histogram are at most {MAX_BAR_WIDTH} characters wide."));
+        }
+        Ok(options)
+    }
+}
@@ -0,0 +1,253 @@
+use aoc_common::{Arguments, Solution, Streaming};
+use day_01::{
+    parse_elves_inventories,
+    ranking::{top_k, TopK},
+    read_top_elves_calories,
+    statistics::{nearest_rank, Bucket, CalorieStatistics, ElfItems, Snack, StatisticsOptions, MAX_BAR_WIDTH},
+    Day01, ElfCalories, Ranking,
+};
+
+/// The example from the puzzle description.
+const EXAMPLE: &str = "\
+1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000
+";
+
+/// The puzzle input of the repository.
+const INPUT: &str = include_str!("../../inputs/day_01.txt");
+
+#[test]
+fn example() {
+    let elves_calories = Day01::parse(EXAMPLE).expect("The example should be valid");
+
+    // the elves are ranked by the number of calories they are carrying
+    let top_elves = Day01::top_elves(&elves_calories, &Ranking { top: 5 });
+    let elves: Vec<usize> = top_elves.elves.iter().map(|elf| elf.elf).collect();
+    assert_eq!(elves, [4, 3, 5, 1, 2]);
+
+    assert_eq!(Day01::part_one(&elves_calories), 24_000);
+    assert_eq!(Day01::part_two(&elves_calories), 45_000);
+}
+
+#[test]
+fn input() {
+    assert_eq!(Day01::solve(INPUT).unwrap(), (67_016, 200_116));
+}
+
+#[test]
+fn example_ranking() {
+    let elves_calories = Day01::parse(EXAMPLE).expect("The example should be valid");
+
+    // the second part adds up the calories of the given number of elves
+    assert_eq!(Day01::part_two_with(&elves_calories, &Ranking { top: 1 }), 24_000);
+    assert_eq!(Day01::part_two_with(&elves_calories, &Ranking { top: 4 }), 51_000);
+
+    // there are less elves than asked when the input doesn't have enough elves
+    let top_elves = Day01::top_elves(&elves_calories, &Ranking { top: 10 });
+    assert_eq!(top_elves.elves.len(), 5);
+    assert_eq!(top_elves.total, 55_000);
+}
+
+#[test]
+fn few_elves() {
+    // less than three elves is not an error anymore
+    assert_eq!(Day01::solve("1000\n\n2000\n").unwrap(), (2_000, 3_000));
+    assert_eq!(Day01::solve_reader("1000\n\n2000\n".as_bytes()).unwrap(), (2_000, 3_000));
+}
+
+#[test]
+fn ties() {
+    // elves carrying as many calories are ranked by index
+    let elves = [(1, 10), (2, 20), (3, 10), (4, 20), (5, 5)].map(|(elf, calories)| ElfCalories { elf, calories });
+    let top_elves = top_k(elves, 3);
+    let ranked: Vec<usize> = top_elves.elves.iter().map(|elf| elf.elf).collect();
+    assert_eq!(ranked, [2, 4, 1]);
+    assert_eq!(top_elves.total, 50);
+
+    // the order in which the elves are added doesn't change the ranking
+    let mut ranking = TopK::new(3);
+    for elf in elves.iter().rev() {
+        ranking.push(*elf);
+    }
+    assert_eq!(ranking.into_top_elves(), top_elves);
+}
+
+#[test]
+fn streamed_example() {
+    // only the top elves are kept, in the same order
+    let top_elves = read_top_elves_calories(EXAMPLE.as_bytes(), 3).expect("The example should be valid");
+    let elves: Vec<usize> = top_elves.elves.iter().map(|elf| elf.elf).collect();
+    assert_eq!(elves, [4, 3, 5]);
+    assert_eq!(top_elves.total, 45_000);
+
+    assert_eq!(Day01::solve_reader(EXAMPLE.as_bytes()).unwrap(), (24_000, 45_000));
+    assert_eq!(Day01::stream_with(EXAMPLE.as_bytes(), &Ranking { top: 4 }).unwrap(), (24_000, 51_000));
+}
+
+#[test]
+fn streamed_input() {
+    assert_eq!(Day01::solve_reader(INPUT.as_bytes()).unwrap(), (67_016, 200_116));
+}
+
+#[test]
+fn streamed_errors() {
+    let error = Day01::solve_reader("1000\n2000\n\n3x00\n".as_bytes()).unwrap_err();
+    assert_eq!((error.day, error.line), (Some(1), Some(4)));
+}
+
+#[test]
+fn example_statistics() {
+    let elves_inventories = parse_elves_inventories(EXAMPLE).expect("The example should be valid");
+    let statistics = CalorieStatistics::compute(&elves_inventories, 2);
+
+    // the distribution of the calories: 4,000, 6,000, 10,000, 11,000 and 24,000
+    assert_eq!((statistics.elves, statistics.total), (5, 55_000));
+    assert_eq!((statistics.mean, statistics.median), (11_000.0, 10_000.0));
+    assert!((statistics.standard_deviation - 6_985.7).abs() < 0.1);
+    assert_eq!(statistics.least, ElfCalories { elf: 2, calories: 4_000 });
+    assert_eq!(statistics.most, ElfCalories { elf: 4, calories: 24_000 });
+    assert_eq!(statistics.percentiles[1], (25, 6_000));
+    assert_eq!(statistics.percentiles[3], (75, 11_000));
+
+    // the buckets cover every value
+    assert_eq!(
+        statistics.histogram,
+        [Bucket { start: 4_000, end: 14_000, count: 4 }, Bucket { start: 14_001, end: 24_001, count: 1 }]
+    );
+
+    // the fourth elf is above the upper fence (11,000 + 1.5 * 5,000)
+    assert_eq!(statistics.outliers, [ElfCalories { elf: 4, calories: 24_000 }]);
+
+    // the statistics of the items
+    let items: Vec<usize> = statistics.item_counts.iter().map(|elf| elf.items).collect();
+    assert_eq!(items, [3, 1, 2, 3, 1]);
+    assert_eq!(statistics.item_counts[0], ElfItems { elf: 1, items: 3 });
+    assert_eq!(statistics.largest_snack, Some(Snack { elf: 5, calories: 10_000 }));
+
+    // the report and the JSON object show the statistics
+    let report = statistics.report(10);
+    assert!(report.contains("5 elves are carrying 55,000 calories."));
+    assert!(report.contains("4,000 - 14,000 | ########## 4"));
+    assert!(report.contains("Outliers: elf 4 (24,000)."));
+    let json = statistics.to_json();
+    assert!(json.starts_with(r#"{"elves":5,"total":55000,"mean":11000,"median":10000,"#));
+    assert!(json.ends_with(r#""largest_snack":{"elf":5,"calories":10000}}"#));
+}
+
+#[test]
+fn statistics_single_elf() {
+    // a single value is its own histogram, and can't be an outlier
+    let elves_inventories = parse_elves_inventories("5\n").unwrap();
+    let statistics = CalorieStatistics::compute(&elves_inventories, 10);
+    assert_eq!((statistics.elves, statistics.total, statistics.standard_deviation), (1, 5, 0.0));
+    assert_eq!(statistics.histogram, [Bucket { start: 5, end: 5, count: 1 }]);
+    assert!(statistics.outliers.is_empty());
+    assert_eq!(statistics.largest_snack, Some(Snack { elf: 1, calories: 5 }));
+    assert!(statistics.report(10).starts_with("1 elf is carrying 5 calories."));
+}
+
+#[test]
+fn statistics_large_values() {
+    // the total, the median and the buckets don't overflow
+    let elves_inventories = parse_elves_inventories("9223372036854775808\n\n9223372036854775808\n").unwrap();
+    let statistics = CalorieStatistics::compute(&elves_inventories, 10);
+    assert_eq!(statistics.total, 18_446_744_073_709_551_616);
+    assert_eq!(statistics.median, 9_223_372_036_854_775_808.0);
+    assert_eq!(statistics.histogram, [Bucket { start: 9_223_372_036_854_775_808, end: 9_223_372_036_854_775_808, count: 2 }]);
+    assert!(statistics.report(10).starts_with("2 elves are carrying 18,446,744,073,709,551,616 calories."));
+
+    // the buckets cover the whole range of `usize`, the last one ends at the largest `usize`
+    let elves_inventories = parse_elves_inventories(&format!("0\n\n{}\n", usize::MAX)).unwrap();
+    let statistics = CalorieStatistics::compute(&elves_inventories, 10);
+    assert_eq!(statistics.median, usize::MAX as f64 / 2.0);
+    assert_eq!(statistics.histogram.len(), 10);
+    assert_eq!(statistics.histogram[0].start, 0);
+    assert_eq!(statistics.histogram[9].end, usize::MAX);
+    assert_eq!(statistics.histogram.iter().map(|bucket| bucket.count).sum::<usize>(), 2);
+}
+
+#[test]
+fn bar_widths() {
+    let options_from = |args: &[&str]| {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        Arguments::parse_with::<StatisticsOptions>(&args).map(|(_, options)| options)
+    };
+
+    // the width of the bars is bounded, so the lengths of the bars don't overflow
+    assert_eq!(options_from(&["--bar-width", "1000"]).map(|options| options.bar_width), Ok(MAX_BAR_WIDTH));
+    assert!(options_from(&["--bar-width", "1001"]).is_err());
+    assert!(options_from(&["--bar-width", &usize::MAX.to_string()]).is_err());
+    assert!(options_from(&["--buckets", "0"]).is_err());
+
+    // the longest bar is as wide as asked
+    let elves_inventories = parse_elves_inventories(EXAMPLE).unwrap();
+    let report = CalorieStatistics::compute(&elves_inventories, 2).report(MAX_BAR_WIDTH);
+    assert!(report.contains(&format!("4,000 - 14,000 | {} 4", "#".repeat(MAX_BAR_WIDTH))));
+}
+
+#[test]
+fn percentiles() {
+    let values = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    assert_eq!(nearest_rank(&values, 0), 1);
+    assert_eq!(nearest_rank(&values, 10), 1);
+    assert_eq!(nearest_rank(&values, 11), 2);
+    assert_eq!(nearest_rank(&values, 50), 5);
+    assert_eq!(nearest_rank(&values, 100), 10);
+}
+
+#[test]
+fn separators() {
+    // repeated blank lines, leading and trailing blank lines and CRLF line endings don't create elves
+    let input = "\n1000\r\n2000\r\n\r\n\r\n\r\n3000\n\n\n4000\n5000\n\n\n";
+    let elves_calories = Day01::parse(input).unwrap();
+    let calories: Vec<(usize, usize)> = elves_calories.iter().map(|elf| (elf.elf, elf.calories)).collect();
+    assert_eq!(calories, [(1, 3_000), (2, 3_000), (3, 9_000)]);
+
+    // the streamed input is split the same way
+    let top_elves = read_top_elves_calories(input.as_bytes(), 3).unwrap();
+    let elves: Vec<usize> = top_elves.elves.iter().map(|elf| elf.elf).collect();
+    assert_eq!(elves, [3, 1, 2]);
+}
+
+#[test]
+fn diagnostics() {
+    // the error points at the line of the item, counting the blank lines
+    let error = Day01::parse_input("1000\n\n\n2000\n 300\n").unwrap_err();
+    assert_eq!((error.day, error.line, error.column), (Some(1), Some(5), Some(1)));
+    assert_eq!(error.message, "expected a number of calories, found ` 300`");
+
+    // the sums are checked
+    let input = format!("1\n\n{}\n1\n", usize::MAX);
+    for error in [Day01::parse(&input).unwrap_err(), Day01::solve_reader(input.as_bytes()).unwrap_err()] {
+        assert_eq!(error.line, Some(4));
+        assert_eq!(error.message, "the calories carried by elf 2 are too big to be added up");
+    }
+
+    // an input without any item has no elf
+    for input in ["", "\n\n\r\n"] {
+        assert_eq!(Day01::parse(input).unwrap_err().message, "expected at least one elf, found no item");
+        assert_eq!(Day01::solve_reader(input.as_bytes()).unwrap_err().message, "expected at least one elf, found no item");
+    }
+}
+
+#[test]
+fn large_totals() {
+    // each elf fits in a `usize`, but the total of the top elves doesn't
+    let input = "9223372036854775808\n\n9223372036854775808\n";
+    let expected = (9_223_372_036_854_775_808, 18_446_744_073_709_551_616);
+    assert_eq!(Day01::solve(input).unwrap(), expected);
+    assert_eq!(Day01::solve_reader(input.as_bytes()).unwrap(), expected);
+    assert_eq!(read_top_elves_calories(input.as_bytes(), 3).unwrap().total, expected.1);
+}
@@ -0,0 +1,159 @@
+use std::str::FromStr;
+use crate::{hand::Hand, round_result::RoundResult};
+
+/// The outcome of a round, for the player whose score is computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+/// A hand of a game.
+/// 
+/// # Fields
+/// 
+/// * `name` - The name of the hand, such as `Rock`.
+/// * `score` - The points earned by showing this hand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandDefinition {
+    pub name: String,
+    pub score: usize,
+}
+
+/// A cyclic game, such as rock-paper-scissors, where each hand beats the hands before it in the cycle, up to half of the cycle.
+/// 
+/// The game has an odd number of hands, so each hand beats as many hands as it loses to, and two different hands never draw.
+/// 
+/// How the hands are written in the strategy guide, and how many points each outcome earns, are part of the [`Rules`](crate::rules::Rules).
+/// 
+/// # Fields
+/// 
+/// * `hands` - The hands, in the order of the cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    hands: Vec<HandDefinition>,
+}
+
+impl Game {
+    /// The largest number of hands, so the default letters of both columns don't overlap (see [`Encoding::letters`](crate::rules::Encoding::letters)).
+    pub const MAX_HANDS: usize = 13;
+
+    /// Creates a game with the given hands, in the order of the cycle.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an error if there isn't an odd number of hands between 3 and [`Game::MAX_HANDS`].
+    pub fn new(hands: Vec<HandDefinition>) -> Result<Self, String> {
+        if hands.len() < 3 || hands.len() > Self::MAX_HANDS || hands.len().is_multiple_of(2) {
+            return Err(format!(
+                "A cyclic game has an odd number of hands between 3 and {}, found {}.",
+                Self::MAX_HANDS,
+                hands.len()
+            ));
+        }
+
+        Ok(Self { hands })
+    }
+
+    /// Creates a game with the given hands, in the order of the cycle, scoring 1 point for the first hand, 2 for the second one, and so on.
+    pub fn cyclic(names: &[&str]) -> Result<Self, String> {
+        Self::new(
+            names
+                .iter()
+                .enumerate()
+                .map(|(index, name)| HandDefinition { name: name.to_string(), score: index + 1 })
+                .collect(),
+        )
+    }
+
+    /// Rock-paper-scissors, the game of the puzzle.
+    pub fn rock_paper_scissors() -> Self {
+        Self::cyclic(&["Rock", "Paper", "Scissors"]).expect("Rock-paper-scissors is a valid game")
+    }
+
+    /// Rock-paper-scissors-lizard-Spock, where each hand beats two hands.
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Self::cyclic(&["Rock", "Spock", "Paper", "Lizard", "Scissors"]).expect("Rock-paper-scissors-lizard-Spock is a valid game")
+    }
+
+    /// Rock-paper-scissors with 7 hands, where each hand beats three hands.
+    pub fn rock_paper_scissors_7() -> Self {
+        Self::cyclic(&["Water", "Air", "Paper", "Sponge", "Scissors", "Fire", "Rock"]).expect("Rock-paper-scissors-7 is a valid game")
+    }
+
+    /// Changes the points earned by showing each hand, given in the order of the cycle.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an error if there isn't a score for each hand.
+    pub fn with_hand_scores(mut self, scores: &[usize]) -> Result<Self, String> {
+        if scores.len() != self.hands.len() {
+            return Err(format!("The game has {} hands, found {} hand scores.", self.hands.len(), scores.len()));
+        }
+
+        for (hand, &score) in self.hands.iter_mut().zip(scores) {
+            hand.score = score;
+        }
+        Ok(self)
+    }
+
+    /// The hands of the game, in the order of the cycle.
+    pub fn hands(&self) -> &[HandDefinition] {
+        &self.hands
+    }
+
+    /// Checks if the first hand beats the second one.
+    pub fn beats(&self, hand: Hand, other: Hand) -> bool {
+        // count the steps from the other hand to the hand, going forward in the cycle
+        let steps = (hand.0 + self.hands.len() - other.0) % self.hands.len();
+        (1..=self.hands.len() / 2).contains(&steps)
+    }
+
+    /// The outcome of a round, for the player showing `hand` against `other`.
+    pub fn outcome(&self, hand: Hand, other: Hand) -> Outcome {
+        if hand == other {
+            Outcome::Draw
+        } else if self.beats(hand, other) {
+            Outcome::Win
+        } else {
+            Outcome::Loss
+        }
+    }
+
+    /// The hand to show against the hand of the opponent to get the given result.
+    pub fn hand_for(&self, opponent: Hand, result: RoundResult) -> Hand {
+        let hands = self.hands.len() as isize;
+        Hand((opponent.0 as isize + result.0).rem_euclid(hands) as usize)
+    }
+
+    /// The points earned by showing the hand.
+    pub fn hand_score(&self, hand: Hand) -> usize {
+        self.hands[hand.0].score
+    }
+
+    /// The name of the hand.
+    pub fn hand_name(&self, hand: Hand) -> &str {
+        &self.hands[hand.0].name
+    }
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        Self::rock_paper_scissors()
+    }
+}
+
+/// Parses the name of a predefined game: `rps`, `rpsls` or `rps7`.
+impl FromStr for Game {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rps" => Ok(Self::rock_paper_scissors()),
+            "rpsls" => Ok(Self::rock_paper_scissors_lizard_spock()),
+            "rps7" => Ok(Self::rock_paper_scissors_7()),
+            _ => Err(format!("`{s}` is not a valid game, expected `rps`, `rpsls` or `rps7`.")),
+        }
+    }
+}
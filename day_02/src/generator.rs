@@ -0,0 +1,14 @@
+use aoc_common::random::Rng;
+
+/// Generates a valid input made of `size` rounds of the strategy guide.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let elf_hand = rng.choose(&["A", "B", "C"]);
+        let second_column = rng.choose(&["X", "Y", "Z"]);
+        input.push_str(&format!("{elf_hand} {second_column}\n"));
+    }
+
+    input
+}
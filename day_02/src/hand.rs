@@ -0,0 +1,10 @@
+/// Represents the hand a player is showing, as its position in the cycle of hands of the game (the first hand is 0).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Hand(pub usize);
+
+/// The hands of rock-paper-scissors, in the order of its cycle.
+impl Hand {
+    pub const ROCK: Hand = Hand(0);
+    pub const PAPER: Hand = Hand(1);
+    pub const SCISSORS: Hand = Hand(2);
+}
@@ -0,0 +1,157 @@
+pub mod game;
+pub mod hand;
+pub mod round_result;
+pub mod rules;
+pub mod generator;
+
+use std::io::BufRead;
+use aoc_common::{parse::{numbered_lines, numbered_reader_lines}, MaybeAnswer, ParseError, Solution, Streaming};
+use hand::Hand;
+use round_result::RoundResult;
+use rules::Rules;
+
+/// A round of the tournament, as described by the strategy guide.
+/// 
+/// # Fields
+/// 
+/// * `elf_hand` - The hand the elf is going to show.
+/// * `human_hand` - The hand we should show, when reading the second column as a hand (first method).
+/// * `expected_result` - The result we should get, when reading the second column as a result (second method).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Round {
+    pub elf_hand: Hand,
+    pub human_hand: Hand,
+    pub expected_result: RoundResult,
+}
+
+/// Parses a line of the strategy guide into a round, written with the symbols of the given rules.
+/// 
+/// The line contains the hand of the elf and the second column, separated by a space (for example `A Y`).
+pub fn parse_round(rules: &Rules, line: &str) -> Result<Round, ParseError> {
+    // split the line into its two columns
+    let (elf_column, human_column) = line
+        .split_once(' ')
+        .ok_or_else(|| ParseError::new("expected two columns separated by a space, such as `A Y`"))?;
+
+    // parse the hand of each player, and the expected result
+    let elf_hand = rules.encoding().parse_elf_hand(elf_column).map_err(|error| error.within(line, elf_column))?;
+    let (human_hand, expected_result) = rules.encoding().parse_second_column(human_column).map_err(|error| error.within(line, human_column))?;
+
+    Ok(Round { elf_hand, human_hand, expected_result })
+}
+
+/// The reason given instead of a score that doesn't fit in a `usize`, which only happens with huge configured scores.
+pub const SCORE_OVERFLOW: &str = "the score doesn't fit in a usize";
+
+/// Computes the score of a round with the given rules, based on the hand we showed and on the winner.
+/// 
+/// Returns `None` if the score doesn't fit in a `usize`.
+pub fn round_score(rules: &Rules, human_hand: Hand, elf_hand: Hand) -> Option<usize> {
+    // attribute points based on the hand
+    let hand_score = rules.game().hand_score(human_hand);
+
+    // attribute points based on the winner
+    let result_score = rules.outcome_scores().score(rules.game().outcome(human_hand, elf_hand));
+
+    hand_score.checked_add(result_score)
+}
+
+/// Computes the score of a round with the first method, where the second column is the hand we should show.
+pub fn score_method_1(rules: &Rules, round: &Round) -> Option<usize> {
+    round_score(rules, round.human_hand, round.elf_hand)
+}
+
+/// Computes the score of a round with the second method, where the second column is the result we should get.
+pub fn score_method_2(rules: &Rules, round: &Round) -> Option<usize> {
+    // compute the hand we need to show to get the expected result
+    let computed_human_hand = rules.game().hand_for(round.elf_hand, round.expected_result);
+
+    round_score(rules, computed_human_hand, round.elf_hand)
+}
+
+/// Our goal is to compute how many points we would make using the given strategy.
+pub struct Day02;
+
+impl Day02 {
+    /// Parses the strategy guide of a tournament, written with the symbols of the given rules.
+    pub fn parse_with(input: &str, rules: &Rules) -> Result<Vec<Round>, ParseError> {
+        // parse each round in the tournament
+        numbered_lines(input)
+            .map(|(line_number, line)| parse_round(rules, line).map_err(|error| error.at_line(line_number, line)))
+            .collect()
+    }
+
+    /// The score of the tournament with the given rules and the first method.
+    /// 
+    /// Returns `None` if the score doesn't fit in a `usize`.
+    pub fn part_one_with(rounds: &[Round], rules: &Rules) -> Option<usize> {
+        rounds.iter().try_fold(0_usize, |total, round| total.checked_add(score_method_1(rules, round)?))
+    }
+
+    /// The score of the tournament with the given rules and the second method.
+    /// 
+    /// Returns `None` if the score doesn't fit in a `usize`.
+    pub fn part_two_with(rounds: &[Round], rules: &Rules) -> Option<usize> {
+        rounds.iter().try_fold(0_usize, |total, round| total.checked_add(score_method_2(rules, round)?))
+    }
+
+    /// The answer of the first part with the given rules, or the reason why there is none.
+    pub fn part_one_answer(rounds: &[Round], rules: &Rules) -> MaybeAnswer<usize> {
+        MaybeAnswer::from_option(Self::part_one_with(rounds, rules), SCORE_OVERFLOW)
+    }
+
+    /// The answer of the second part with the given rules, or the reason why there is none.
+    pub fn part_two_answer(rounds: &[Round], rules: &Rules) -> MaybeAnswer<usize> {
+        MaybeAnswer::from_option(Self::part_two_with(rounds, rules), SCORE_OVERFLOW)
+    }
+
+    /// Scores the rounds of a tournament with the given rules while they are read, with both methods.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an error if a line can't be parsed, or at the first round whose score doesn't fit in a `usize`.
+    pub fn stream_with(reader: impl BufRead, rules: &Rules) -> Result<(usize, usize), ParseError> {
+        // score each round as soon as it is read
+        let mut scores: (usize, usize) = (0, 0);
+        for line in numbered_reader_lines(reader) {
+            let (line_number, line) = line?;
+            let round = parse_round(rules, &line).map_err(|error| error.at_line(line_number, &line))?;
+
+            // the scores come from the rules, which can make them overflow
+            let overflow = || ParseError::new(format!("{SCORE_OVERFLOW} after this round")).at_line(line_number, &line);
+            scores.0 = score_method_1(rules, &round).and_then(|score| scores.0.checked_add(score)).ok_or_else(overflow)?;
+            scores.1 = score_method_2(rules, &round).and_then(|score| scores.1.checked_add(score)).ok_or_else(overflow)?;
+        }
+
+        Ok(scores)
+    }
+}
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Round>;
+    type PartOne = MaybeAnswer<usize>;
+    type PartTwo = MaybeAnswer<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Rules::default())
+    }
+
+    /// Method 1: the second column is the hand we should show.
+    fn part_one(rounds: &Self::Input) -> Self::PartOne {
+        Self::part_one_answer(rounds, &Rules::default())
+    }
+
+    /// Method 2: the second column is the result we should get.
+    fn part_two(rounds: &Self::Input) -> Self::PartTwo {
+        Self::part_two_answer(rounds, &Rules::default())
+    }
+}
+
+impl Streaming for Day02 {
+    fn stream(reader: impl BufRead) -> Result<(Self::PartOne, Self::PartTwo), ParseError> {
+        let (score_method_1, score_method_2) = Self::stream_with(reader, &Rules::default())?;
+        Ok((MaybeAnswer::Answer(score_method_1), MaybeAnswer::Answer(score_method_2)))
+    }
+}
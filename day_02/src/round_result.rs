@@ -0,0 +1,13 @@
+/// The result a player should get, as the position of the hand to show relative to the hand of the opponent, in the cycle of hands of the game.
+/// 
+/// A hand beats the hands before it in the cycle, up to half of the cycle, so a positive position wins and a negative one loses.
+/// A game with more hands has more ways to win or to lose, such as winning with a hand two steps ahead of the opponent's.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RoundResult(pub isize);
+
+/// The results of rock-paper-scissors.
+impl RoundResult {
+    pub const LOSE: RoundResult = RoundResult(-1);
+    pub const DRAW: RoundResult = RoundResult(0);
+    pub const WIN: RoundResult = RoundResult(1);
+}
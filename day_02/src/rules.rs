@@ -0,0 +1,483 @@
+use std::fs;
+use aoc_common::{parse::{numbered_lines, parse_number}, Arguments, Parameters, ParseError};
+use crate::{game::{Game, Outcome}, hand::Hand, round_result::RoundResult};
+
+/// The points earned for each outcome of a round.
+/// 
+/// # Fields
+/// 
+/// * `loss` - The points earned by losing the round, 0 in the puzzle.
+/// * `draw` - The points earned by a draw, 3 in the puzzle.
+/// * `win` - The points earned by winning the round, 6 in the puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutcomeScores {
+    pub loss: usize,
+    pub draw: usize,
+    pub win: usize,
+}
+
+impl OutcomeScores {
+    /// The points earned for the outcome.
+    pub fn score(&self, outcome: Outcome) -> usize {
+        match outcome {
+            Outcome::Loss => self.loss,
+            Outcome::Draw => self.draw,
+            Outcome::Win => self.win,
+        }
+    }
+
+    /// Reads the points of a loss, a draw and a win, in this order.
+    fn from_slice(scores: &[usize]) -> Option<Self> {
+        match *scores {
+            [loss, draw, win] => Some(Self { loss, draw, win }),
+            _ => None,
+        }
+    }
+}
+
+impl Default for OutcomeScores {
+    fn default() -> Self {
+        Self { loss: 0, draw: 3, win: 6 }
+    }
+}
+
+/// How the hands and the results are written in the strategy guide.
+/// 
+/// Each column has a symbol per hand of the game, given in the order of its cycle. The second column is read both as the hand
+/// we should show and as the result we should get, so it uses the same symbols for both. The results are given from the biggest loss
+/// to the biggest win: the `i`-th result is the hand `i - n / 2` steps ahead of the elf's hand, for `n` hands.
+/// 
+/// # Fields
+/// 
+/// * `elf_symbols` - The symbol of each hand of the elf.
+/// * `hand_symbols` - The symbol of each hand we should show.
+/// * `result_symbols` - The symbol of each result we should get.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Encoding {
+    elf_symbols: Vec<char>,
+    hand_symbols: Vec<char>,
+    result_symbols: Vec<char>,
+}
+
+impl Encoding {
+    /// Creates the encoding of a game with the given number of hands.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an error if a column doesn't have a different symbol for each hand, if a symbol is a whitespace (which separates the columns),
+    /// or if the hands and the results of the second column don't use the same symbols.
+    pub fn new(elf_symbols: Vec<char>, hand_symbols: Vec<char>, result_symbols: Vec<char>, hands: usize) -> Result<Self, String> {
+        for (column, symbols) in [("elf", &elf_symbols), ("hand", &hand_symbols), ("result", &result_symbols)] {
+            let text: String = symbols.iter().collect();
+            if symbols.len() != hands {
+                return Err(format!("The game has {hands} hands, found {} {column} symbols (`{text}`).", symbols.len()));
+            }
+            if symbols.iter().any(|symbol| symbol.is_whitespace()) {
+                return Err(format!("The {column} symbols can't be whitespaces, found `{text}`."));
+            }
+            if symbols.iter().enumerate().any(|(index, symbol)| symbols[..index].contains(symbol)) {
+                return Err(format!("The {column} symbols must be different, found `{text}`."));
+            }
+        }
+
+        // the same column is read as a hand or as a result, so any of its symbols must be both
+        if hand_symbols.iter().any(|symbol| !result_symbols.contains(symbol)) {
+            return Err(format!(
+                "The hands and the results of the second column use the same symbols, found `{}` and `{}`.",
+                hand_symbols.iter().collect::<String>(),
+                result_symbols.iter().collect::<String>()
+            ));
+        }
+
+        Ok(Self { elf_symbols, hand_symbols, result_symbols })
+    }
+
+    /// The letters of the puzzle, for a game with the given number of hands: the hands of the elf are the first letters
+    /// of the alphabet (`A`, `B`, `C`, ...), and the second column uses the last letters (..., `X`, `Y`, `Z`) for both the hands and the results.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if there are more than [`Game::MAX_HANDS`] hands.
+    pub fn letters(hands: usize) -> Self {
+        assert!(hands <= Game::MAX_HANDS, "The letters of both columns would overlap");
+
+        let elf_symbols = (b'A'..).take(hands).map(char::from).collect();
+        let second_column_symbols: Vec<char> = (b'Z' + 1 - hands as u8..=b'Z').map(char::from).collect();
+
+        Self { elf_symbols, hand_symbols: second_column_symbols.clone(), result_symbols: second_column_symbols }
+    }
+
+    /// The symbols of the hands of the elf, in the order of the cycle.
+    pub fn elf_symbols(&self) -> &[char] {
+        &self.elf_symbols
+    }
+
+    /// The symbols of the hands of the second column, in the order of the cycle.
+    pub fn hand_symbols(&self) -> &[char] {
+        &self.hand_symbols
+    }
+
+    /// The symbols of the results of the second column, from the biggest loss to the biggest win.
+    pub fn result_symbols(&self) -> &[char] {
+        &self.result_symbols
+    }
+
+    /// Parses the hand of the elf, the error spans the whole fragment.
+    pub fn parse_elf_hand(&self, s: &str) -> Result<Hand, ParseError> {
+        parse_symbol(s, &self.elf_symbols, "a hand").map(Hand)
+    }
+
+    /// Parses the second column, read both as the hand we should show and as the result we should get.
+    pub fn parse_second_column(&self, s: &str) -> Result<(Hand, RoundResult), ParseError> {
+        let hand = parse_symbol(s, &self.hand_symbols, "a hand or a result")?;
+        let result = parse_symbol(s, &self.result_symbols, "a hand or a result")?;
+
+        // the results are centered on the draw
+        let losses = (self.result_symbols.len() / 2) as isize;
+        Ok((Hand(hand), RoundResult(result as isize - losses)))
+    }
+}
+
+/// Finds the position of a single symbol among the given symbols, the error spans the whole fragment.
+fn parse_symbol(s: &str, symbols: &[char], description: &str) -> Result<usize, ParseError> {
+    let mut chars = s.chars();
+    let position = match (chars.next(), chars.next()) {
+        (Some(symbol), None) => symbols.iter().position(|&expected| expected == symbol),
+        _ => None,
+    };
+
+    position.ok_or_else(|| {
+        // list the expected symbols, such as `A`, `B` or `C`
+        let symbols: Vec<String> = symbols.iter().map(|symbol| format!("`{symbol}`")).collect();
+        let (last, others) = symbols.split_last().expect("A game has hands");
+        ParseError::new(format!("expected {description} ({} or {last}), found `{s}`", others.join(", "))).within(s, s)
+    })
+}
+
+/// The rules of a tournament: the game that is played, the points earned for each outcome, and how the strategy guide is written.
+/// 
+/// # Fields
+/// 
+/// * `game` - The game that is played, along with the points earned by showing each hand.
+/// * `outcome_scores` - The points earned for each outcome of a round.
+/// * `encoding` - The symbols of the hands and of the results in the strategy guide.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    game: Game,
+    outcome_scores: OutcomeScores,
+    encoding: Encoding,
+}
+
+impl Rules {
+    /// Creates the rules of a tournament.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an error if the encoding doesn't have a symbol for each hand of the game.
+    pub fn new(game: Game, outcome_scores: OutcomeScores, encoding: Encoding) -> Result<Self, String> {
+        if encoding.elf_symbols.len() != game.hands().len() {
+            return Err(format!(
+                "The game has {} hands, but the strategy guide is written with {} symbols.",
+                game.hands().len(),
+                encoding.elf_symbols.len()
+            ));
+        }
+
+        Ok(Self { game, outcome_scores, encoding })
+    }
+
+    /// The rules of the puzzle, applied to the given game: a loss, a draw and a win earn 0, 3 and 6 points, and the hands are written with letters.
+    pub fn for_game(game: Game) -> Self {
+        let encoding = Encoding::letters(game.hands().len());
+        Self { game, outcome_scores: OutcomeScores::default(), encoding }
+    }
+
+    /// The game that is played.
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    /// The points earned for each outcome of a round.
+    pub fn outcome_scores(&self) -> OutcomeScores {
+        self.outcome_scores
+    }
+
+    /// The symbols of the hands and of the results in the strategy guide.
+    pub fn encoding(&self) -> &Encoding {
+        &self.encoding
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Self::for_game(Game::default())
+    }
+}
+
+/// The rules are those of the puzzle, they can be changed by a rules file (`--rules FILE`) and by the options of each rule.
+/// The options override the rules file.
+impl Parameters for Rules {
+    const OPTIONS: &'static [(&'static str, &'static str)] = &[
+        ("rules", "a rules file changing the game, the points and the symbols (such as `day_02/rules/rpsls.toml`)"),
+        ("game", "the game of the tournament: `rps`, `rpsls` (rock-paper-scissors-lizard-Spock) or `rps7` (`rps` by default)"),
+        ("hand-scores", "the points earned by showing each hand, in the order of the cycle (`1,2,3` by default)"),
+        ("outcome-scores", "the points earned by a loss, a draw and a win (`0,3,6` by default)"),
+        ("elf-symbols", "the symbols of the hands of the elf (`ABC` by default)"),
+        ("hand-symbols", "the symbols of the hands of the second column (`XYZ` by default)"),
+        ("result-symbols", "the symbols of the results of the second column, from the biggest loss to the biggest win (`XYZ` by default)"),
+    ];
+
+    fn from_arguments(arguments: &Arguments) -> Result<Self, String> {
+        let file = match arguments.optional_parameter::<String>("rules")? {
+            Some(path) => RulesConfig::read(&path)?,
+            None => RulesConfig::default(),
+        };
+
+        file.merge(RulesConfig::from_arguments(arguments)?).into_rules()
+    }
+}
+
+/// Changes to the rules of the puzzle, given by a rules file or by the options of the binary.
+/// The rules that aren't given keep the values of the puzzle.
+/// 
+/// # Fields
+/// 
+/// * `game` - The game that is played.
+/// * `hand_scores` - The points earned by showing each hand, in the order of the cycle.
+/// * `outcome_scores` - The points earned for each outcome of a round.
+/// * `elf_symbols` - The symbol of each hand of the elf.
+/// * `hand_symbols` - The symbol of each hand of the second column.
+/// * `result_symbols` - The symbol of each result of the second column, from the biggest loss to the biggest win.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RulesConfig {
+    pub game: Option<Game>,
+    pub hand_scores: Option<Vec<usize>>,
+    pub outcome_scores: Option<OutcomeScores>,
+    pub elf_symbols: Option<Vec<char>>,
+    pub hand_symbols: Option<Vec<char>>,
+    pub result_symbols: Option<Vec<char>>,
+}
+
+impl RulesConfig {
+    /// Parses a rules file, written in a small subset of TOML.
+    /// 
+    /// Each line gives a rule as `key = value`, where the value is a string, or an array of numbers or of strings on a single line.
+    /// A `#` starts a comment, outside of strings. The game is either predefined (`game`), or given by the names of its hands
+    /// in the order of the cycle (`hands`), which score 1 point for the first hand, 2 for the second one, and so on:
+    /// 
+    /// ```toml
+    /// hands = ["Rock", "Spock", "Paper", "Lizard", "Scissors"]
+    /// hand_scores = [1, 2, 3, 4, 5]
+    /// outcome_scores = [0, 3, 6]    # a loss, a draw and a win
+    /// elf_symbols = "ABCDE"
+    /// hand_symbols = "VWXYZ"
+    /// result_symbols = "VWXYZ"
+    /// ```
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an error pointing at the first line that isn't a known rule with a valid value, or that gives a rule a second time.
+    pub fn from_toml(input: &str) -> Result<Self, ParseError> {
+        let mut config = Self::default();
+
+        for (line_number, line) in numbered_lines(input) {
+            // skip the blank lines and the comments
+            let content = strip_comment(line).trim();
+            if content.is_empty() {
+                continue;
+            }
+
+            let (key, value) = content
+                .split_once('=')
+                .ok_or_else(|| ParseError::new("expected a rule such as `game = \"rps\"`").within(line, content).at_line(line_number, line))?;
+            let (key, value) = (key.trim(), value.trim());
+
+            // a rule is only given once
+            match config.is_given(key) {
+                None => {
+                    return Err(ParseError::new(format!(
+                        "expected a rule (`game`, `hands`, `hand_scores`, `outcome_scores`, `elf_symbols`, `hand_symbols` or `result_symbols`), found `{key}`"
+                    ))
+                    .within(line, key)
+                    .at_line(line_number, line));
+                }
+                Some(true) => {
+                    return Err(ParseError::new(format!("the rule `{key}` changes a rule given on a previous line"))
+                        .within(line, key)
+                        .at_line(line_number, line));
+                }
+                Some(false) => {}
+            }
+
+            config.set(key, value).map_err(|error| error.within(line, value).at_line(line_number, line))?;
+        }
+
+        Ok(config)
+    }
+
+    /// Reads a rules file, see [`RulesConfig::from_toml`].
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an error if the file can't be read, or if it isn't a valid rules file.
+    pub fn read(path: &str) -> Result<Self, String> {
+        let input = fs::read_to_string(path).map_err(|error| format!("Unable to read the rules file `{path}`: {error}."))?;
+        Self::from_toml(&input).map_err(|error| format!("The rules file `{path}` is invalid, {error}."))
+    }
+
+    /// Reads the rules given by the options of the binary, such as `--outcome-scores 0,3,6`.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an error if the value of an option is invalid.
+    pub fn from_arguments(arguments: &Arguments) -> Result<Self, String> {
+        Ok(Self {
+            game: arguments.optional_parameter("game")?,
+            hand_scores: scores_option(arguments, "hand-scores")?,
+            outcome_scores: match scores_option(arguments, "outcome-scores")? {
+                Some(scores) => Some(OutcomeScores::from_slice(&scores).ok_or_else(|| {
+                    format!("The option `--outcome-scores` expects the points of a loss, a draw and a win, found {} numbers.", scores.len())
+                })?),
+                None => None,
+            },
+            elf_symbols: symbols_option(arguments, "elf-symbols")?,
+            hand_symbols: symbols_option(arguments, "hand-symbols")?,
+            result_symbols: symbols_option(arguments, "result-symbols")?,
+        })
+    }
+
+    /// Combines two configurations, the rules given by the other configuration replace those of this one.
+    pub fn merge(self, other: Self) -> Self {
+        Self {
+            game: other.game.or(self.game),
+            hand_scores: other.hand_scores.or(self.hand_scores),
+            outcome_scores: other.outcome_scores.or(self.outcome_scores),
+            elf_symbols: other.elf_symbols.or(self.elf_symbols),
+            hand_symbols: other.hand_symbols.or(self.hand_symbols),
+            result_symbols: other.result_symbols.or(self.result_symbols),
+        }
+    }
+
+    /// Applies the changes to the rules of the puzzle.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an error if the rules are inconsistent, such as a number of hand scores that isn't the number of hands,
+    /// or two hands written with the same symbol.
+    pub fn into_rules(self) -> Result<Rules, String> {
+        // the hand scores apply to the game, whether it was changed or not
+        let mut game = self.game.unwrap_or_default();
+        if let Some(hand_scores) = &self.hand_scores {
+            game = game.with_hand_scores(hand_scores)?;
+        }
+
+        // the symbols that aren't given are the letters of the puzzle
+        let hands = game.hands().len();
+        let letters = Encoding::letters(hands);
+        let encoding = Encoding::new(
+            self.elf_symbols.unwrap_or(letters.elf_symbols),
+            self.hand_symbols.unwrap_or(letters.hand_symbols),
+            self.result_symbols.unwrap_or(letters.result_symbols),
+            hands,
+        )?;
+
+        Rules::new(game, self.outcome_scores.unwrap_or_default(), encoding)
+    }
+
+    /// Checks if a rule of the rules file was already given, or returns `None` if the rule is unknown.
+    fn is_given(&self, key: &str) -> Option<bool> {
+        // the game is given either by its name or by its hands
+        match key {
+            "game" | "hands" => Some(self.game.is_some()),
+            "hand_scores" => Some(self.hand_scores.is_some()),
+            "outcome_scores" => Some(self.outcome_scores.is_some()),
+            "elf_symbols" => Some(self.elf_symbols.is_some()),
+            "hand_symbols" => Some(self.hand_symbols.is_some()),
+            "result_symbols" => Some(self.result_symbols.is_some()),
+            _ => None,
+        }
+    }
+
+    /// Sets a rule of the rules file, the error is relative to the value.
+    fn set(&mut self, key: &str, value: &str) -> Result<(), ParseError> {
+        match key {
+            "game" => self.game = Some(parse_string(value)?.parse().map_err(|error: String| ParseError::new(error).within(value, value))?),
+            "hands" => {
+                let names = parse_array(value, parse_string)?;
+                self.game = Some(Game::cyclic(&names).map_err(|error| ParseError::new(error).within(value, value))?);
+            }
+            "hand_scores" => self.hand_scores = Some(parse_array(value, |score| parse_number(score, "a number of points"))?),
+            "outcome_scores" => {
+                let scores = parse_array(value, |score| parse_number(score, "a number of points"))?;
+                let outcome_scores = OutcomeScores::from_slice(&scores)
+                    .ok_or_else(|| ParseError::new("expected the points of a loss, a draw and a win, such as `[0, 3, 6]`").within(value, value))?;
+                self.outcome_scores = Some(outcome_scores);
+            }
+            "elf_symbols" => self.elf_symbols = Some(parse_string(value)?.chars().collect()),
+            "hand_symbols" => self.hand_symbols = Some(parse_string(value)?.chars().collect()),
+            "result_symbols" => self.result_symbols = Some(parse_string(value)?.chars().collect()),
+            _ => unreachable!("The unknown rules are rejected before being set"),
+        }
+
+        Ok(())
+    }
+}
+
+/// Removes the comment at the end of a line of the rules file, if any.
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    for (index, c) in line.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..index],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Parses a string between double quotes, the error spans the whole fragment.
+fn parse_string(fragment: &str) -> Result<&str, ParseError> {
+    fragment
+        .strip_prefix('"')
+        .and_then(|fragment| fragment.strip_suffix('"'))
+        .filter(|content| !content.contains('"'))
+        .ok_or_else(|| ParseError::new(format!("expected a string between double quotes, found `{fragment}`")).within(fragment, fragment))
+}
+
+/// Parses an array whose items are separated by commas, such as `[1, 2, 3]`, the errors are relative to the fragment.
+fn parse_array<'a, T>(fragment: &'a str, parse_item: impl Fn(&'a str) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+    let items = fragment
+        .strip_prefix('[')
+        .and_then(|fragment| fragment.strip_suffix(']'))
+        .ok_or_else(|| ParseError::new(format!("expected an array between brackets, found `{fragment}`")).within(fragment, fragment))?;
+
+    // an empty array has no item, and a trailing comma is allowed
+    let mut items: Vec<&str> = items.split(',').map(str::trim).collect();
+    if items.last() == Some(&"") {
+        items.pop();
+    }
+
+    items
+        .into_iter()
+        .map(|item| parse_item(item).map_err(|error| error.within(fragment, item)))
+        .collect()
+}
+
+/// Parses the value of an option listing numbers separated by commas, such as `--hand-scores 1,2,3`, if it was given.
+fn scores_option(arguments: &Arguments, name: &str) -> Result<Option<Vec<usize>>, String> {
+    let Some(value) = arguments.optional_parameter::<String>(name)? else {
+        return Ok(None);
+    };
+
+    value
+        .split(',')
+        .map(|score| score.trim().parse().map_err(|_| format!("`{value}` is not a valid value for the option `--{name}`, expected numbers separated by commas.")))
+        .collect::<Result<Vec<usize>, String>>()
+        .map(Some)
+}
+
+/// Parses the value of an option listing symbols, such as `--elf-symbols ABC`, if it was given.
+fn symbols_option(arguments: &Arguments, name: &str) -> Result<Option<Vec<char>>, String> {
+    Ok(arguments.optional_parameter::<String>(name)?.map(|value| value.chars().collect()))
+}
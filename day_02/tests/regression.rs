@@ -0,0 +1,270 @@
+use aoc_common::{MaybeAnswer, Solution, Streaming};
+use aoc_common::{Arguments, Parameters};
+use day_02::{
+    game::{Game, HandDefinition, Outcome},
+    hand::Hand,
+    round_result::RoundResult,
+    rules::{Encoding, OutcomeScores, Rules, RulesConfig},
+    Day02,
+};
+
+/// The example from the puzzle description.
+const EXAMPLE: &str = "\
+A Y
+B X
+C Z
+";
+
+/// A tournament of rock-paper-scissors-lizard-Spock, whose hands are read from `A` to `E` and from `V` to `Z`.
+const LIZARD_SPOCK_EXAMPLE: &str = "\
+A X
+C V
+E Z
+";
+
+/// The puzzle input of the repository.
+const INPUT: &str = include_str!("../../inputs/day_02.txt");
+
+#[test]
+fn example() {
+    assert_eq!(Day02::solve(EXAMPLE).unwrap(), (MaybeAnswer::Answer(15), MaybeAnswer::Answer(12)));
+}
+
+#[test]
+fn input() {
+    assert_eq!(Day02::solve(INPUT).unwrap(), (MaybeAnswer::Answer(9_651), MaybeAnswer::Answer(10_560)));
+}
+
+#[test]
+fn streamed_example() {
+    assert_eq!(Day02::solve_reader(EXAMPLE.as_bytes()).unwrap(), (MaybeAnswer::Answer(15), MaybeAnswer::Answer(12)));
+}
+
+#[test]
+fn streamed_input() {
+    assert_eq!(Day02::solve_reader(INPUT.as_bytes()).unwrap(), (MaybeAnswer::Answer(9_651), MaybeAnswer::Answer(10_560)));
+}
+
+#[test]
+fn streamed_crlf() {
+    // the carriage returns are removed along with the line feeds
+    let answers = (MaybeAnswer::Answer(15), MaybeAnswer::Answer(12));
+    assert_eq!(Day02::solve_reader(EXAMPLE.replace('\n', "\r\n").as_bytes()).unwrap(), answers);
+}
+
+#[test]
+fn rock_paper_scissors() {
+    let game = Game::rock_paper_scissors();
+
+    // each hand beats the one before it in the cycle
+    assert!(game.beats(Hand::ROCK, Hand::SCISSORS));
+    assert!(game.beats(Hand::PAPER, Hand::ROCK));
+    assert!(game.beats(Hand::SCISSORS, Hand::PAPER));
+    assert_eq!(game.outcome(Hand::ROCK, Hand::PAPER), Outcome::Loss);
+    assert_eq!(game.outcome(Hand::ROCK, Hand::ROCK), Outcome::Draw);
+
+    // the hand to show for each result
+    assert_eq!(game.hand_for(Hand::ROCK, RoundResult::WIN), Hand::PAPER);
+    assert_eq!(game.hand_for(Hand::ROCK, RoundResult::LOSE), Hand::SCISSORS);
+    assert_eq!(game.hand_for(Hand::SCISSORS, RoundResult::DRAW), Hand::SCISSORS);
+}
+
+#[test]
+fn rock_paper_scissors_lizard_spock() {
+    let game = Game::rock_paper_scissors_lizard_spock();
+    let hand = |name: &str| Hand(game.hands().iter().position(|hand| hand.name == name).unwrap());
+
+    // the rules of the game, as listed by Sheldon
+    for (winner, loser) in [
+        ("Scissors", "Paper"),
+        ("Paper", "Rock"),
+        ("Rock", "Lizard"),
+        ("Lizard", "Spock"),
+        ("Spock", "Scissors"),
+        ("Scissors", "Lizard"),
+        ("Lizard", "Paper"),
+        ("Paper", "Spock"),
+        ("Spock", "Rock"),
+        ("Rock", "Scissors"),
+    ] {
+        assert_eq!(game.outcome(hand(winner), hand(loser)), Outcome::Win, "{winner} beats {loser}");
+        assert_eq!(game.outcome(hand(loser), hand(winner)), Outcome::Loss, "{loser} loses to {winner}");
+    }
+}
+
+#[test]
+fn balanced_games() {
+    for game in [Game::rock_paper_scissors(), Game::rock_paper_scissors_lizard_spock(), Game::rock_paper_scissors_7()] {
+        let hands = game.hands().len();
+        for hand in (0..hands).map(Hand) {
+            // each hand beats half of the other hands, and loses to the other half
+            let beaten = (0..hands).map(Hand).filter(|&other| game.beats(hand, other)).count();
+            assert_eq!(beaten, hands / 2);
+            assert!(!game.beats(hand, hand));
+
+            // the hand to show gets the expected result
+            for offset in -(hands as isize / 2)..=hands as isize / 2 {
+                let expected = match offset {
+                    0 => Outcome::Draw,
+                    offset if offset > 0 => Outcome::Win,
+                    _ => Outcome::Loss,
+                };
+                assert_eq!(game.outcome(game.hand_for(hand, RoundResult(offset)), hand), expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn invalid_games() {
+    assert!(Game::cyclic(&["Rock", "Paper"]).is_err());
+    assert!(Game::cyclic(&["Rock", "Paper", "Scissors", "Lizard"]).is_err());
+    assert!(Game::new(vec![HandDefinition { name: "Rock".to_owned(), score: 1 }; 15]).is_err());
+    assert!("rpsls".parse::<Game>().is_ok());
+    assert!("chess".parse::<Game>().is_err());
+}
+
+#[test]
+fn lizard_spock_example() {
+    let rules = Rules::for_game(Game::rock_paper_scissors_lizard_spock());
+    let rounds = Day02::parse_with(LIZARD_SPOCK_EXAMPLE, &rules).unwrap();
+
+    // Paper beats Rock (3 + 6), Rock loses to Paper (1 + 0), Scissors draws (5 + 3)
+    assert_eq!(Day02::part_one_with(&rounds, &rules), Some(18));
+
+    // Rock draws (1 + 3), Rock loses to Paper (1 + 0), Spock beats Scissors (2 + 6)
+    assert_eq!(Day02::part_two_with(&rounds, &rules), Some(13));
+    assert_eq!(Day02::stream_with(LIZARD_SPOCK_EXAMPLE.as_bytes(), &rules).unwrap(), (18, 13));
+}
+
+#[test]
+fn symbols() {
+    let encoding = Encoding::letters(5);
+    assert_eq!(encoding.elf_symbols(), ['A', 'B', 'C', 'D', 'E']);
+    assert_eq!(encoding.hand_symbols(), ['V', 'W', 'X', 'Y', 'Z']);
+    assert_eq!(encoding.result_symbols(), ['V', 'W', 'X', 'Y', 'Z']);
+
+    // the letters of rock-paper-scissors are only valid in their own column
+    let rules = Rules::default();
+    let error = Day02::parse("X Y\n").unwrap_err();
+    assert!(error.to_string().contains("expected a hand (`A`, `B` or `C`), found `X`"), "{error}");
+    let error = Day02::parse("B Q\n").unwrap_err();
+    assert!(error.to_string().contains("expected a hand or a result (`X`, `Y` or `Z`), found `Q`"), "{error}");
+    assert!(Day02::parse_with("E Z\n", &rules).is_err());
+    assert!(Day02::parse_with("E Z\n", &Rules::for_game(Game::rock_paper_scissors_lizard_spock())).is_ok());
+}
+
+/// Parses the rules given on the command line.
+fn rules_from_arguments(args: &[&str]) -> Result<Rules, String> {
+    let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+    Arguments::parse_with::<Rules>(&args).map(|(_, rules)| rules)
+}
+
+#[test]
+fn rules_files() {
+    // the rules file of the puzzle spells out the default rules
+    let puzzle = RulesConfig::from_toml(include_str!("../rules/puzzle.toml")).unwrap();
+    assert_eq!(puzzle.into_rules().unwrap(), Rules::default());
+
+    let rpsls = RulesConfig::from_toml(include_str!("../rules/rpsls.toml")).unwrap();
+    assert_eq!(rpsls.into_rules().unwrap(), Rules::for_game(Game::rock_paper_scissors_lizard_spock()));
+
+    // an empty rules file changes nothing
+    assert_eq!(RulesConfig::from_toml("# nothing to change\n\n").unwrap(), RulesConfig::default());
+}
+
+#[test]
+fn custom_scores() {
+    let config = RulesConfig::from_toml("hand_scores = [3, 2, 1]\noutcome_scores = [0, 1, 2]  # a loss, a draw and a win\n").unwrap();
+    let rules = config.into_rules().unwrap();
+    assert_eq!(rules.outcome_scores(), OutcomeScores { loss: 0, draw: 1, win: 2 });
+
+    // Paper beats Rock (2 + 2), Rock loses to Paper (3 + 0), Scissors draws (1 + 1)
+    let rounds = Day02::parse_with(EXAMPLE, &rules).unwrap();
+    assert_eq!(Day02::part_one_with(&rounds, &rules), Some(9));
+
+    // Rock draws (3 + 1), Rock loses to Paper (3 + 0), Rock beats Scissors (3 + 2)
+    assert_eq!(Day02::part_two_with(&rounds, &rules), Some(12));
+    assert_eq!(Day02::stream_with(EXAMPLE.as_bytes(), &rules).unwrap(), (9, 12));
+}
+
+#[test]
+fn huge_scores() {
+    // a win is worth so much that a single round overflows
+    let rules = rules_from_arguments(&["--outcome-scores", &format!("0,1,{}", usize::MAX)]).unwrap();
+    let rounds = Day02::parse_with(EXAMPLE, &rules).unwrap();
+    assert_eq!(Day02::part_one_with(&rounds, &rules), None);
+    assert_eq!(Day02::part_one_answer(&rounds, &rules), MaybeAnswer::NoAnswer("the score doesn't fit in a usize"));
+    let error = Day02::stream_with(EXAMPLE.as_bytes(), &rules).unwrap_err();
+    assert_eq!(error.line, Some(1));
+
+    // each round fits, but not the total of the second method, whose first two rounds play Rock
+    let rules = rules_from_arguments(&["--hand-scores", &format!("{},1,1", usize::MAX / 2)]).unwrap();
+    let rounds = Day02::parse_with(EXAMPLE, &rules).unwrap();
+    assert_eq!(Day02::part_one_with(&rounds, &rules), Some(usize::MAX / 2 + 11));
+    assert_eq!(Day02::part_two_with(&rounds, &rules), None);
+    let error = Day02::stream_with(EXAMPLE.as_bytes(), &rules).unwrap_err();
+    assert_eq!(error.line, Some(2));
+}
+
+#[test]
+fn custom_symbols() {
+    // the hands of the elf are written with their initials
+    let rules = rules_from_arguments(&["--elf-symbols", "RPS"]).unwrap();
+    assert_eq!(Day02::parse_with("R Y\nP X\nS Z\n", &rules).map(|rounds| Day02::part_one_with(&rounds, &rules)), Ok(Some(15)));
+    assert!(Day02::parse_with(EXAMPLE, &rules).is_err());
+
+    // `Z` is a loss and `X` a win, so the second method plays Rock (1 + 3), Scissors (3 + 6) and Paper (2 + 0)
+    let rules = rules_from_arguments(&["--result-symbols", "ZYX"]).unwrap();
+    let rounds = Day02::parse_with(EXAMPLE, &rules).unwrap();
+    assert_eq!((Day02::part_one_with(&rounds, &rules), Day02::part_two_with(&rounds, &rules)), (Some(15), Some(15)));
+}
+
+#[test]
+fn rules_options() {
+    assert_eq!(rules_from_arguments(&[]), Ok(Rules::default()));
+    assert_eq!(rules_from_arguments(&["--game", "rpsls"]), Ok(Rules::for_game(Game::rock_paper_scissors_lizard_spock())));
+
+    // the options change the rules file
+    let rules = rules_from_arguments(&["--rules", "rules/rpsls.toml", "--outcome-scores", "1,2,3"]).unwrap();
+    assert_eq!(rules.game(), &Game::rock_paper_scissors_lizard_spock());
+    assert_eq!(rules.outcome_scores(), OutcomeScores { loss: 1, draw: 2, win: 3 });
+    assert_eq!(rules.encoding().elf_symbols(), ['A', 'B', 'C', 'D', 'E']);
+
+    // the rules must be consistent
+    assert!(rules_from_arguments(&["--outcome-scores", "0,3"]).is_err());
+    assert!(rules_from_arguments(&["--hand-scores", "1,2,x"]).is_err());
+    assert!(rules_from_arguments(&["--game", "rpsls", "--hand-scores", "1,2,3"]).is_err());
+    assert!(rules_from_arguments(&["--elf-symbols", "AAB"]).is_err());
+    assert!(rules_from_arguments(&["--elf-symbols", "A C"]).is_err());
+    assert!(rules_from_arguments(&["--hand-symbols", "XYW"]).is_err());
+    assert!(rules_from_arguments(&["--rules", "rules/missing.toml"]).is_err());
+    assert!(Rules::OPTIONS.iter().any(|(name, _)| *name == "rules"));
+}
+
+#[test]
+fn rules_diagnostics() {
+    // each error points at the offending part of the line
+    let error = RulesConfig::from_toml("game = \"rps\"\nlizard = true\n").unwrap_err();
+    assert_eq!(error.to_string(), "line 2, column 1: expected a rule (`game`, `hands`, `hand_scores`, `outcome_scores`, `elf_symbols`, `hand_symbols` or `result_symbols`), found `lizard`");
+    assert_eq!(error.offending_text(), Some("lizard"));
+
+    let error = RulesConfig::from_toml("hand_scores = [1, two, 3]\n").unwrap_err();
+    assert_eq!(error.to_string(), "line 1, column 19: expected a number of points, found `two`");
+
+    let error = RulesConfig::from_toml("game = \"rps\"\nhands = [\"Rock\", \"Paper\", \"Scissors\"]\n").unwrap_err();
+    assert_eq!(error.offending_text(), Some("hands"));
+
+    let error = RulesConfig::from_toml("outcome_scores = [0, 3]\n").unwrap_err();
+    assert_eq!(error.offending_text(), Some("[0, 3]"));
+
+    let error = RulesConfig::from_toml("game = rps\n").unwrap_err();
+    assert_eq!(error.to_string(), "line 1, column 8: expected a string between double quotes, found `rps`");
+
+    let error = RulesConfig::from_toml("game = \"chess\"\n").unwrap_err();
+    assert!(error.to_string().contains("`chess` is not a valid game"), "{error}");
+
+    // a `#` only starts a comment outside of strings
+    let config = RulesConfig::from_toml("elf_symbols = \"#@!\" # the hands of the elf\n").unwrap();
+    assert_eq!(config.elf_symbols, Some(vec!['#', '@', '!']));
+}
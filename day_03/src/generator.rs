@@ -0,0 +1,44 @@
+use aoc_common::random::Rng;
+
+/// The letters used as items, in the order of their priorities.
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Generates a valid input made of `size` groups of three elves.
+/// 
+/// Like in the real inputs, both compartments of a rucksack share exactly one type of item,
+/// and the three rucksacks of a group share exactly one type of item, their badge.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size {
+        // pick the badge, and split the other items between the three rucksacks so they don't share anything else
+        let mut items = ITEMS.to_vec();
+        rng.shuffle(&mut items);
+        let badge = items[0];
+
+        for pool in items[1..].chunks(17) {
+            // pick the item shared by both compartments, and split the other items between them
+            let shared = pool[0];
+            let (left_pool, right_pool) = pool[1..].split_at(rng.usize(0..pool.len()));
+
+            // fill both compartments, the badge goes in the first one
+            let compartment_size = rng.usize(2..17);
+            let mut compartment = |pool: &[u8], required: &[u8]| {
+                let mut compartment = required.to_vec();
+                while compartment.len() < compartment_size {
+                    compartment.push(if pool.is_empty() { shared } else { *rng.choose(pool) });
+                }
+                rng.shuffle(&mut compartment);
+                compartment
+            };
+            let left = compartment(left_pool, &[shared, badge]);
+            let right = compartment(right_pool, &[shared]);
+
+            input.push_str(&String::from_utf8_lossy(&left));
+            input.push_str(&String::from_utf8_lossy(&right));
+            input.push('\n');
+        }
+    }
+
+    input
+}
@@ -0,0 +1,24 @@
+/// Special iterator that packs the output of another iterator into groups of 3 elements.
+pub struct GroupIter<I: Iterator> {
+    iter: I
+}
+
+impl<I: Iterator> Iterator for GroupIter<I> {
+    type Item = [I::Item; 3];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some([self.iter.next()?, self.iter.next()?, self.iter.next()?])
+    }
+}
+
+/// Adds the `group` method to every iterator.
+pub trait GroupIterTrait<I: Iterator> {
+    /// Packs the output of the iterator into groups of 3 elements.
+    fn group(self) -> GroupIter<I>;
+}
+
+impl<I: Iterator> GroupIterTrait<I> for I {
+    fn group(self) -> GroupIter<Self> {
+        GroupIter { iter: self }
+    }
+}
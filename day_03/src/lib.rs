@@ -0,0 +1,139 @@
+pub mod item;
+pub mod group;
+pub mod generator;
+
+use std::{collections::HashSet, io::BufRead};
+use aoc_common::{parse::{numbered_lines, numbered_reader_lines}, ParseError, Solution, Streaming};
+use group::GroupIterTrait;
+use item::item_to_priority;
+
+/// The content of a rucksack, as the priorities of its items.
+pub type Rucksack = Vec<usize>;
+
+/// Parses a line of the input into a rucksack.
+/// 
+/// # Errors
+/// 
+/// Returns an error if one of the items isn't a letter, or if the items can't be split into two compartments of equal size.
+pub fn parse_rucksack(line: &str) -> Result<Rucksack, ParseError> {
+    // convert each item to its priority
+    let rucksack = line
+        .char_indices()
+        .map(|(index, item)| {
+            item_to_priority(item).map_err(|error| error.within(line, &line[index..index + item.len_utf8()]))
+        })
+        .collect::<Result<Rucksack, ParseError>>()?;
+
+    // the rucksack has two compartments of equal size
+    if rucksack.len() % 2 != 0 {
+        return Err(ParseError::new(format!(
+            "expected an even number of items to fill both compartments, found {}",
+            rucksack.len()
+        )));
+    }
+
+    Ok(rucksack)
+}
+
+/// Finds the item found in both compartments of a rucksack, and returns its priority (0 if there is none).
+pub fn shared_item_priority(rucksack: &[usize]) -> usize {
+    // create a set to store the items in the rucksack
+    let mut items = HashSet::new();
+
+    // the rucksack has two compartments of equal size
+    // compute the size of each compartment
+    let compartment_size = rucksack.len() / 2;
+
+    // add the items of the first compartment to the set
+    for item in &rucksack[0..compartment_size] {
+        items.insert(item);
+    }
+
+    // look for the first item of the second compartment that is already in the set
+    // stop at the first one, as we don't want to count doubloons
+    rucksack[compartment_size..]
+        .iter()
+        .find(|item| items.contains(item))
+        .copied()
+        .unwrap_or(0)
+}
+
+/// Finds the badge of a group of three elves, the item found in their three rucksacks, and returns its priority (0 if there is none).
+pub fn badge_priority(rucksack_1: &[usize], rucksack_2: &[usize], rucksack_3: &[usize]) -> usize {
+    // create a set to store the items in the first rucksack
+    let mut rucksack_1_items = HashSet::new();
+    // create a set to store the items in the second rucksack
+    let mut rucksack_2_items = HashSet::new();
+
+    // add the items of the first rucksack to the first set
+    for item in rucksack_1 {
+        rucksack_1_items.insert(item);
+    }
+    // add the items of the second rucksack to the second set if they already exist in the first set
+    for item in rucksack_2 {
+        if rucksack_1_items.contains(item) {
+            rucksack_2_items.insert(item);
+        }
+    }
+    // find the item in the third rucksack that is already in the previous two sets
+    rucksack_3
+        .iter()
+        .find(|item| rucksack_2_items.contains(item))
+        .copied()
+        .unwrap_or(0)
+}
+
+/// The goal of this challenge is to find the items that are in multiple compartments or rucksacks.
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Rucksack>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        numbered_lines(input)
+            .map(|(line_number, line)| parse_rucksack(line).map_err(|error| error.at_line(line_number, line)))
+            .collect()
+    }
+
+    /// The sum of the priorities of the items found in both compartments of a rucksack.
+    fn part_one(rucksacks: &Self::Input) -> Self::PartOne {
+        rucksacks.iter().map(|rucksack| shared_item_priority(rucksack)).sum()
+    }
+
+    /// The sum of the priorities of the badges of each group.
+    fn part_two(rucksacks: &Self::Input) -> Self::PartTwo {
+        rucksacks
+            .iter()
+            .group()
+            .map(|[rucksack_1, rucksack_2, rucksack_3]| badge_priority(rucksack_1, rucksack_2, rucksack_3))
+            .sum()
+    }
+}
+
+impl Streaming for Day03 {
+    fn stream(reader: impl BufRead) -> Result<(Self::PartOne, Self::PartTwo), ParseError> {
+        let mut sum_of_priorities_1: usize = 0;
+        let mut sum_of_priorities_2: usize = 0;
+
+        // only the rucksacks of the current group are kept, an incomplete group at the end has no badge
+        let mut group: Vec<Rucksack> = Vec::with_capacity(3);
+
+        for line in numbered_reader_lines(reader) {
+            let (line_number, line) = line?;
+            let rucksack = parse_rucksack(&line).map_err(|error| error.at_line(line_number, &line))?;
+            sum_of_priorities_1 += shared_item_priority(&rucksack);
+
+            group.push(rucksack);
+            if let [rucksack_1, rucksack_2, rucksack_3] = group.as_slice() {
+                sum_of_priorities_2 += badge_priority(rucksack_1, rucksack_2, rucksack_3);
+                group.clear();
+            }
+        }
+
+        Ok((sum_of_priorities_1, sum_of_priorities_2))
+    }
+}
@@ -1,171 +1,42 @@
-use std::{fs, collections::HashSet};
+use aoc_common::{
+    load_input_or_exit, memory::CountingAllocator, open_input_or_exit, print_streamed_answers, print_timings, Arguments, Command,
+    Format, Solution, Streaming,
+};
+use day_03::Day03;
 
-/// Converts a char representing an item to a priority
-/// 
-/// # Arguments
-/// 
-/// * `item` - The item to convert
-/// 
-/// # Priorities
-/// 
-/// a-z: 1-26
-/// A-Z: 27-52
-fn item_to_priority(item: char) -> usize {
-    match item {
-        'a' => 1,
-        'b' => 2,
-        'c' => 3,
-        'd' => 4,
-        'e' => 5,
-        'f' => 6,
-        'g' => 7,
-        'h' => 8,
-        'i' => 9,
-        'j' => 10,
-        'k' => 11,
-        'l' => 12,
-        'm' => 13,
-        'n' => 14,
-        'o' => 15,
-        'p' => 16,
-        'q' => 17,
-        'r' => 18,
-        's' => 19,
-        't' => 20,
-        'u' => 21,
-        'v' => 22,
-        'w' => 23,
-        'x' => 24,
-        'y' => 25,
-        'z' => 26,
-        'A' => 27,
-        'B' => 28,
-        'C' => 29,
-        'D' => 30,
-        'E' => 31,
-        'F' => 32,
-        'G' => 33,
-        'H' => 34,
-        'I' => 35,
-        'J' => 36,
-        'K' => 37,
-        'L' => 38,
-        'M' => 39,
-        'N' => 40,
-        'O' => 41,
-        'P' => 42,
-        'Q' => 43,
-        'R' => 44,
-        'S' => 45,
-        'T' => 46,
-        'U' => 47,
-        'V' => 48,
-        'W' => 49,
-        'X' => 50,
-        'Y' => 51,
-        'Z' => 52,
-        _ => panic!("Invalid item: {}", item),
-    }
-}
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
 
 /// The goal of this challenge is to find the items that are in multiple compartments or rucksacks.
 fn main() {
-    // read the input file
-    let input = fs::read_to_string("inputs/day_03.txt").expect("Unable to read the input file");
-
-    // create a variable to store the sum of the priorities of the items
-    let mut sum_of_priorities_1 = 0;
-
-    // for each rucksack
-    for rucksack in input.lines() {
-        // create a set to store the items in the rucksack
-        let mut items = HashSet::new();
-
-        // the rucksack has two compartments of equal size
-        // compute the size of each compartment
-        let compartment_size = rucksack.len() / 2;
-        
-        // add the items of the first compartment to the set
-        for item in rucksack[0..compartment_size].chars() {
-            items.insert(item);
-        }
+    // read the input file, its path can be given as an argument (`-` for the standard input)
+    let arguments = Arguments::from_env();
 
-        // look for the first item of the second compartment that is already in the set
-        for item in rucksack[compartment_size..].chars() {
-            if items.contains(&item) {
-                // compute the priority of the item
-                let item_priority = item_to_priority(item);
-
-                // add the priority to the sum
-                sum_of_priorities_1 += item_priority;
-
-                // stop looking for items, as we don't want to count doubloons
-                break;
-            }
-        }
+    // the time taken and the heap used by each phase are measured on the whole input, which is loaded at once
+    if arguments.timings {
+        let input = load_input_or_exit(Day03::DAY, arguments.input.as_deref());
+        return print_timings::<Day03>(&input, arguments.format);
     }
 
-    // display the computed sum of the priorities
-    println!("Sum of the priorities of items in both compartments: {sum_of_priorities_1}");
-
-    // create a variable to store the sum of the priorities of the items
-    let mut sum_of_priorities_2 = 0;
-
-    // for each rucksack in the same group
-    for [rucksack_1, rucksack_2, rucksack_3] in input.lines().group() {
-        // create a set to store the items in the first rucksack
-        let mut rucksack_1_items = HashSet::new();
-        // create a set to store the items in the second rucksack
-        let mut rucksack_2_items = HashSet::new();
+    // the input is solved while it is read, so inputs of any size can be solved with a constant memory
+    let reader = open_input_or_exit(Day03::DAY, arguments.input.as_deref());
 
-        // add the items of the first rucksack to the first set
-        for item in rucksack_1.chars() {
-            rucksack_1_items.insert(item);
-        }
-        // add the items of the second rucksack to the second set if they already exist in the first set
-        for item in rucksack_2.chars() {
-            if rucksack_1_items.contains(&item) {
-                rucksack_2_items.insert(item);
-            }
-        }
-        // find the item in the third rucksack that is already in the previous two sets
-        for item in rucksack_3.chars() {
-            if rucksack_2_items.contains(&item) {
-                // compute the priority of the item
-                let item_priority = item_to_priority(item);
-
-                // add the priority to the sum
-                sum_of_priorities_2 += item_priority;
-
-                // stop looking for items
-                break;
-            }
-        }
+    // the answers can be displayed in a machine-readable format instead
+    if arguments.format != Format::Text {
+        return print_streamed_answers::<Day03>(reader, arguments.format, arguments.command);
     }
 
-    // display the computed sum of the priorities
-    println!("Sum of the priorities of the badges: {sum_of_priorities_2}");
+    // compute and display the sums of the priorities
+    let (sum_of_priorities_1, sum_of_priorities_2) = Day03::solve_reader(reader).unwrap_or_else(|error| error.exit());
+    print_sums(arguments.command, sum_of_priorities_1, sum_of_priorities_2);
 }
 
-/// Special iterator that packs the output of another iterator into groups of 3 elements.
-struct GroupIter<I: Iterator> {
-    iter: I
-}
-
-impl<I: Iterator> Iterator for GroupIter<I> {
-    type Item = [I::Item; 3];
-
-    fn next(&mut self) -> Option<Self::Item> {
-        Some([self.iter.next()?, self.iter.next()?, self.iter.next()?])
+/// Displays the sums of the priorities, for the parts that are run.
+fn print_sums(command: Command, sum_of_priorities_1: usize, sum_of_priorities_2: usize) {
+    if command.runs(1) {
+        println!("Sum of the priorities of items in both compartments: {sum_of_priorities_1}");
     }
-}
-
-trait GroupIterTrait<I: Iterator> {
-    fn group(self) -> GroupIter<I>;
-}
-
-impl<I: Iterator> GroupIterTrait<I> for I {
-    fn group(self) -> GroupIter<Self> {
-        GroupIter { iter: self }
+    if command.runs(2) {
+        println!("Sum of the priorities of the badges: {sum_of_priorities_2}");
     }
 }
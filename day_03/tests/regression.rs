@@ -0,0 +1,35 @@
+use aoc_common::{Solution, Streaming};
+use day_03::Day03;
+
+/// The example from the puzzle description.
+const EXAMPLE: &str = "\
+vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+/// The puzzle input of the repository.
+const INPUT: &str = include_str!("../../inputs/day_03.txt");
+
+#[test]
+fn example() {
+    assert_eq!(Day03::solve(EXAMPLE).unwrap(), (157, 70));
+}
+
+#[test]
+fn input() {
+    assert_eq!(Day03::solve(INPUT).unwrap(), (8_018, 2_518));
+}
+
+#[test]
+fn streamed_example() {
+    assert_eq!(Day03::solve_reader(EXAMPLE.as_bytes()).unwrap(), (157, 70));
+}
+
+#[test]
+fn streamed_input() {
+    assert_eq!(Day03::solve_reader(INPUT.as_bytes()).unwrap(), (8_018, 2_518));
+}
@@ -0,0 +1,23 @@
+use aoc_common::random::Rng;
+
+/// Generates a valid input made of `size` pairs of elves.
+/// 
+/// The sections are numbered from 1 to 99, like in the real inputs.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size {
+        // each elf is responsible for a non-empty range of sections
+        let mut sections = || {
+            let start = rng.usize(1..100);
+            let end = rng.usize(start..100);
+            format!("{start}-{end}")
+        };
+
+        let elf_1 = sections();
+        let elf_2 = sections();
+        input.push_str(&format!("{elf_1},{elf_2}\n"));
+    }
+
+    input
+}
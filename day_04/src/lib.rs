@@ -0,0 +1,92 @@
+pub mod range;
+pub mod generator;
+
+use std::{io::BufRead, ops::RangeInclusive};
+use aoc_common::{parse::{numbered_lines, numbered_reader_lines, parse_number}, ParseError, Solution, Streaming};
+use range::ContainsRange;
+
+/// A pair of elves, represented by the sections each elf is responsible for.
+pub type ElfPair = (RangeInclusive<usize>, RangeInclusive<usize>);
+
+/// Parses a pair of elves, represented by a line such as `2-4,6-8`.
+pub fn parse_elf_pair(elf_pair: &str) -> Result<ElfPair, ParseError> {
+    // split the data between the sections each elf is responsible for
+    let (elf_1, elf_2) = elf_pair
+        .split_once(',')
+        .ok_or_else(|| ParseError::new("expected the sections of two elves separated by a comma, such as `2-4,6-8`"))?;
+
+    // find each elf's sections
+    let elf_1_sections = parse_sections(elf_1).map_err(|error| error.within(elf_pair, elf_1))?;
+    let elf_2_sections = parse_sections(elf_2).map_err(|error| error.within(elf_pair, elf_2))?;
+
+    Ok((elf_1_sections, elf_2_sections))
+}
+
+/// Parses the sections an elf is responsible for, represented by a range such as `2-4`.
+fn parse_sections(sections: &str) -> Result<RangeInclusive<usize>, ParseError> {
+    // split the range between its start and its end
+    let (start, end) = sections
+        .split_once('-')
+        .ok_or_else(|| ParseError::new(format!("expected a range of sections such as `2-4`, found `{sections}`")))?;
+
+    // parse both ends of the range
+    let section_start: usize = parse_number(start, "a section number").map_err(|error| error.within(sections, start))?;
+    let section_end: usize = parse_number(end, "a section number").map_err(|error| error.within(sections, end))?;
+
+    Ok(section_start..=section_end)
+}
+
+/// Checks if the sections of one elf of the pair contain the sections of the other elf.
+pub fn is_contained(elf_pair: &ElfPair) -> bool {
+    let (elf_1_sections, elf_2_sections) = elf_pair;
+    elf_1_sections.contains_range(elf_2_sections) || elf_2_sections.contains_range(elf_1_sections)
+}
+
+/// Checks if the sections of both elves of the pair overlap.
+pub fn is_overlapping(elf_pair: &ElfPair) -> bool {
+    let (elf_1_sections, elf_2_sections) = elf_pair;
+    elf_1_sections.overlapping_range(elf_2_sections)
+}
+
+/// The goal of this challenge is to find how many sections of the elfs' sections are contained in the other elfs' sections and how many are overlapping.
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<ElfPair>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        numbered_lines(input)
+            .map(|(line_number, line)| parse_elf_pair(line).map_err(|error| error.at_line(line_number, line)))
+            .collect()
+    }
+
+    /// The number of pairs where one range contains the other.
+    fn part_one(elf_pairs: &Self::Input) -> Self::PartOne {
+        elf_pairs.iter().filter(|elf_pair| is_contained(elf_pair)).count()
+    }
+
+    /// The number of pairs where the ranges overlap.
+    fn part_two(elf_pairs: &Self::Input) -> Self::PartTwo {
+        elf_pairs.iter().filter(|elf_pair| is_overlapping(elf_pair)).count()
+    }
+}
+
+impl Streaming for Day04 {
+    fn stream(reader: impl BufRead) -> Result<(Self::PartOne, Self::PartTwo), ParseError> {
+        // count the pairs as soon as they are read
+        let mut counts = (0, 0);
+        for line in numbered_reader_lines(reader) {
+            let (line_number, line) = line?;
+            let elf_pair = parse_elf_pair(&line).map_err(|error| error.at_line(line_number, &line))?;
+
+            counts.0 += usize::from(is_contained(&elf_pair));
+            counts.1 += usize::from(is_overlapping(&elf_pair));
+        }
+
+        Ok(counts)
+    }
+}
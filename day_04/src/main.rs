@@ -1,64 +1,42 @@
-use std::fs;
-use std::ops::RangeInclusive;
+use aoc_common::{
+    load_input_or_exit, memory::CountingAllocator, open_input_or_exit, print_streamed_answers, print_timings, Arguments, Command,
+    Format, Solution, Streaming,
+};
+use day_04::Day04;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
 
 /// The goal of this challenge is to find how many sections of the elfs' sections are contained in the other elfs' sections and how many are overlapping.
 fn main() {
-    // read the input file
-    let input = fs::read_to_string("inputs/day_04.txt").expect("Unable to read the input file");
-
-    // create a variable to count how many sections are contained in the other elf's sections
-    let mut contained_count: usize = 0;
-    // create a variable to count how many sections are overlapping the other elf's sections
-    let mut overlapping_count: usize = 0;
-
-    // for each pair of elves
-    for elf_pair in input.lines() {
-        // split the data between the sections each elf is responsible for
-        let split: Vec<&str> = elf_pair.split(",").collect();
-
-        // find the first elf's sections
-        let elf_1_split: Vec<&str> = split[0].split("-").collect();
-        let elf_1_section_start: usize = elf_1_split[0].parse().unwrap();
-        let elf_1_section_end: usize = elf_1_split[1].parse().unwrap();
-        let elf_1_sections = elf_1_section_start..=elf_1_section_end;
-        
-        // find the second elf's sections
-        let elf_2_split: Vec<&str> = split[1].split("-").collect();
-        let elf_2_section_start: usize = elf_2_split[0].parse().unwrap();
-        let elf_2_section_end: usize = elf_2_split[1].parse().unwrap();
-        let elf_2_sections = elf_2_section_start..=elf_2_section_end;
+    // read the input file, its path can be given as an argument (`-` for the standard input)
+    let arguments = Arguments::from_env();
 
-        // find if one is contained in the other
-        if elf_1_sections.contains_range(&elf_2_sections) || elf_2_sections.contains_range(&elf_1_sections) {
-            contained_count += 1;
-        }
-
-        // find if one is overlapping the other
-        if elf_1_sections.overlapping_range(&elf_2_sections) {
-            overlapping_count += 1;
-        }
+    // the time taken and the heap used by each phase are measured on the whole input, which is loaded at once
+    if arguments.timings {
+        let input = load_input_or_exit(Day04::DAY, arguments.input.as_deref());
+        return print_timings::<Day04>(&input, arguments.format);
     }
 
-    // print the results
-    println!("In total, {contained_count} ranges are contained in the other elf's ranges");
-    println!("In total, {overlapping_count} ranges are overlapping the other elf's ranges");
-}
+    // the input is solved while it is read, so inputs of any size can be solved with a constant memory
+    let reader = open_input_or_exit(Day04::DAY, arguments.input.as_deref());
 
-/// Trait that adds methods to detect if two ranges are contained in each other or if they are overlapping
-trait ContainsRange {
-    /// Returns true if the range contains the other range
-    fn contains_range(&self, range: &RangeInclusive<usize>) -> bool;
+    // the answers can be displayed in a machine-readable format instead
+    if arguments.format != Format::Text {
+        return print_streamed_answers::<Day04>(reader, arguments.format, arguments.command);
+    }
 
-    /// Returns true if the range is overlapping the other range
-    fn overlapping_range(&self, range: &RangeInclusive<usize>) -> bool;
+    // count and print the contained and overlapping ranges
+    let (contained, overlapping) = Day04::solve_reader(reader).unwrap_or_else(|error| error.exit());
+    print_counts(arguments.command, contained, overlapping);
 }
 
-impl ContainsRange for RangeInclusive<usize> {
-    fn contains_range(&self, range: &RangeInclusive<usize>) -> bool {
-        self.contains(&range.start()) && self.contains(&range.end())
+/// Prints the number of contained and overlapping ranges, for the parts that are run.
+fn print_counts(command: Command, contained: usize, overlapping: usize) {
+    if command.runs(1) {
+        println!("In total, {contained} ranges are contained in the other elf's ranges");
     }
-
-    fn overlapping_range(&self, range: &RangeInclusive<usize>) -> bool {
-        self.start() <= range.end() && range.start() <= self.end()
+    if command.runs(2) {
+        println!("In total, {overlapping} ranges are overlapping the other elf's ranges");
     }
 }
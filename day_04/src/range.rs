@@ -0,0 +1,20 @@
+use std::ops::RangeInclusive;
+
+/// Trait that adds methods to detect if two ranges are contained in each other or if they are overlapping
+pub trait ContainsRange {
+    /// Returns true if the range contains the other range
+    fn contains_range(&self, range: &RangeInclusive<usize>) -> bool;
+
+    /// Returns true if the range is overlapping the other range
+    fn overlapping_range(&self, range: &RangeInclusive<usize>) -> bool;
+}
+
+impl ContainsRange for RangeInclusive<usize> {
+    fn contains_range(&self, range: &RangeInclusive<usize>) -> bool {
+        self.contains(&range.start()) && self.contains(&range.end())
+    }
+
+    fn overlapping_range(&self, range: &RangeInclusive<usize>) -> bool {
+        self.start() <= range.end() && range.start() <= self.end()
+    }
+}
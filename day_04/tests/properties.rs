@@ -0,0 +1,22 @@
+use aoc_common::{random::check_property, Solution};
+use day_04::{generator::generate_input, range::ContainsRange, Day04};
+
+#[test]
+fn containment_implies_overlap() {
+    check_property(200, |rng| {
+        let input = generate_input(rng, 50);
+        let elf_pairs = Day04::parse(&input).unwrap();
+
+        for (elf_1, elf_2) in &elf_pairs {
+            if elf_1.contains_range(elf_2) || elf_2.contains_range(elf_1) {
+                assert!(elf_1.overlapping_range(elf_2), "{elf_1:?} contains {elf_2:?} without overlapping it");
+            }
+
+            // overlapping doesn't depend on the order of the elves
+            assert_eq!(elf_1.overlapping_range(elf_2), elf_2.overlapping_range(elf_1));
+        }
+
+        // so there are always at least as many overlapping pairs as containing ones
+        assert!(Day04::part_one(&elf_pairs) <= Day04::part_two(&elf_pairs));
+    });
+}
@@ -0,0 +1,35 @@
+use aoc_common::{Solution, Streaming};
+use day_04::Day04;
+
+/// The example from the puzzle description.
+const EXAMPLE: &str = "\
+2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8
+";
+
+/// The puzzle input of the repository.
+const INPUT: &str = include_str!("../../inputs/day_04.txt");
+
+#[test]
+fn example() {
+    assert_eq!(Day04::solve(EXAMPLE).unwrap(), (2, 4));
+}
+
+#[test]
+fn input() {
+    assert_eq!(Day04::solve(INPUT).unwrap(), (456, 808));
+}
+
+#[test]
+fn streamed_example() {
+    assert_eq!(Day04::solve_reader(EXAMPLE.as_bytes()).unwrap(), (2, 4));
+}
+
+#[test]
+fn streamed_input() {
+    assert_eq!(Day04::solve_reader(INPUT.as_bytes()).unwrap(), (456, 808));
+}
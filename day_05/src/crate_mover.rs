@@ -0,0 +1,36 @@
+use crate::supplies::Move;
+
+/// The model of the crane used to move the crates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrateMover {
+    /// Moves the crates one at a time, so their order is reversed.
+    CM9000,
+    /// Moves multiple crates at once, so their order is kept.
+    CM9001,
+}
+
+impl CrateMover {
+    /// The model number of the crane, such as `9000`.
+    pub fn model(&self) -> u16 {
+        match self {
+            CrateMover::CM9000 => 9000,
+            CrateMover::CM9001 => 9001,
+        }
+    }
+
+    /// Executes the instruction on the stacks.
+    pub fn execute(&self, stacks: &mut [Vec<char>], instruction: Move) {
+        let origin_stack_size = stacks[instruction.origin_stack_index].len();
+        let moved_crates = &stacks[instruction.origin_stack_index][origin_stack_size - instruction.quantity..];
+
+        let tmp_stack: Vec<char> = match self {
+            // we take the top n crates from the origin stack, reverse their order, and push them to the destination stack
+            CrateMover::CM9000 => moved_crates.iter().rev().copied().collect(),
+            // we take the top n crates from the origin stack, keep their order, and push them to the destination stack
+            CrateMover::CM9001 => moved_crates.to_vec(),
+        };
+
+        stacks[instruction.origin_stack_index].truncate(origin_stack_size - instruction.quantity);
+        stacks[instruction.destination_stack_index].extend(tmp_stack);
+    }
+}
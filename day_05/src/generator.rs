@@ -0,0 +1,44 @@
+use aoc_common::random::Rng;
+
+/// The number of stacks, like in the real inputs.
+const STACK_COUNT: usize = 9;
+
+/// Generates a valid input made of 9 stacks of crates, followed by `size` moves that can all be executed.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    // pile up a few crates on each stack
+    let mut stacks: Vec<Vec<char>> = (0..STACK_COUNT)
+        .map(|_| (0..rng.usize(1..9)).map(|_| (b'A' + rng.below(26) as u8) as char).collect())
+        .collect();
+
+    // draw the stacks from the top, every row has the same width
+    let mut input = String::new();
+    let highest_stack = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    for level in (0..highest_stack).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|stack| stack.get(level).map_or_else(|| "   ".to_owned(), |c| format!("[{c}]")))
+            .collect();
+        input.push_str(&row.join(" "));
+        input.push('\n');
+    }
+    let labels: Vec<String> = (1..=STACK_COUNT).map(|number| format!(" {number} ")).collect();
+    input.push_str(&labels.join(" "));
+    input.push_str("\n\n");
+
+    // only move crates from stacks that have some, keeping track of the stack sizes
+    for _ in 0..size {
+        let non_empty: Vec<usize> = (0..STACK_COUNT).filter(|&index| !stacks[index].is_empty()).collect();
+        let from = *rng.choose(&non_empty);
+        let to = (from + rng.usize(1..STACK_COUNT)) % STACK_COUNT;
+        let count = rng.usize(1..stacks[from].len() + 1);
+
+        // only the sizes matter, the order of the crates doesn't change them
+        let remaining = stacks[from].len() - count;
+        let moved = stacks[from].split_off(remaining);
+        stacks[to].extend(moved);
+
+        input.push_str(&format!("move {count} from {} to {}\n", from + 1, to + 1));
+    }
+
+    input
+}
@@ -0,0 +1,33 @@
+pub mod supplies;
+pub mod crate_mover;
+pub mod generator;
+pub mod visualization;
+
+use aoc_common::{ParseError, Solution};
+use crate_mover::CrateMover;
+use supplies::{top_crates, Supplies};
+
+/// The goal of this challenge is to find the state of the stacks after the instructions have been executed.
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = Supplies;
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Supplies::parse_from_string(input)
+    }
+
+    /// The top crates when using the CrateMover 9000.
+    fn part_one(supplies: &Self::Input) -> Self::PartOne {
+        top_crates(&supplies.rearrange(CrateMover::CM9000))
+    }
+
+    /// The top crates when using the CrateMover 9001.
+    fn part_two(supplies: &Self::Input) -> Self::PartTwo {
+        top_crates(&supplies.rearrange(CrateMover::CM9001))
+    }
+}
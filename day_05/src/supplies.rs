@@ -0,0 +1,166 @@
+use regex::Regex;
+use aoc_common::{parse::{numbered_lines, parse_number}, ParseError};
+use crate::crate_mover::CrateMover;
+
+/// An instruction to move crates from one stack to another.
+/// 
+/// # Fields
+/// 
+/// * `quantity` - The number of crates to move.
+/// * `origin_stack_index` - The index of the stack the crates are taken from (starting at 0).
+/// * `destination_stack_index` - The index of the stack the crates are moved to (starting at 0).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub quantity: usize,
+    pub origin_stack_index: usize,
+    pub destination_stack_index: usize,
+}
+
+/// The initial state of the stacks, and the instructions to execute on them.
+/// 
+/// # Fields
+/// 
+/// * `stacks` - The crates of each stack, from bottom to top.
+/// * `moves` - The instructions to execute, in order.
+#[derive(Debug, Clone)]
+pub struct Supplies {
+    pub stacks: Vec<Vec<char>>,
+    pub moves: Vec<Move>,
+}
+
+impl Supplies {
+    /// Creates the supplies from the given input.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an error if the input isn't made of the stacks and the instructions separated by an empty line,
+    /// if an instruction is malformed, or if an instruction would take more crates than a stack contains.
+    pub fn parse_from_string(input: &str) -> Result<Self, ParseError> {
+        // split the input into two parts: the representation of the stacks and the instructions
+        let lines: Vec<(usize, &str)> = numbered_lines(input).collect();
+        let separator_index = lines
+            .iter()
+            .position(|(_, line)| line.is_empty())
+            .ok_or_else(|| ParseError::new("expected an empty line between the stacks and the instructions"))?;
+        let stacks_representation = &lines[..separator_index];
+        let instructions = &lines[separator_index + 1..];
+
+
+
+        // count the number of stacks present in the input
+        let stack_count = (stacks_representation.iter().map(|(_, row)| row.len()).max().unwrap_or(0) + 1) / 4;
+
+        // create a variable to store the state of the stacks
+        let mut stacks_initial_state: Vec<Vec<char>> = vec![vec![]; stack_count];
+
+        // parse the stacks representation and store the state of the stacks in the variable
+        for (_, row) in stacks_representation.iter().rev().skip(1) {
+            // convert the current row into a vector of chars
+            let chars: Vec<char> = row.chars().collect();
+
+            // for each stack, push a new crate if there is one
+            for stack_index in 0..stack_count {
+                let c = chars.get(4 * stack_index + 1).copied().unwrap_or(' ');
+                if c != ' ' {
+                    stacks_initial_state[stack_index].push(c);
+                }
+            }
+        }
+
+
+
+        // create a regex to parse the instructions
+        let instruction_regex = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
+
+        // keep track of the size of each stack, to make sure the instructions can be executed
+        let mut stack_sizes: Vec<usize> = stacks_initial_state.iter().map(Vec::len).collect();
+
+        // for each instruction in the input
+        let mut moves = vec![];
+        for &(line_number, instruction) in instructions {
+            // parse the instruction
+            let instruction_move = Self::parse_move(&instruction_regex, instruction, &mut stack_sizes)
+                .map_err(|error| error.at_line(line_number, instruction))?;
+            moves.push(instruction_move);
+        }
+
+        Ok(Supplies { stacks: stacks_initial_state, moves })
+    }
+
+    /// Parses an instruction such as `move 1 from 2 to 1`, and updates the size of the stacks accordingly.
+    fn parse_move(instruction_regex: &Regex, instruction: &str, stack_sizes: &mut [usize]) -> Result<Move, ParseError> {
+        let instruction_captures = instruction_regex
+            .captures(instruction)
+            .ok_or_else(|| ParseError::new("expected an instruction such as `move 1 from 2 to 1`"))?;
+        let quantity = &instruction_captures[1];
+        let origin = &instruction_captures[2];
+        let destination = &instruction_captures[3];
+
+        // parse the stack numbers, and make sure the stacks exist
+        let parse_stack_index = |stack: &str| -> Result<usize, ParseError> {
+            let stack_number: usize = parse_number(stack, "a stack number").map_err(|error| error.within(instruction, stack))?;
+            if stack_number == 0 || stack_number > stack_sizes.len() {
+                return Err(ParseError::new(format!("there is no stack {stack_number}, the stacks are numbered from 1 to {}", stack_sizes.len()))
+                    .within(instruction, stack));
+            }
+            Ok(stack_number - 1)
+        };
+        let origin_stack_index = parse_stack_index(origin)?;
+        let destination_stack_index = parse_stack_index(destination)?;
+        let quantity: usize = parse_number(quantity, "a number of crates").map_err(|error| error.within(instruction, quantity))?;
+
+        // make sure the origin stack contains enough crates
+        if quantity > stack_sizes[origin_stack_index] {
+            return Err(ParseError::new(format!(
+                "cannot move {quantity} crates from stack {origin}, which only contains {}",
+                stack_sizes[origin_stack_index]
+            ))
+            .within(instruction, instruction));
+        }
+        stack_sizes[origin_stack_index] -= quantity;
+        stack_sizes[destination_stack_index] += quantity;
+
+        Ok(Move { quantity, origin_stack_index, destination_stack_index })
+    }
+
+    /// Executes every instruction using the given crate mover.
+    /// 
+    /// # Returns
+    /// 
+    /// The state of the stacks once every instruction has been executed.
+    pub fn rearrange(&self, crate_mover: CrateMover) -> Vec<Vec<char>> {
+        let mut stacks = self.stacks.clone();
+
+        for (step, instruction) in self.moves.iter().enumerate() {
+            crate_mover.execute(&mut stacks, *instruction);
+
+            // the moved crates are now on top of the destination stack, in their new order
+            aoc_common::trace!(
+                5,
+                "move",
+                crane = crate_mover.model(),
+                step = step + 1,
+                quantity = instruction.quantity,
+                from = instruction.origin_stack_index + 1,
+                to = instruction.destination_stack_index + 1,
+                moved = stacks[instruction.destination_stack_index][stacks[instruction.destination_stack_index].len() - instruction.quantity..]
+                    .iter()
+                    .collect::<String>(),
+            );
+        }
+
+        stacks
+    }
+}
+
+/// Finds the top crate of each stack.
+pub fn top_crates(stacks: &[Vec<char>]) -> String {
+    // find the top crate of each stack, empty stacks don't have one
+    let mut top_crates = vec![];
+    for stack in stacks {
+        if let Some(top_crate) = stack.last() {
+            top_crates.push(*top_crate);
+        }
+    }
+    top_crates.into_iter().collect()
+}
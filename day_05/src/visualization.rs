@@ -0,0 +1,54 @@
+use std::iter;
+use aoc_visual::{Cell, Color, Frame, Frames, Visualize};
+use crate::{crate_mover::CrateMover, Day05};
+
+/// Animates the rearrangement of the stacks, one frame per move: first with the CrateMover 9000, then with the CrateMover 9001.
+impl Visualize for Day05 {
+    fn frames(supplies: Self::Input) -> Frames {
+        // the frames are big enough to hold every crate on a single stack, and the longest title
+        let crate_count: usize = supplies.stacks.iter().map(Vec::len).sum();
+        let longest_title = title(CrateMover::CM9001, supplies.moves.len(), supplies.moves.len());
+        let width = (supplies.stacks.len() * 4).saturating_sub(1).max(longest_title.len());
+        let height = crate_count + 2;
+
+        let frames = [CrateMover::CM9000, CrateMover::CM9001].into_iter().flat_map(move |crate_mover| {
+            let mut stacks = supplies.stacks.clone();
+            let move_count = supplies.moves.len();
+
+            // the initial stacks, followed by the stacks after each move
+            let initial_frame = draw(&stacks, &title(crate_mover, 0, move_count), width, height);
+            let moves = supplies.moves.clone().into_iter().enumerate();
+            iter::once(initial_frame).chain(moves.map(move |(index, instruction)| {
+                crate_mover.execute(&mut stacks, instruction);
+                draw(&stacks, &title(crate_mover, index + 1, move_count), width, height)
+            }))
+        });
+
+        Box::new(frames)
+    }
+}
+
+/// The title of a frame, telling which crane is used and how many moves were made.
+fn title(crate_mover: CrateMover, moves_made: usize, move_count: usize) -> String {
+    format!("CrateMover {}, move {moves_made}/{move_count}", crate_mover.model())
+}
+
+/// Draws the stacks like in the puzzle input, with a title above them and the numbers of the stacks below them.
+fn draw(stacks: &[Vec<char>], title: &str, width: usize, height: usize) -> Frame {
+    let mut frame = Frame::new(width, height);
+    frame.write_text((0, 0), title, Color::WHITE);
+
+    for (index, stack) in stacks.iter().enumerate() {
+        // each crate has its own color, so they can be followed from one stack to another
+        for (level, &label) in stack.iter().enumerate() {
+            let color = Color::of_label(label);
+            let y = height - 2 - level;
+            frame[(4 * index, y)] = Cell::new('[', color);
+            frame[(4 * index + 1, y)] = Cell::new(label, color);
+            frame[(4 * index + 2, y)] = Cell::new(']', color);
+        }
+        frame.write_text((4 * index + 1, height - 1), &(index + 1).to_string(), Color::GRAY);
+    }
+
+    frame
+}
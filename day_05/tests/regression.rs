@@ -0,0 +1,55 @@
+use aoc_common::Solution;
+use aoc_visual::parse_frames;
+use day_05::Day05;
+
+/// The example from the puzzle description.
+/// 
+/// The lines are written one by one, as the drawing of the stacks starts with spaces.
+const EXAMPLE: &str = concat!(
+    "    [D]    \n",
+    "[N] [C]    \n",
+    "[Z] [M] [P]\n",
+    " 1   2   3 \n",
+    "\n",
+    "move 1 from 2 to 1\n",
+    "move 3 from 1 to 3\n",
+    "move 2 from 2 to 1\n",
+    "move 1 from 1 to 2\n",
+);
+
+/// The puzzle input of the repository.
+const INPUT: &str = include_str!("../../inputs/day_05.txt");
+
+#[test]
+fn example() {
+    let supplies = Day05::parse(EXAMPLE).expect("The example should be valid");
+    assert_eq!(supplies.stacks, [vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]);
+    assert_eq!(supplies.moves.len(), 4);
+
+    assert_eq!(Day05::part_one(&supplies), "CMZ");
+    assert_eq!(Day05::part_two(&supplies), "MCD");
+}
+
+#[test]
+fn input() {
+    assert_eq!(Day05::solve(INPUT).unwrap(), ("QNNTGTPFN".to_owned(), "GGNPJBTTR".to_owned()));
+}
+
+#[test]
+fn visualization() {
+    // the initial stacks and the stacks after each move, with each crane
+    let frames: Vec<String> = parse_frames::<Day05>(EXAMPLE).unwrap().map(|frame| frame.to_string()).collect();
+    assert_eq!(frames.len(), 10);
+
+    let last_move: Vec<&str> = frames[4].lines().map(str::trim_end).collect();
+    assert_eq!(last_move, [
+        "CrateMover 9000, move 4/4",
+        "",
+        "",
+        "        [Z]",
+        "        [N]",
+        "        [D]",
+        "[C] [M] [P]",
+        " 1   2   3",
+    ]);
+}
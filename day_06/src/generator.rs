@@ -0,0 +1,23 @@
+use aoc_common::random::Rng;
+
+/// Generates a valid datastream of `size` characters (at least 14).
+/// 
+/// The datastream only uses a few letters at first, so no marker can be found,
+/// and the start-of-message marker is hidden somewhere in its second half (ignoring the length of the marker).
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(14);
+    let mut datastream: Vec<u8> = (0..size).map(|_| b"abc"[rng.usize(0..3)]).collect();
+
+    // hide 14 different characters in the second half
+    let mut marker = b"abcdefghijklmnopqrstuvwxyz".to_vec();
+    rng.shuffle(&mut marker);
+    let start = rng.usize((size - 14) / 2..size - 13);
+    datastream[start..start + 14].copy_from_slice(&marker[..14]);
+
+    // fill the rest of the stream with any letter
+    for character in &mut datastream[start + 14..] {
+        *character = b'a' + rng.below(26) as u8;
+    }
+
+    format!("{}\n", String::from_utf8_lossy(&datastream))
+}
@@ -0,0 +1,106 @@
+pub mod generator;
+
+use std::collections::HashMap;
+use aoc_common::{MaybeAnswer, ParseError, Solution};
+
+/// The goal of this problem is to find the index of the first character after a `start-of-packet` instruction.
+/// A `start-of-packet` instruction is a sequence of n different characters.
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = String;
+    type PartOne = MaybeAnswer<usize>;
+    type PartTwo = MaybeAnswer<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // the datastream must be able to fill the largest window
+        let length = input.trim_end().chars().count();
+        if length < 14 {
+            return Err(ParseError::new(format!("expected a datastream of at least 14 characters, found {length}")));
+        }
+
+        Ok(input.to_owned())
+    }
+
+    /// The start-of-packet instruction when using a window of size 4, if the datastream has one.
+    fn part_one(input: &Self::Input) -> Self::PartOne {
+        MaybeAnswer::from_option(find_first_start_of_packet(input.clone(), 4), "no marker in the datastream")
+    }
+
+    /// The start-of-packet instruction when using a window of size 14, if the datastream has one.
+    fn part_two(input: &Self::Input) -> Self::PartTwo {
+        MaybeAnswer::from_option(find_first_start_of_packet(input.clone(), 14), "no marker in the datastream")
+    }
+}
+
+/// Finds the index of the first character after a `start-of-packet` instruction.
+/// 
+/// Returns `None` if no window of the datastream only contains different characters.
+pub fn find_first_start_of_packet(input: String, window_size: usize) -> Option<usize> {
+    // convert the input to a vector of chars
+    let chars: Vec<char> = input.chars().collect();
+
+    // create a hashmap to store the characters in the window
+    let mut window_characters: HashMap<char, usize> = HashMap::new();
+
+    // the datastream can't fill a single window
+    if chars.len() < window_size {
+        return None;
+    }
+
+    // initialize the window by adding the first few characters
+    for character_index in 0..window_size-1 {
+        // get the character at the current index
+        let char = chars[character_index];
+
+        // if the character is already in the window, increment its count
+        if window_characters.contains_key(&char) {
+            *window_characters.get_mut(&char).unwrap() += 1;
+        }
+        // otherwise, add it to the window
+        else {
+            window_characters.insert(char, 1);
+        }
+    }
+
+    // slide the window over the input
+    for character_index in window_size-1..chars.len() {
+        // get the character at the current index
+        let char = chars[character_index];
+
+        // if the character is already in the window, increment its count
+        if window_characters.contains_key(&char) {
+            *window_characters.get_mut(&char).unwrap() += 1;
+        }
+        // otherwise, add it to the window
+        else {
+            window_characters.insert(char, 1);
+        }
+
+        // if the window contains only different characters,
+        // then the number of different characters is equal to the window size
+        if window_characters.len() == window_size {
+            // if that's the case, return the index of the character following the last character in the window
+            return Some(character_index + 1);
+        }
+        // if we didn't find a solution, remove the first character from the window
+        else {
+            // find the character to remove
+            let char_to_remove = chars[character_index + 1 - window_size];
+
+            // if the character is only present once in the window, remove it
+            if window_characters[&char_to_remove] == 1 {
+                window_characters.remove(&char_to_remove);
+            }
+            // otherwise, decrement its count
+            else {
+                *window_characters.get_mut(&char_to_remove).unwrap() -= 1;
+            }
+        }
+    }
+
+    // no window only contains different characters
+    None
+}
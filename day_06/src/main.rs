@@ -1,82 +1,43 @@
-use std::{fs, collections::HashMap};
+use aoc_common::{load_input_or_exit, memory::CountingAllocator, print_answers, print_timings, Arguments, Format, Solution};
+use day_06::Day06;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
 
 /// The goal of this problem is to find the index of the first character after a `start-of-packet` instruction.
 /// A `start-of-packet` instruction is a sequence of n different characters.
 fn main() {
-    // read the input file
-    let input = fs::read_to_string("inputs/day_06.txt").expect("Unable to read the input file");
-
-    // compute the answer to the first part
-    println!(
-        "The start-of-packet instruction is detected after {} characters when using a window of size 4.",
-        find_first_start_of_packet(input.clone(), 4)
-    );
-    // compute the answer to the second part
-    println!(
-        "The start-of-packet instruction is detected after {} characters when using a window of size 14.",
-        find_first_start_of_packet(input.clone(), 14)
-    );
-}
-
-/// Finds the index of the first character after a `start-of-packet` instruction.
-fn find_first_start_of_packet(input: String, window_size: usize) -> usize {
-    // convert the input to a vector of chars
-    let chars: Vec<char> = input.chars().collect();
+    // read the input file, its path can be given as an argument (`-` for the standard input)
+    let arguments = Arguments::from_env();
+    let input = load_input_or_exit(Day06::DAY, arguments.input.as_deref());
 
-    // create a hashmap to store the characters in the window
-    let mut window_characters: HashMap<char, usize> = HashMap::new();
-
-    // initialize the window by adding the first few characters
-    for character_index in 0..window_size-1 {
-        // get the character at the current index
-        let char = chars[character_index];
-
-        // if the character is already in the window, increment its count
-        if window_characters.contains_key(&char) {
-            *window_characters.get_mut(&char).unwrap() += 1;
-        }
-        // otherwise, add it to the window
-        else {
-            window_characters.insert(char, 1);
-        }
+    // the time taken and the heap used by each phase can be reported along with the answers
+    if arguments.timings {
+        return print_timings::<Day06>(&input, arguments.format);
     }
 
-    // slide the window over the input
-    for character_index in window_size-1..input.len() {
-        // get the character at the current index
-        let char = chars[character_index];
-
-        // if the character is already in the window, increment its count
-        if window_characters.contains_key(&char) {
-            *window_characters.get_mut(&char).unwrap() += 1;
-        }
-        // otherwise, add it to the window
-        else {
-            window_characters.insert(char, 1);
-        }
-
-        // if the window contains only different characters,
-        // then the number of different characters is equal to the window size
-        if window_characters.len() == window_size {
-            // if that's the case, return the index of the character following the last character in the window
-            return character_index + 1;
-        }
-        // if we didn't find a solution, remove the first character from the window
-        else {
-            // find the character to remove
-            let char_to_remove = chars[character_index + 1 - window_size];
+    // the answers can be displayed in a machine-readable format instead
+    if arguments.format != Format::Text {
+        return print_answers::<Day06>(&input, arguments.format, arguments.command);
+    }
+    let datastream = Day06::parse_input(&input).unwrap_or_else(|error| error.exit());
 
-            // if the character is only present once in the window, remove it
-            if window_characters[&char_to_remove] == 1 {
-                window_characters.remove(&char_to_remove);
+    // compute the answer to the first part
+    if arguments.command.runs(1) {
+        match Day06::part_one(&datastream).answer() {
+            Some(characters) => {
+                println!("The start-of-packet instruction is detected after {characters} characters when using a window of size 4.")
             }
-            // otherwise, decrement its count
-            else {
-                *window_characters.get_mut(&char_to_remove).unwrap() -= 1;
+            None => println!("No start-of-packet instruction is detected when using a window of size 4."),
+        }
+    }
+    // compute the answer to the second part
+    if arguments.command.runs(2) {
+        match Day06::part_two(&datastream).answer() {
+            Some(characters) => {
+                println!("The start-of-packet instruction is detected after {characters} characters when using a window of size 14.")
             }
+            None => println!("No start-of-packet instruction is detected when using a window of size 14."),
         }
     }
-
-    // if we didn't find a solution, panic (this should never happen)
-    panic!("No solution found");
 }
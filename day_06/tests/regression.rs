@@ -0,0 +1,39 @@
+use aoc_common::{MaybeAnswer, Solution};
+use day_06::Day06;
+
+/// The examples from the puzzle description, along with their expected answers.
+const EXAMPLES: [(&str, usize, usize); 5] = [
+    ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+    ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+    ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+    ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+    ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+];
+
+/// The puzzle input of the repository.
+const INPUT: &str = include_str!("../../inputs/day_06.txt");
+
+#[test]
+fn examples() {
+    for (datastream, part_one, part_two) in EXAMPLES {
+        let answers = (MaybeAnswer::Answer(part_one), MaybeAnswer::Answer(part_two));
+        assert_eq!(Day06::solve(datastream).unwrap(), answers, "datastream: {datastream}");
+    }
+}
+
+#[test]
+fn input() {
+    assert_eq!(Day06::solve(INPUT).unwrap(), (MaybeAnswer::Answer(1_723), MaybeAnswer::Answer(3_708)));
+}
+
+#[test]
+fn no_marker() {
+    // the datastream is long enough, but never has 4 different characters in a row
+    let no_marker = MaybeAnswer::NoAnswer("no marker in the datastream");
+    assert_eq!(Day06::solve("aaaaaaaaaaaaaaaaaaaa\n").unwrap(), (no_marker, no_marker));
+    assert_eq!(day_06::find_first_start_of_packet("aaaaaaaaaaaaaaaaaaaa".to_owned(), 4), None);
+
+    // a start-of-packet marker doesn't need a start-of-message marker
+    assert_eq!(Day06::solve("abcdaaaaaaaaaaaaaaaaaa\n").unwrap(), (MaybeAnswer::Answer(4), no_marker));
+    assert_eq!(Day06::solve("abcdabcdabcdabcdabcd\n").unwrap(), (MaybeAnswer::Answer(4), no_marker));
+}
@@ -37,22 +37,26 @@ impl FileSystem {
     }
 
     /// Tries to move in the current filesystem using the given path.
-    pub fn move_using_path(&mut self, path: Path) {
+    /// 
+    /// Returns an error if the path goes above the root directory, or through a directory that does not exist.
+    pub fn move_using_path(&mut self, path: Path) -> Result<(), String> {
         match path {
             Path::Relative(_) => {
-                self.move_using_relative_path(path);
+                self.move_using_relative_path(path)
             },
             Path::Absolute(_) => {
                 self.current_directory = self.root_directory.clone();
-                self.move_using_relative_path(path.to_relative());
+                self.move_using_relative_path(path.to_relative())
             },
         }
     }
     
     /// Tries to move in the current filesystem using the given relative path.
-    pub fn move_using_relative_path(&mut self, path: Path) {
+    /// 
+    /// Returns an error if the path goes above the root directory, or through a directory that does not exist.
+    pub fn move_using_relative_path(&mut self, path: Path) -> Result<(), String> {
         match path {
-            Path::Absolute(_) => panic!("Cannot move using absolute path."),
+            Path::Absolute(_) => Err("cannot move using an absolute path".to_owned()),
             Path::Relative(directories) => {
                 // for each directory in the path
                 for directory in directories {
@@ -69,7 +73,7 @@ impl FileSystem {
                         }
                         // else, we are already at the root directory, so we cannot move up
                         else {
-                            panic!("Cannot move up from root directory.");
+                            return Err("cannot move up from the root directory".to_owned());
                         }
                     }
                     // else, move to the sub directory
@@ -85,20 +89,24 @@ impl FileSystem {
                         }
                         // else, the directory does not exist, so we cannot move to it
                         else {
-                            panic!("Cannot move to directory {directory} because it does not exist.");
+                            return Err(format!("cannot move to directory `{directory}` because it does not exist"));
                         }
                     }
                 }
+
+                Ok(())
             }
         }
     }
 
     /// Executes the given instruction.
-    pub fn execute(&mut self, instruction: Instruction) {
+    /// 
+    /// Returns an error if the instruction tries to move to a directory that does not exist.
+    pub fn execute(&mut self, instruction: Instruction) -> Result<(), String> {
         match instruction {
             // if the instruction is to change directory, move to the given path
             Instruction::ChangeDirectory(path) => {
-                self.move_using_path(path);
+                return self.move_using_path(path);
             },
             // if the instruction is to list the directory, do nothing
             Instruction::ListDirectory => {
@@ -129,6 +137,13 @@ impl FileSystem {
                 );
             },
         }
+
+        Ok(())
+    }
+
+    /// Returns the root directory of the filesystem.
+    pub fn root_directory(&self) -> Rc<RefCell<Directory>> {
+        self.root_directory.clone()
     }
 
     /// Computes the size of the filesystem.
@@ -0,0 +1,118 @@
+use std::collections::HashSet;
+use aoc_common::random::Rng;
+
+/// A line of the generated terminal output, before the sizes of the files are known.
+enum Line {
+    ChangeDirectory(String),
+    ListDirectory,
+    Directory(String),
+    /// A file, with the weight used to compute its size.
+    File(u64, String),
+}
+
+/// Generates a valid input, the terminal output of the exploration of a filesystem containing `size` directories (besides the root).
+///
+/// The filesystem uses between 45,000,000 and 65,000,000 bytes (unless it has more files than that), so an update can always be installed by deleting a directory.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let mut lines = vec![Line::ChangeDirectory("/".to_owned())];
+
+    // the directories that haven't been explored yet, for each directory currently open
+    let mut open_directories: Vec<Vec<String>> = vec![];
+    let mut remaining_directories = size;
+    let mut pending_directories = 0;
+
+    // explore the root directory first
+    let mut entering = true;
+    loop {
+        if entering {
+            // list the content of the directory
+            lines.push(Line::ListDirectory);
+            let mut names = HashSet::new();
+
+            // the exploration must go on until every directory has been created
+            let minimum = usize::from(pending_directories == 0 && remaining_directories > 0);
+            let directories_count = rng.usize(minimum..4).min(remaining_directories);
+            remaining_directories -= directories_count;
+            pending_directories += directories_count;
+
+            let mut sub_directories = vec![];
+            for _ in 0..directories_count {
+                let name = unique_name(rng, &mut names, false);
+                lines.push(Line::Directory(name.clone()));
+                sub_directories.push(name);
+            }
+            for _ in 0..rng.usize(0..5) {
+                let name = unique_name(rng, &mut names, true);
+                lines.push(Line::File(rng.usize(1..1_000) as u64, name));
+            }
+
+            open_directories.push(sub_directories);
+        }
+
+        // enter the next sub-directory of the current directory, or go back up when they were all explored
+        let Some(current_directory) = open_directories.last_mut() else { break };
+        match current_directory.pop() {
+            Some(name) => {
+                pending_directories -= 1;
+                lines.push(Line::ChangeDirectory(name));
+                entering = true;
+            }
+            None => {
+                open_directories.pop();
+                if !open_directories.is_empty() {
+                    lines.push(Line::ChangeDirectory("..".to_owned()));
+                }
+                entering = false;
+            }
+        }
+    }
+
+    // scale the sizes of the files so the whole filesystem has the chosen size
+    let filesystem_size = rng.usize(45_000_000..65_000_001) as u128;
+    let weights: Vec<u128> = lines
+        .iter()
+        .filter_map(|line| match line {
+            Line::File(weight, _) => Some(*weight as u128),
+            _ => None,
+        })
+        .collect();
+    let total_weight = weights.iter().sum::<u128>().max(1);
+    let scaled_size = |weight: u128| (weight * filesystem_size / total_weight).max(1);
+
+    // the sizes are rounded down, the first file makes up for the difference
+    let mut remainder = filesystem_size.saturating_sub(weights.iter().map(|&weight| scaled_size(weight)).sum());
+
+    // write the terminal output
+    let mut input = String::new();
+    for line in lines {
+        match line {
+            Line::ChangeDirectory(path) => input.push_str(&format!("$ cd {path}\n")),
+            Line::ListDirectory => input.push_str("$ ls\n"),
+            Line::Directory(name) => input.push_str(&format!("dir {name}\n")),
+            Line::File(weight, name) => {
+                let file_size = scaled_size(weight as u128) + std::mem::take(&mut remainder);
+                input.push_str(&format!("{file_size} {name}\n"));
+            }
+        }
+    }
+
+    // a filesystem without any file is given a single one, so it still has the chosen size
+    if weights.is_empty() {
+        input.push_str(&format!("$ cd /\n$ ls\n{filesystem_size} a.dat\n"));
+    }
+
+    input
+}
+
+/// Generates a name that isn't used yet in the directory, files get an extension most of the time.
+fn unique_name(rng: &mut Rng, names: &mut HashSet<String>, is_file: bool) -> String {
+    loop {
+        let mut name = rng.name(1..9);
+        if is_file && rng.chance(0.75) {
+            name = format!("{name}.{}", rng.name(1..4));
+        }
+        if names.insert(name.clone()) {
+            return name;
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use super::path::Path;
 use regex::Regex;
+use aoc_common::{parse::{numbered_lines, parse_number}, ParseError};
 
 /// Representation of an instruction.
 /// An instruction is a command that can be executed on a filesystem.
@@ -26,7 +27,9 @@ pub enum Instruction {
 }
 
 /// Parses the given input into a list of instructions.
-pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>, String> {
+/// 
+/// Each line of the input is converted into exactly one instruction.
+pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
     // create the regexes to parse the instructions
     let cd_command_regex = Regex::new(r"\$ cd (.*)").unwrap();
     let ls_command_regex = Regex::new(r"\$ ls").unwrap();
@@ -37,12 +40,12 @@ pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>, String> {
     let mut instructions = Vec::new();
 
     // for each line in the input
-    for line in input.lines() {
+    for (line_number, line) in numbered_lines(input) {
         // if the line is a cd command
         if cd_command_regex.is_match(line) {
             // parse the path from the line
             let captures = cd_command_regex.captures(line).unwrap();
-            let path = Path::try_from(&captures[1]).expect("Invalid path.");
+            let path = Path::try_from(&captures[1]).map_err(|error| error.within(line, &captures[1]).at_line(line_number, line))?;
 
             // add the instruction to the list
             instructions.push(Instruction::ChangeDirectory(path));
@@ -58,7 +61,8 @@ pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>, String> {
         else if ls_file_regex.is_match(line) {
             // parse the file size and name from the line
             let captures = ls_file_regex.captures(line).unwrap();
-            let file_size: usize = captures[1].parse().unwrap();
+            let file_size: usize = parse_number(&captures[1], "a file size")
+                .map_err(|error| error.within(line, &captures[1]).at_line(line_number, line))?;
             let file_name: String = captures[2].to_owned();
             
             // add the instruction to the list
@@ -78,7 +82,10 @@ pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>, String> {
         // if the line is not a valid command
         else {
             // return an error
-            return Err("Invalid instruction.".to_owned());
+            return Err(
+                ParseError::new("expected a command (`$ cd`, `$ ls`) or the content of a directory (`dir a`, `14848 b.txt`)")
+                    .at_line(line_number, line)
+            );
         }
     }
 
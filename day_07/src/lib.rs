@@ -0,0 +1,102 @@
+pub mod path;
+pub mod file;
+pub mod directory;
+pub mod filesystem;
+pub mod instruction;
+pub mod generator;
+
+use aoc_common::{parse::numbered_lines, Arguments, MaybeAnswer, Parameters, ParseError, Solution};
+use filesystem::FileSystem;
+use instruction::parse_instructions;
+
+/// The goal of this challenge is basically to create a filesystem.
+pub struct Day07;
+
+/// The space of the disk, which tells how much space must be freed for the update.
+/// 
+/// # Fields
+/// 
+/// * `disk_size` - The total size of the disk, 70,000,000 in the puzzle.
+/// * `space_needed` - The unused space needed by the update, 30,000,000 in the puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiskSpace {
+    pub disk_size: usize,
+    pub space_needed: usize,
+}
+
+impl Default for DiskSpace {
+    fn default() -> Self {
+        Self { disk_size: 70_000_000, space_needed: 30_000_000 }
+    }
+}
+
+impl Parameters for DiskSpace {
+    const OPTIONS: &'static [(&'static str, &'static str)] = &[
+        ("disk-size", "the total size of the disk (70,000,000 by default)"),
+        ("space-needed", "the unused space needed by the update (30,000,000 by default)"),
+    ];
+
+    fn from_arguments(arguments: &Arguments) -> Result<Self, String> {
+        let default = Self::default();
+        Ok(Self {
+            disk_size: arguments.parameter("disk-size", default.disk_size)?,
+            space_needed: arguments.parameter("space-needed", default.space_needed)?,
+        })
+    }
+}
+
+impl Day07 {
+    /// The size of the smallest directory we can clear to free enough space for the update, on the given disk.
+    /// 
+    /// Returns `None` if even clearing the whole filesystem wouldn't free enough space.
+    pub fn part_two_with(filesystem: &FileSystem, disk_space: &DiskSpace) -> Option<usize> {
+        // find how much space is missing, nothing needs to be cleared if the disk already has enough unused space
+        let used_space = filesystem.size();
+        let unused_space = disk_space.disk_size.saturating_sub(used_space);
+        let space_to_clear = disk_space.space_needed.saturating_sub(unused_space);
+
+        filesystem.big_enough_for_delete(space_to_clear)
+    }
+
+    /// The answer of the second part on the given disk, or the reason why there is none.
+    pub fn part_two_answer(filesystem: &FileSystem, disk_space: &DiskSpace) -> MaybeAnswer<usize> {
+        MaybeAnswer::from_option(Self::part_two_with(filesystem, disk_space), "no directory is big enough")
+    }
+}
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = FileSystem;
+    type PartOne = usize;
+    type PartTwo = MaybeAnswer<usize>;
+
+    /// Builds the filesystem described by the terminal output given as input.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // parse the input into a vector of instructions
+        let instructions = parse_instructions(input)?;
+
+        // create a new filesystem
+        let mut filesystem = FileSystem::new();
+
+        // execute each instruction, each instruction comes from a line of the input
+        for (instruction, (line_number, line)) in instructions.into_iter().zip(numbered_lines(input)) {
+            filesystem
+                .execute(instruction)
+                .map_err(|message| ParseError::new(message).at_line(line_number, line))?;
+        }
+
+        Ok(filesystem)
+    }
+
+    /// The small-size of the filesystem.
+    fn part_one(filesystem: &Self::Input) -> Self::PartOne {
+        filesystem.small_size()
+    }
+
+    /// The size of the smallest directory we can clear to free enough space for the update.
+    fn part_two(filesystem: &Self::Input) -> Self::PartTwo {
+        // the disk of the puzzle has a size of 70MB, and the update needs 30MB
+        Self::part_two_answer(filesystem, &DiskSpace::default())
+    }
+}
@@ -1,48 +1,58 @@
-pub mod path;
-pub mod file;
-pub mod directory;
-pub mod filesystem;
-pub mod instruction;
-
-use std::fs;
-use filesystem::FileSystem;
-use instruction::parse_instructions;
+use std::process;
+use aoc_common::{load_input_or_exit, memory::CountingAllocator, print_answers_with, print_timings_with, Arguments, Format, Solution};
+use day_07::{filesystem::FileSystem, Day07, DiskSpace};
 use thousands::Separable;
 
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
 /// The goal of this challenge is basically to create a filesystem.
 fn main() {
-    // read the input file
-    let input = fs::read_to_string("inputs/day_07.txt").expect("Unable to read the input file");
+    // read the input file, its path can be given as an argument (`-` for the standard input)
+    // the parameters baked into the puzzle can be changed with options, such as `--disk-size 100000000`
+    let (arguments, disk_space) = Arguments::from_env_with::<DiskSpace>();
+    let input = load_input_or_exit(Day07::DAY, arguments.input.as_deref());
+
+    // compute the parts with the given parameters
+    let part_one = Day07::part_one;
+    let part_two = |filesystem: &FileSystem| Day07::part_two_answer(filesystem, &disk_space);
 
-    // parse the input into a vector of instructions
-    let instructions = parse_instructions(&input).unwrap();
 
-    // create a new filesystem
-    let mut filesystem = FileSystem::new();
+    // the time taken and the heap used by each phase can be reported along with the answers
+    if arguments.timings {
+        return print_timings_with::<Day07>(&input, arguments.format, part_one, part_two);
+    }
 
-    // execute each instruction
-    for instruction in instructions {
-        filesystem.execute(instruction);
+    // the answers can be displayed in a machine-readable format instead
+    if arguments.format != Format::Text {
+        return print_answers_with::<Day07>(&input, arguments.format, arguments.command, part_one, part_two);
     }
 
+    // execute the instructions to build the filesystem
+    let filesystem = Day07::parse_input(&input).unwrap_or_else(|error| error.exit());
+
     // print a representation of the filesystem
     println!("{filesystem}");
 
     // print the small-size of the filesystem
-    println!(
-        "Small-size of the filesystem: {small_size} bytes",
-        small_size = filesystem.small_size().separate_with_commas()
-    );
-
-    // print the size of the smallest directory that can free 30MB of space
-    let disk_size: usize = 70_000_000;
-    let used_space = filesystem.size();
-    let unused_space = disk_size - used_space;
-    let space_needed_for_update: usize = 30_000_000;
-    let space_to_clear = space_needed_for_update - unused_space;
-
-    println!(
-        "The size of the smallest directory we can clear to free a total of 3MB is: {big_enough} bytes",
-        big_enough = filesystem.big_enough_for_delete(space_to_clear).unwrap().separate_with_commas()
-    );
+    if arguments.command.runs(1) {
+        println!(
+            "Small-size of the filesystem: {small_size} bytes",
+            small_size = part_one(&filesystem).separate_with_commas()
+        );
+    }
+
+    // print the size of the smallest directory that can free enough space for the update
+    if arguments.command.runs(2) {
+        let space_needed = disk_space.space_needed.separate_with_commas();
+        let Some(big_enough) = Day07::part_two_with(&filesystem, &disk_space) else {
+            // the disk is too small for the update, whatever is cleared
+            eprintln!("error: no directory is big enough, even clearing the whole filesystem doesn't free a total of {space_needed} bytes");
+            process::exit(1);
+        };
+        println!(
+            "The size of the smallest directory we can clear to free a total of {space_needed} bytes is: {big_enough} bytes",
+            big_enough = big_enough.separate_with_commas()
+        );
+    }
 }
@@ -1,4 +1,5 @@
 use std::{convert::TryFrom, fmt::Display};
+use aoc_common::ParseError;
 
 /// Representation of a path.
 /// A path can be either absolute or relative. Absolute paths start with a `/` and are relative to the root directory.
@@ -20,7 +21,7 @@ impl Path {
 }
 
 impl TryFrom<&str> for Path {
-    type Error = String;
+    type Error = ParseError;
 
     fn try_from(path: &str) -> Result<Self, Self::Error> {
         let mut path_parts: Vec<&str> = path.split("/").collect();
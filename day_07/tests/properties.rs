@@ -0,0 +1,40 @@
+use aoc_common::{random::check_property, Solution};
+use day_07::{generator::generate_input, Day07};
+
+#[test]
+fn directory_sizes_are_the_sum_of_file_sizes() {
+    check_property(50, |rng| {
+        let size = rng.usize(0..40);
+        let input = generate_input(rng, size);
+        let filesystem = Day07::parse(&input).unwrap();
+
+        // the whole filesystem holds every file of the terminal output
+        let files_size: usize = input
+            .lines()
+            .filter_map(|line| line.split_once(' ')?.0.parse::<usize>().ok())
+            .sum();
+        assert_eq!(filesystem.size(), files_size);
+
+        // each directory holds the files of its whole subtree
+        let mut directories = vec![filesystem.root_directory()];
+        while let Some(directory) = directories.pop() {
+            let directory = directory.borrow();
+
+            // add up the files of the subtree, without going through `Directory::size`
+            let mut subtree_size = 0;
+            let mut subtree = vec![directory.clone()];
+            while let Some(sub_directory) = subtree.pop() {
+                subtree_size += sub_directory.files.values().map(|file| file.size).sum::<usize>();
+                subtree.extend(sub_directory.sub_directories.values().map(|d| d.borrow().clone()));
+            }
+            assert_eq!(directory.size(), subtree_size, "wrong size for the directory `{}`", directory.name);
+
+            directories.extend(directory.sub_directories.values().cloned());
+        }
+
+        // the generated filesystem always leaves a directory to delete for the update
+        let (_, deleted_size) = Day07::solve(&input).unwrap();
+        let deleted_size = deleted_size.answer().expect("A directory is big enough");
+        assert!(filesystem.size() - deleted_size <= 40_000_000);
+    });
+}
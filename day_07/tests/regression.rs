@@ -0,0 +1,69 @@
+use aoc_common::{MaybeAnswer, Solution};
+use day_07::{Day07, DiskSpace};
+
+/// The example from the puzzle description.
+const EXAMPLE: &str = "\
+$ cd /
+$ ls
+dir a
+14848514 b.txt
+8504156 c.dat
+dir d
+$ cd a
+$ ls
+dir e
+29116 f
+2557 g
+62596 h.lst
+$ cd e
+$ ls
+584 i
+$ cd ..
+$ cd ..
+$ cd d
+$ ls
+4060174 j
+8033020 d.log
+5626152 d.ext
+7214296 k
+";
+
+/// The puzzle input of the repository.
+const INPUT: &str = include_str!("../../inputs/day_07.txt");
+
+#[test]
+fn example() {
+    let filesystem = Day07::parse(EXAMPLE).expect("The example should be valid");
+    assert_eq!(filesystem.size(), 48_381_165);
+
+    assert_eq!(Day07::part_one(&filesystem), 95_437);
+    assert_eq!(Day07::part_two(&filesystem), MaybeAnswer::Answer(24_933_642));
+}
+
+#[test]
+fn input() {
+    assert_eq!(Day07::solve(INPUT).unwrap(), (1_423_358, MaybeAnswer::Answer(545_729)));
+}
+
+#[test]
+fn example_disk_space() {
+    let filesystem = Day07::parse(EXAMPLE).expect("The example should be valid");
+
+    // the puzzle's disk gives the usual answer
+    assert_eq!(Day07::part_two_with(&filesystem, &DiskSpace::default()), Day07::part_two(&filesystem).answer());
+
+    // on a smaller disk, only the whole filesystem is big enough
+    let small_disk = DiskSpace { disk_size: 50_000_000, space_needed: 30_000_000 };
+    assert_eq!(Day07::part_two_with(&filesystem, &small_disk), Some(48_381_165));
+
+    // nothing needs to be cleared if the disk already has enough unused space
+    let big_disk = DiskSpace { disk_size: 100_000_000, space_needed: 30_000_000 };
+    assert_eq!(Day07::part_two_with(&filesystem, &big_disk), Some(584));
+
+    // the update may not fit at all, which is reported instead of an answer
+    let tiny_disk = DiskSpace { disk_size: 50_000_000, space_needed: 999_999_999_999 };
+    assert_eq!(Day07::part_two_with(&filesystem, &tiny_disk), None);
+    let answer = Day07::part_two_answer(&filesystem, &tiny_disk);
+    assert_eq!(answer, MaybeAnswer::NoAnswer("no directory is big enough"));
+    assert_eq!(answer.to_string(), "no directory is big enough");
+}
@@ -0,0 +1,155 @@
+use aoc_common::ParseError;
+use aoc_grid::Grid;
+use crate::tree::{TreeVisibility, Tree};
+
+/// A forest is a grid of trees.
+pub type Forest = Grid<Tree>;
+
+/// Parses the input into a forest of trees.
+/// 
+/// # Errors
+/// 
+/// Returns an error if the forest is empty, if a tree height isn't a digit, or if the rows don't all have the same length.
+pub fn parse_input(input: &str) -> Result<Forest, ParseError> {
+    Grid::parse(input, |_, char| {
+        // the height of a tree is a single digit
+        char
+            .to_digit(10)
+            .map(|tree_height| Tree::new(tree_height as usize))
+            .ok_or_else(|| ParseError::new(format!("expected the height of a tree (a digit), found `{char}`")))
+    })
+}
+
+/// Updates the visibility of the trees in the forest.
+pub fn update_visibility(forest: &mut Forest) {
+    // find the forest dimensions
+    let forest_height = forest.height();
+    let forest_width = forest.width();
+
+    // set the visibility of the trees on the edges of the forest to visible
+    for row in 0..forest_height {
+        // east trees
+        forest[(0, row)].visibility = TreeVisibility::Visible;
+        // west trees
+        forest[(forest_width - 1, row)].visibility = TreeVisibility::Visible;
+    }
+    for col in 0..forest_width {
+        // north trees
+        forest[(col, 0)].visibility = TreeVisibility::Visible;
+        // south trees
+        forest[(col, forest_height - 1)].visibility = TreeVisibility::Visible;
+    }
+
+    // for each row in the forest
+    for row in 1..forest_height - 1 {
+        // looking west
+        let mut heighest_tree = forest[(0, row)];
+        let mut current_view_distance = [1; 10];
+
+        for col in 1..forest_width {
+            // find the currently selected tree
+            let tree = &mut forest[(col, row)];
+
+            // if the tree is higher than the heighest tree
+            // then it is visible
+            if *tree > heighest_tree {
+                tree.visibility = TreeVisibility::Visible;
+                heighest_tree = *tree;
+            }
+
+            // update the view distance of the tree
+            tree.west_view_distance = current_view_distance[tree.height];
+
+            // all the trees below or equal to the current tree won't be able to see further
+            for height in 0..=tree.height {
+                current_view_distance[height] = 1;
+            }
+            for height in tree.height + 1..10 {
+                current_view_distance[height] += 1;
+            }
+        }
+
+        // looking east
+        let mut heighest_tree = forest[(forest_width - 1, row)];
+        let mut current_view_distance = [1; 10];
+
+        for col in (0..forest_width - 1).rev() {
+            // find the currently selected tree
+            let tree = &mut forest[(col, row)];
+
+            // if the tree is higher than the heighest tree
+            // then it is visible
+            if *tree > heighest_tree {
+                tree.visibility = TreeVisibility::Visible;
+                heighest_tree = *tree;
+            }
+
+            // update the view distance of the tree
+            tree.east_view_distance = current_view_distance[tree.height];
+
+            // all the trees below or equal to the current tree won't be able to see further
+            for height in 0..=tree.height {
+                current_view_distance[height] = 1;
+            }
+            for height in tree.height + 1..10 {
+                current_view_distance[height] += 1;
+            }
+        }
+    }
+
+    for col in 1..forest_width - 1 {
+        // looking north
+        let mut heighest_tree = forest[(col, 0)];
+        let mut current_view_distance = [1; 10];
+
+        for row in 1..forest_height {
+            // find the currently selected tree
+            let tree = &mut forest[(col, row)];
+
+            // if the tree is higher than the heighest tree
+            // then it is visible
+            if *tree > heighest_tree {
+                tree.visibility = TreeVisibility::Visible;
+                heighest_tree = *tree;
+            }
+
+            // update the view distance of the tree
+            tree.north_view_distance = current_view_distance[tree.height];
+
+            // all the trees below or equal to the current tree won't be able to see further
+            for height in 0..=tree.height {
+                current_view_distance[height] = 1;
+            }
+            for height in tree.height + 1..10 {
+                current_view_distance[height] += 1;
+            }
+        }
+
+        // looking south
+        let mut heighest_tree = forest[(col, forest_height - 1)];
+        let mut current_view_distance = [1; 10];
+
+        for row in (0..forest_height - 1).rev() {
+            // find the currently selected tree
+            let tree = &mut forest[(col, row)];
+
+            // if the tree is higher than the heighest tree
+            // then it is visible
+            if *tree > heighest_tree {
+                tree.visibility = TreeVisibility::Visible;
+                heighest_tree = *tree;
+            }
+
+            // update the view distance of the tree
+            tree.south_view_distance = current_view_distance[tree.height];
+
+            // all the trees below or equal to the current tree won't be able to see further
+            for height in 0..=tree.height {
+                current_view_distance[height] = 1;
+            }
+            for height in tree.height + 1..10 {
+                current_view_distance[height] += 1;
+            }
+        }
+    }
+}
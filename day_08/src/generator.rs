@@ -0,0 +1,16 @@
+use aoc_common::random::Rng;
+
+/// Generates a valid forest of `size` by `size` trees (at least 1), with heights from 0 to 9.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut input = String::with_capacity(size * (size + 1));
+
+    for _ in 0..size {
+        for _ in 0..size {
+            input.push((b'0' + rng.below(10) as u8) as char);
+        }
+        input.push('\n');
+    }
+
+    input
+}
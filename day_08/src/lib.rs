@@ -0,0 +1,46 @@
+pub mod tree;
+pub mod forest;
+pub mod generator;
+
+use aoc_common::{ParseError, Solution};
+use tree::TreeVisibility;
+use forest::{parse_input, update_visibility, Forest};
+
+/// The goal of this challenge is to find the number of visible trees and the highest scenic score in a given forest.
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Forest;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    /// Parses the forest, and computes the visibility and the view distances of every tree.
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // parse the input into a forest of trees
+        let mut forest = parse_input(input)?;
+
+        // update the visibility of the trees
+        update_visibility(&mut forest);
+
+        Ok(forest)
+    }
+
+    /// The number of visible trees.
+    fn part_one(forest: &Self::Input) -> Self::PartOne {
+        forest
+            .iter()
+            .filter(|tree| tree.visibility == TreeVisibility::Visible)
+            .count()
+    }
+
+    /// The highest scenic score possible.
+    fn part_two(forest: &Self::Input) -> Self::PartTwo {
+        forest
+            .iter()
+            .map(|tree| tree.scenic_score())
+            .max()
+            .unwrap_or(0)
+    }
+}
@@ -0,0 +1,24 @@
+use aoc_common::Solution;
+use day_08::Day08;
+
+/// The example from the puzzle description.
+const EXAMPLE: &str = "\
+30373
+25512
+65332
+33549
+35390
+";
+
+/// The puzzle input of the repository.
+const INPUT: &str = include_str!("../../inputs/day_08.txt");
+
+#[test]
+fn example() {
+    assert_eq!(Day08::solve(EXAMPLE).unwrap(), (21, 8));
+}
+
+#[test]
+fn input() {
+    assert_eq!(Day08::solve(INPUT).unwrap(), (1_789, 314_820));
+}
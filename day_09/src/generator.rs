@@ -0,0 +1,14 @@
+use aoc_common::random::Rng;
+
+/// Generates a valid input made of `size` moves of the head of the rope, each one of 1 to 20 steps.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let direction = rng.choose(&["U", "D", "L", "R"]);
+        let distance = rng.usize(1..21);
+        input.push_str(&format!("{direction} {distance}\n"));
+    }
+
+    input
+}
@@ -0,0 +1,94 @@
+use std::io::BufRead;
+use regex::Regex;
+use aoc_common::{parse::{numbered_lines, numbered_reader_lines, parse_number}, ParseError};
+
+/// An instruction to move the rope.
+/// The number inside each enum represents the distance to move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Up(usize),
+    Down(usize),
+    Left(usize),
+    Right(usize),
+}
+
+/// Parses the instructions from a string.
+/// 
+/// # Arguments
+/// 
+/// * `input` - The string containing the instructions.
+/// 
+/// # Returns
+/// 
+/// A vector of instructions, or an error pointing at the first line that isn't a valid instruction.
+pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    // create a vector of instructions
+    let mut instructions: Vec<Instruction> = vec![];
+
+    // create a regex to parse the instructions
+    let instruction_regex = instruction_regex();
+    
+    // for each line in the input
+    for (line_number, instruction_str) in numbered_lines(input) {
+        // parse the instruction using the regex
+        let instruction = parse_instruction(&instruction_regex, instruction_str)
+            .map_err(|error| error.at_line(line_number, instruction_str))?;
+
+        // add the instruction to the vector
+        instructions.push(instruction);
+    }
+
+    // return the vector of instructions
+    Ok(instructions)
+}
+
+/// Reads the instructions one line at a time, and parses each of them as soon as it is read.
+/// 
+/// # Returns
+/// 
+/// An iterator over the instructions, yielding an error for each line that isn't a valid instruction.
+pub fn read_instructions(reader: impl BufRead) -> impl Iterator<Item = Result<Instruction, ParseError>> {
+    let instruction_regex = instruction_regex();
+
+    numbered_reader_lines(reader).map(move |line| {
+        let (line_number, instruction_str) = line?;
+        parse_instruction(&instruction_regex, &instruction_str).map_err(|error| error.at_line(line_number, &instruction_str))
+    })
+}
+
+/// Creates the regex splitting an instruction into a direction and a distance.
+fn instruction_regex() -> Regex {
+    Regex::new(r"^(\S+) (\S+)$").expect("Unable to compile the regex.")
+}
+
+/// Parses a single instruction, such as `R 4`.
+fn parse_instruction(instruction_regex: &Regex, instruction_str: &str) -> Result<Instruction, ParseError> {
+    // split the instruction into a direction and a distance
+    let captures = instruction_regex
+        .captures(instruction_str)
+        .ok_or_else(|| ParseError::new("expected a direction and a distance, such as `R 4`"))?;
+
+    // get the direction and the distance
+    let direction: &str = captures
+        .get(1)
+        .expect("Unable to get the direction.")
+        .as_str();
+    let distance_str: &str = captures
+        .get(2)
+        .expect("Unable to get the distance.")
+        .as_str();
+    let distance: usize = parse_number(distance_str, "a distance")
+        .map_err(|error| error.within(instruction_str, distance_str))?;
+
+    // create the instruction
+    match direction {
+        "U" => Ok(Instruction::Up(distance)),
+        "D" => Ok(Instruction::Down(distance)),
+        "L" => Ok(Instruction::Left(distance)),
+        "R" => Ok(Instruction::Right(distance)),
+        _ => Err(
+            ParseError::new(format!("expected a direction (`U`, `D`, `L` or `R`), found `{direction}`"))
+                .within(instruction_str, direction)
+        ),
+    }
+}
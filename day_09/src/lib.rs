@@ -0,0 +1,122 @@
+pub mod instruction;
+pub mod rope;
+pub mod generator;
+pub mod visualization;
+
+use std::io::BufRead;
+use aoc_common::{Arguments, Parameters, ParseError, Solution, Streaming};
+use instruction::{parse_instructions, read_instructions, Instruction};
+use rope::Rope;
+
+/// Moves a rope of the given size using the instructions, and counts the locations visited by its tail.
+fn count_visited_locations(instructions: &[Instruction], rope_size: usize) -> usize {
+    // create the rope
+    let mut rope = Rope::new(rope_size);
+
+    // move the rope
+    for instruction in instructions {
+        rope.move_rope(*instruction);
+    }
+
+    rope.visited_locations_count()
+}
+
+/// The goal of this challenge is to find the number of locations the tail of the rope has visited.
+pub struct Day09;
+
+/// The number of knots of the rope of each part.
+/// 
+/// # Fields
+/// 
+/// * `part_one` - The length of the rope of the first part, 2 in the puzzle.
+/// * `part_two` - The length of the rope of the second part, 10 in the puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RopeLengths {
+    pub part_one: usize,
+    pub part_two: usize,
+}
+
+impl Default for RopeLengths {
+    fn default() -> Self {
+        Self { part_one: 2, part_two: 10 }
+    }
+}
+
+impl Parameters for RopeLengths {
+    const OPTIONS: &'static [(&'static str, &'static str)] = &[
+        ("part1-length", "the number of knots of the rope of the first part (2 by default)"),
+        ("part2-length", "the number of knots of the rope of the second part (10 by default)"),
+    ];
+
+    fn from_arguments(arguments: &Arguments) -> Result<Self, String> {
+        let default = Self::default();
+        let lengths = Self {
+            part_one: arguments.parameter("part1-length", default.part_one)?,
+            part_two: arguments.parameter("part2-length", default.part_two)?,
+        };
+
+        // the head of the rope is always there
+        if lengths.part_one == 0 || lengths.part_two == 0 {
+            return Err("A rope has at least one knot.".to_owned());
+        }
+        Ok(lengths)
+    }
+}
+
+impl Day09 {
+    /// The number of locations visited by the tail of the rope of the first part.
+    pub fn part_one_with(instructions: &[Instruction], lengths: &RopeLengths) -> usize {
+        count_visited_locations(instructions, lengths.part_one)
+    }
+
+    /// The number of locations visited by the tail of the rope of the second part.
+    pub fn part_two_with(instructions: &[Instruction], lengths: &RopeLengths) -> usize {
+        count_visited_locations(instructions, lengths.part_two)
+    }
+
+    /// Moves the ropes of both parts while the instructions are read, and counts the locations visited by their tails.
+    /// 
+    /// Only the ropes and the locations they visited are kept in memory, not the instructions.
+    pub fn stream_with(reader: impl BufRead, lengths: &RopeLengths) -> Result<(usize, usize), ParseError> {
+        // create the ropes
+        let mut rope_1 = Rope::new(lengths.part_one);
+        let mut rope_2 = Rope::new(lengths.part_two);
+
+        // move the ropes
+        for instruction in read_instructions(reader) {
+            let instruction = instruction?;
+            rope_1.move_rope(instruction);
+            rope_2.move_rope(instruction);
+        }
+
+        Ok((rope_1.visited_locations_count(), rope_2.visited_locations_count()))
+    }
+}
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Instruction>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_instructions(input)
+    }
+
+    /// The number of locations visited by the tail of a rope of length 2.
+    fn part_one(instructions: &Self::Input) -> Self::PartOne {
+        Self::part_one_with(instructions, &RopeLengths::default())
+    }
+
+    /// The number of locations visited by the tail of a rope of length 10.
+    fn part_two(instructions: &Self::Input) -> Self::PartTwo {
+        Self::part_two_with(instructions, &RopeLengths::default())
+    }
+}
+
+impl Streaming for Day09 {
+    fn stream(reader: impl BufRead) -> Result<(Self::PartOne, Self::PartTwo), ParseError> {
+        Self::stream_with(reader, &RopeLengths::default())
+    }
+}
@@ -0,0 +1,110 @@
+use std::collections::HashSet;
+use aoc_point::{Direction, Point};
+use crate::instruction::Instruction;
+
+/// A position in the grid, whose origin is at the center of the grid (0, 0).
+/// Each coordinate can be negative.
+pub type Position = Point<i64>;
+
+/// A rope that can be moved.
+/// The rope is made of knots.
+/// Each knot is a position in the grid.
+/// The knots are connected.
+/// 
+/// # Fields
+/// 
+/// * `knots` - The list of knots that compose the rope.
+/// * `visited_locations` - A set of the locations the tail of the rope has visited.
+pub struct Rope {
+    knots: Vec<Position>,
+    visited_locations: HashSet<Position>,
+}
+
+impl Rope {
+    /// Creates a new rope of a given size.
+    /// Each knots is placed at the origin (0, 0).
+    /// 
+    /// # Arguments
+    /// 
+    /// * `size` - The number of knots in the rope.
+    pub fn new(size: usize) -> Self {
+        // create a set of visited locations
+        let mut hash_set = HashSet::new();
+
+        // add the origin to the set
+        hash_set.insert(Position::ORIGIN);
+
+        // create the rope
+        Self {
+            knots: vec![Position::ORIGIN; size],
+            visited_locations: hash_set,
+        }
+    }
+
+    /// Moves the rope according to an instruction.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `instruction` - The instruction to move the rope.
+    pub fn move_rope(&mut self, instruction: Instruction) {
+        // find the direction the head moves in
+        let (direction, distance) = match instruction {
+            Instruction::Up(distance) => (Direction::North, distance),
+            Instruction::Down(distance) => (Direction::South, distance),
+            Instruction::Left(distance) => (Direction::West, distance),
+            Instruction::Right(distance) => (Direction::East, distance),
+        };
+
+        // move the head one step at a time
+        for _ in 0..distance {
+            self.knots[0] = self.knots[0].step(direction);
+            self.propagate();
+        }
+    }
+
+    /// Propagates the movement of the rope after the first knots was moved.
+    fn propagate(&mut self) {
+        // The number of knots in the rope.
+        let knots_count = self.knots.len();
+
+        // Propagate the movement of the first knot to the other knots. The first knot was already moved manually, so we start at 1.
+        for knot in 1..knots_count {
+            // find the two currently connected knots
+            let previous_knot = self.knots[knot - 1];
+            let current_knot = self.knots[knot];
+
+            // if the two knots are not connected, move the current knot one step toward the previous one
+            if previous_knot.chebyshev_distance(current_knot) > 1 {
+                self.knots[knot] += (previous_knot - current_knot).signum();
+            }
+            // if the two knots are connected, then we can stop propagating the movement
+            else {
+                break;
+            }
+        }
+
+        // add the last knot position to the set of visited locations
+        self.visited_locations.insert(self.knots[knots_count - 1]);
+
+        aoc_common::trace!(9, "step", knots = self.knots.iter().map(|knot| [knot.x, knot.y]).collect::<Vec<_>>());
+    }
+
+    /// Returns the positions of the knots of the rope, starting with the head.
+    pub fn knots(&self) -> &[Position] {
+        &self.knots
+    }
+
+    /// Returns the locations the tail of the rope has visited.
+    pub fn visited_locations(&self) -> &HashSet<Position> {
+        &self.visited_locations
+    }
+
+    /// Returns the number of locations the tail of the rope has visited.
+    /// 
+    /// # Returns
+    /// 
+    /// The number of locations the tail of the rope has visited.
+    pub fn visited_locations_count(&self) -> usize {
+        self.visited_locations.len()
+    }
+}
@@ -0,0 +1,56 @@
+use std::iter;
+use aoc_visual::{Cell, Color, Frame, Frames, Visualize};
+use crate::{rope::{Position, Rope}, Day09};
+
+/// The number of knots of the animated rope, the one of the second part.
+const ROPE_SIZE: usize = 10;
+
+/// Animates the rope of the second part, one frame per instruction, along with the locations visited by its tail.
+impl Visualize for Day09 {
+    fn frames(instructions: Self::Input) -> Frames {
+        // every knot follows the head, so the rope never leaves the area covered by the head
+        let mut head = Rope::new(1);
+        let (mut min, mut max) = (Position::ORIGIN, Position::ORIGIN);
+        for instruction in &instructions {
+            head.move_rope(*instruction);
+            let position = head.knots()[0];
+            min = Position::new(min.x.min(position.x), min.y.min(position.y));
+            max = Position::new(max.x.max(position.x), max.y.max(position.y));
+        }
+
+        // the initial rope, followed by the rope after each instruction
+        let mut rope = Rope::new(ROPE_SIZE);
+        let initial_frame = draw(&rope, min, max);
+        let frames = instructions.into_iter().map(move |instruction| {
+            rope.move_rope(instruction);
+            draw(&rope, min, max)
+        });
+
+        Box::new(iter::once(initial_frame).chain(frames))
+    }
+}
+
+/// Draws the locations visited by the tail, the starting point, and the knots of the rope on top of them.
+fn draw(rope: &Rope, min: Position, max: Position) -> Frame {
+    let mut frame = Frame::new((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize);
+    let cell_position = |position: Position| ((position.x - min.x) as usize, (position.y - min.y) as usize);
+
+    for &location in rope.visited_locations() {
+        frame[cell_position(location)] = Cell::new('#', Color::DARK_GRAY);
+    }
+    frame[cell_position(Position::ORIGIN)] = Cell::new('s', Color::WHITE);
+
+    // draw the tail first, so the knots closer to the head are drawn over it
+    let knots = rope.knots();
+    for (index, &knot) in knots.iter().enumerate().rev() {
+        let cell = if index == 0 {
+            Cell::new('H', Color::RED)
+        } else {
+            let symbol = char::from_digit(index as u32, 10).unwrap_or('T');
+            Cell::new(symbol, Color::YELLOW.mix(Color::GREEN, index as f64 / knots.len() as f64))
+        };
+        frame[cell_position(knot)] = cell;
+    }
+
+    frame
+}
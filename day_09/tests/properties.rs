@@ -0,0 +1,34 @@
+use aoc_common::{random::check_property, Solution};
+use day_09::{generator::generate_input, instruction::Instruction, rope::Rope, Day09};
+
+/// Splits an instruction into single steps.
+fn single_steps(instruction: Instruction) -> impl Iterator<Item = Instruction> {
+    let (step, distance) = match instruction {
+        Instruction::Up(distance) => (Instruction::Up(1), distance),
+        Instruction::Down(distance) => (Instruction::Down(1), distance),
+        Instruction::Left(distance) => (Instruction::Left(1), distance),
+        Instruction::Right(distance) => (Instruction::Right(1), distance),
+    };
+    (0..distance).map(move |_| step)
+}
+
+#[test]
+fn knots_stay_adjacent() {
+    check_property(100, |rng| {
+        let input = generate_input(rng, 50);
+        let instructions = Day09::parse(&input).unwrap();
+        let mut rope = Rope::new(rng.usize(2..12));
+
+        // after every step of the head, each knot touches the previous one
+        for step in instructions.into_iter().flat_map(single_steps) {
+            rope.move_rope(step);
+            for knots in rope.knots().windows(2) {
+                assert!(knots[0].chebyshev_distance(knots[1]) <= 1, "{:?} and {:?} are not adjacent", knots[0], knots[1]);
+            }
+        }
+
+        // the tail can't visit more locations than the head moved, plus its starting location
+        let steps: usize = input.lines().map(|line| line[2..].parse::<usize>().unwrap()).sum();
+        assert!(rope.visited_locations_count() <= steps + 1);
+    });
+}
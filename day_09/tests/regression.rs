@@ -0,0 +1,78 @@
+use aoc_common::{Solution, Streaming};
+use aoc_visual::parse_frames;
+use day_09::{Day09, RopeLengths};
+
+/// The example from the puzzle description.
+const EXAMPLE: &str = "\
+R 4
+U 4
+L 3
+D 1
+R 4
+D 1
+L 5
+R 2
+";
+
+/// The larger example from the puzzle description, used for the second part.
+const LARGER_EXAMPLE: &str = "\
+R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20
+";
+
+/// The puzzle input of the repository.
+const INPUT: &str = include_str!("../../inputs/day_09.txt");
+
+#[test]
+fn example() {
+    assert_eq!(Day09::solve(EXAMPLE).unwrap(), (13, 1));
+}
+
+#[test]
+fn larger_example() {
+    let instructions = Day09::parse(LARGER_EXAMPLE).expect("The example should be valid");
+    assert_eq!(Day09::part_two(&instructions), 36);
+}
+
+#[test]
+fn input() {
+    assert_eq!(Day09::solve(INPUT).unwrap(), (5_710, 2_259));
+}
+
+#[test]
+fn visualization() {
+    // the initial rope and the rope after each instruction, the knots closer to the head hide the others
+    let frames: Vec<String> = parse_frames::<Day09>(EXAMPLE).unwrap().map(|frame| frame.to_string()).collect();
+    assert_eq!(frames.len(), 9);
+    assert_eq!(frames[8], "      \n      \n 1H3  \n 5    \n6     ");
+}
+
+#[test]
+fn example_rope_lengths() {
+    let instructions = Day09::parse(EXAMPLE).expect("The example should be valid");
+
+    // swapping the lengths swaps the answers
+    let swapped = RopeLengths { part_one: 10, part_two: 2 };
+    assert_eq!(Day09::part_one_with(&instructions, &swapped), 1);
+    assert_eq!(Day09::part_two_with(&instructions, &swapped), 13);
+}
+
+#[test]
+fn streamed_example() {
+    assert_eq!(Day09::solve_reader(EXAMPLE.as_bytes()).unwrap(), (13, 1));
+
+    // the lengths of the ropes can be changed too
+    let swapped = RopeLengths { part_one: 10, part_two: 2 };
+    assert_eq!(Day09::stream_with(EXAMPLE.as_bytes(), &swapped).unwrap(), (1, 13));
+}
+
+#[test]
+fn streamed_input() {
+    assert_eq!(Day09::solve_reader(INPUT.as_bytes()).unwrap(), (5_710, 2_259));
+}
@@ -0,0 +1,128 @@
+use crate::instruction::Instruction;
+
+/// The state of the crt while it runs the instructions.
+/// 
+/// # Fields
+/// 
+/// * `reg_x` - The value of the x register.
+/// * `cycle` - The current cycle.
+/// * `next_log` - The next cycle whose signal strength is logged.
+/// * `total_signal_strength` - The total signal strength logged so far.
+/// * `crt_screen` - The values displayed on the crt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Crt {
+    reg_x: i64,
+    cycle: usize,
+    next_log: usize,
+    total_signal_strength: i64,
+    crt_screen: Vec<Vec<bool>>,
+}
+
+impl Crt {
+    /// Creates a crt that hasn't run any instruction yet, with all its pixels off.
+    pub fn new() -> Self {
+        Self { reg_x: 1, cycle: 0, next_log: 20, total_signal_strength: 0, crt_screen: vec![vec![false; 40]; 6] }
+    }
+
+    /// Runs a single instruction on the crt.
+    pub fn run(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::NoOp => {
+                self.draw_pixel();
+
+                // if needed, log the signal strength
+                self.log_signal_strength();
+            },
+            Instruction::AddX(x) => {
+                self.draw_pixel();
+                self.draw_pixel();
+
+                // if needed, log the signal strength
+                self.log_signal_strength();
+
+                // increment the x register
+                self.reg_x += x;
+            }
+        }
+    }
+
+    /// The total signal strength of the signal so far.
+    pub fn total_signal_strength(&self) -> i64 {
+        self.total_signal_strength
+    }
+
+    /// The pixels that are on so far.
+    pub fn crt_screen(&self) -> &[Vec<bool>] {
+        &self.crt_screen
+    }
+
+    /// Draws the pixel of the current cycle, then moves to the next cycle.
+    /// 
+    /// The screen is only 6 rows high, so the cycles after the 240th don't draw anything.
+    fn draw_pixel(&mut self) {
+        // find the current x and y coordinates of the pixel
+        let pixel_x = (self.cycle % 40) as i64;
+        let pixel_y = self.cycle / 40;
+
+        // if the current pixel is in the sprite and on the screen, then it is on
+        let lit = self.reg_x - 1 <= pixel_x && pixel_x <= self.reg_x + 1 && pixel_y < self.crt_screen.len();
+        if lit {
+            self.crt_screen[pixel_y][pixel_x as usize] = true;
+        }
+
+        // increment the cycle
+        self.cycle += 1;
+        aoc_common::trace!(10, "cycle", cycle = self.cycle, x = self.reg_x, lit = lit);
+    }
+
+    /// Logs the signal strength if the log cycle was reached.
+    fn log_signal_strength(&mut self) {
+        if self.cycle >= self.next_log {
+            self.total_signal_strength += self.reg_x * self.next_log as i64;
+            self.next_log += 40;
+        }
+    }
+}
+
+impl Default for Crt {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Runs the instructions on the crt.
+/// 
+/// # Returns
+/// 
+/// The total signal strength of the signal, and the pixels that are on when the program ends.
+pub fn execute(instructions: &[Instruction]) -> (i64, Vec<Vec<bool>>) {
+    // create the crt
+    let mut crt = Crt::new();
+
+    // run each instruction
+    for instruction in instructions {
+        crt.run(*instruction);
+    }
+
+    (crt.total_signal_strength, crt.crt_screen)
+}
+
+/// Converts the pixels of the crt screen into a string, where the pixels that are on are displayed as `█`.
+pub fn render_screen(crt_screen: &[Vec<bool>]) -> String {
+    // convert the crt screen to a string
+    let mut screen = String::new();
+    for (line_index, line) in crt_screen.iter().enumerate() {
+        for &pixel in line {
+            if pixel {
+                screen.push('█');
+            } else {
+                screen.push(' ');
+            }
+        }
+        if line_index != crt_screen.len() - 1 {
+            screen.push('\n');
+        }
+    }
+
+    screen
+}
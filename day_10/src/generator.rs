@@ -0,0 +1,25 @@
+use aoc_common::random::Rng;
+
+/// The number of cycles needed to draw the whole screen.
+const CYCLES: usize = 240;
+
+/// Generates a valid program drawing exactly one frame on the screen.
+/// 
+/// The screen only has room for 240 cycles, so the size of the program can't grow: `size` is ignored.
+pub fn generate_input(rng: &mut Rng, _size: usize) -> String {
+    let mut input = String::new();
+    let mut cycle = 0;
+
+    while cycle < CYCLES {
+        // an `addx` takes 2 cycles, it can't start on the last one
+        if cycle + 2 <= CYCLES && rng.chance(0.7) {
+            input.push_str(&format!("addx {}\n", rng.i64(-20..21)));
+            cycle += 2;
+        } else {
+            input.push_str("noop\n");
+            cycle += 1;
+        }
+    }
+
+    input
+}
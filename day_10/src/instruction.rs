@@ -0,0 +1,72 @@
+use std::io::BufRead;
+use regex::Regex;
+use aoc_common::{parse::{numbered_lines, numbered_reader_lines, parse_number}, ParseError};
+
+/// An instruction for the crt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    NoOp,
+    AddX(i64)
+}
+
+/// Parses the instructions from the input.
+/// 
+/// Returns an error pointing at the first line that isn't a valid instruction.
+pub fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    // create the parser of the instructions
+    let parser = InstructionParser::new();
+
+    // parse each line in the input
+    numbered_lines(input)
+        .map(|(line_number, line)| parser.parse(line).map_err(|error| error.at_line(line_number, line)))
+        .collect()
+}
+
+/// Reads the instructions one line at a time, and parses each of them as soon as it is read.
+/// 
+/// Yields an error for each line that isn't a valid instruction.
+pub fn read_instructions(reader: impl BufRead) -> impl Iterator<Item = Result<Instruction, ParseError>> {
+    let parser = InstructionParser::new();
+
+    numbered_reader_lines(reader).map(move |line| {
+        let (line_number, line) = line?;
+        parser.parse(&line).map_err(|error| error.at_line(line_number, &line))
+    })
+}
+
+/// Parses the instructions, one line at a time.
+struct InstructionParser {
+    noop_regex: Regex,
+    addx_regex: Regex,
+}
+
+impl InstructionParser {
+    /// Creates the regexes for parsing the instructions.
+    fn new() -> Self {
+        Self {
+            noop_regex: Regex::new(r"^noop$").unwrap(),
+            addx_regex: Regex::new(r"^addx (\S*)$").unwrap(),
+        }
+    }
+
+    /// Parses a single instruction, such as `addx -5`.
+    fn parse(&self, line: &str) -> Result<Instruction, ParseError> {
+        // if the line matches the noop regex
+        if self.noop_regex.is_match(line) {
+            Ok(Instruction::NoOp)
+        }
+
+        // if the line matches the addx regex
+        else if let Some(captures) = self.addx_regex.captures(line) {
+            // parse the value
+            let add_x_value: i64 = parse_number(&captures[1], "the value to add")
+                .map_err(|error| error.within(line, &captures[1]))?;
+            Ok(Instruction::AddX(add_x_value))
+        }
+
+        // if the line doesn't match any regex
+        else {
+            Err(ParseError::new("expected an instruction (`noop` or `addx <value>`)"))
+        }
+    }
+}
@@ -0,0 +1,48 @@
+pub mod instruction;
+pub mod crt;
+pub mod generator;
+pub mod visualization;
+
+use std::io::BufRead;
+use aoc_common::{ParseError, Solution, Streaming};
+use crt::{execute, render_screen, Crt};
+use instruction::{parse_instructions, read_instructions, Instruction};
+
+/// The goal of this challenge is to find the total signal strength of the signal, and find what is displayed on the crt screen.
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Instruction>;
+    type PartOne = i64;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_instructions(input)
+    }
+
+    /// The total signal strength of the signal.
+    fn part_one(instructions: &Self::Input) -> Self::PartOne {
+        execute(instructions).0
+    }
+
+    /// The image displayed on the crt screen.
+    fn part_two(instructions: &Self::Input) -> Self::PartTwo {
+        let (_, crt_screen) = execute(instructions);
+
+        render_screen(&crt_screen)
+    }
+}
+
+impl Streaming for Day10 {
+    fn stream(reader: impl BufRead) -> Result<(Self::PartOne, Self::PartTwo), ParseError> {
+        // run each instruction as soon as it is read
+        let mut crt = Crt::new();
+        for instruction in read_instructions(reader) {
+            crt.run(instruction?);
+        }
+
+        Ok((crt.total_signal_strength(), render_screen(crt.crt_screen())))
+    }
+}
@@ -0,0 +1,67 @@
+use aoc_visual::{Cell, Color, Frame, Frames, Visualize};
+use crate::{instruction::Instruction, Day10};
+
+/// The number of pixels in a row of the crt.
+const SCREEN_WIDTH: usize = 40;
+
+/// The number of rows of the crt.
+const SCREEN_HEIGHT: usize = 6;
+
+/// Animates the crt drawing its screen, one frame per cycle.
+/// 
+/// Each frame shows the cycle and the value of the x register, then the sprite, then the screen with the pixel being drawn.
+impl Visualize for Day10 {
+    fn frames(instructions: Self::Input) -> Frames {
+        // find the value of the x register during each cycle, an addx takes two cycles and changes the register after them
+        let mut reg_x: i64 = 1;
+        let mut registers = vec![];
+        for instruction in instructions {
+            match instruction {
+                Instruction::NoOp => registers.push(reg_x),
+                Instruction::AddX(x) => {
+                    registers.extend([reg_x, reg_x]);
+                    reg_x += x;
+                }
+            }
+        }
+
+        // the screen only has room for so many cycles
+        registers.truncate(SCREEN_WIDTH * SCREEN_HEIGHT);
+
+        // draw one pixel per cycle
+        let mut screen = vec![false; SCREEN_WIDTH * SCREEN_HEIGHT];
+        let frames = registers.into_iter().enumerate().map(move |(cycle, reg_x)| {
+            let pixel_x = (cycle % SCREEN_WIDTH) as i64;
+            screen[cycle] = (reg_x - 1..=reg_x + 1).contains(&pixel_x);
+            draw(&screen, cycle, reg_x)
+        });
+
+        Box::new(frames)
+    }
+}
+
+/// Draws the state of the crt during a cycle (starting at 0).
+fn draw(screen: &[bool], cycle: usize, reg_x: i64) -> Frame {
+    let mut frame = Frame::new(SCREEN_WIDTH, SCREEN_HEIGHT + 2);
+    frame.write_text((0, 0), &format!("cycle {}, X = {reg_x}", cycle + 1), Color::WHITE);
+
+    // the sprite is 3 pixels wide, centered on the x register
+    for x in reg_x - 1..=reg_x + 1 {
+        if (0..SCREEN_WIDTH as i64).contains(&x) {
+            frame[(x as usize, 1)] = Cell::new('▀', Color::YELLOW);
+        }
+    }
+
+    // the pixels drawn so far, and the one being drawn
+    for (index, &lit) in screen[..=cycle].iter().enumerate() {
+        let position = (index % SCREEN_WIDTH, index / SCREEN_WIDTH + 2);
+        frame[position] = match (lit, index == cycle) {
+            (true, false) => Cell::new('█', Color::GREEN),
+            (true, true) => Cell::new('█', Color::WHITE),
+            (false, true) => Cell::new('▒', Color::DARK_GRAY),
+            (false, false) => Cell::EMPTY,
+        };
+    }
+
+    frame
+}
@@ -0,0 +1,231 @@
+use aoc_common::{Solution, Streaming};
+use aoc_visual::parse_frames;
+use day_10::Day10;
+
+/// The larger example from the puzzle description.
+const EXAMPLE: &str = "\
+addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop
+";
+
+/// The image displayed by the example, as drawn in the puzzle description.
+const EXAMPLE_SCREEN: &str = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+/// The puzzle input of the repository.
+const INPUT: &str = include_str!("../../inputs/day_10.txt");
+
+/// The image displayed by the puzzle input, reading `EFCZEFLH`.
+const INPUT_SCREEN: &str = "\
+####.####..##..####.####.#....#..#.####.
+#....#....#..#....#.#....#....#..#.#....
+###..###..#......#..###..#....####.###..
+#....#....#.....#...#....#....#..#.#....
+#....#....#..#.#....#....#....#..#.#....
+#....####..##..####.####.####.#..#.####.";
+
+/// Converts a screen drawn with `#` and `.` into the characters used by the solver.
+fn draw(screen: &str) -> String {
+    screen.replace('#', "█").replace('.', " ")
+}
+
+#[test]
+fn example() {
+    assert_eq!(Day10::solve(EXAMPLE).unwrap(), (13_140, draw(EXAMPLE_SCREEN)));
+}
+
+#[test]
+fn input() {
+    assert_eq!(Day10::solve(INPUT).unwrap(), (12_540, draw(INPUT_SCREEN)));
+}
+
+#[test]
+fn visualization() {
+    // one frame per cycle, the last one shows the whole screen, with the pixel being drawn
+    let frames: Vec<String> = parse_frames::<Day10>(EXAMPLE).unwrap().map(|frame| frame.to_string()).collect();
+    assert_eq!(frames.len(), 240);
+
+    let last_frame: Vec<&str> = frames[239].lines().collect();
+    assert_eq!(last_frame[0].trim_end(), "cycle 240, X = 17");
+    let mut screen = draw(EXAMPLE_SCREEN);
+    screen.pop();
+    screen.push('▒');
+    assert_eq!(last_frame[2..].join("\n"), screen);
+}
+
+#[test]
+fn streamed_example() {
+    assert_eq!(Day10::solve_reader(EXAMPLE.as_bytes()).unwrap(), (13_140, draw(EXAMPLE_SCREEN)));
+}
+
+#[test]
+fn streamed_input() {
+    assert_eq!(Day10::solve_reader(INPUT.as_bytes()).unwrap(), (12_540, draw(INPUT_SCREEN)));
+}
+
+#[test]
+fn streamed_errors() {
+    let error = Day10::solve_reader("noop\naddx 3\naddx x\n".as_bytes()).unwrap_err();
+    assert_eq!((error.day, error.line, error.column), (Some(10), Some(3), Some(6)));
+}
+
+#[test]
+fn long_program() {
+    // the cycles after the last row of the screen don't draw anything
+    let program = format!("{EXAMPLE}{}", "noop\n".repeat(300));
+    let (_, screen) = Day10::solve_reader(program.as_bytes()).unwrap();
+    assert_eq!(screen, draw(EXAMPLE_SCREEN));
+    assert_eq!(Day10::solve(&program).unwrap().1, draw(EXAMPLE_SCREEN));
+
+    // the program can also be much longer than the screen on its own
+    assert!(Day10::solve_reader("noop\n".repeat(300).as_bytes()).is_ok());
+}
@@ -0,0 +1,44 @@
+use aoc_common::random::Rng;
+
+/// The divisors used by the tests of the monkeys.
+/// 
+/// The simulation relies on them being distinct primes, and on their product being small enough
+/// for the worry-levels to be squared without overflowing, which limits the number of monkeys.
+const DIVISORS: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+/// Generates a valid input made of 2 to 8 monkeys, holding `size` items in total (at least one per monkey).
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    // give each monkey a different divisor
+    let monkey_count = rng.usize(2..9);
+    let mut divisors = DIVISORS.to_vec();
+    rng.shuffle(&mut divisors);
+
+    // split the items between the monkeys
+    let mut items_counts = vec![1; monkey_count];
+    for _ in monkey_count..size {
+        items_counts[rng.usize(0..monkey_count)] += 1;
+    }
+
+    let monkeys: Vec<String> = (0..monkey_count)
+        .map(|monkey| {
+            let items: Vec<String> = (0..items_counts[monkey]).map(|_| rng.usize(50..100).to_string()).collect();
+            let operation = match rng.usize(0..5) {
+                0 => "old * old".to_owned(),
+                1 | 2 => format!("old + {}", rng.usize(1..9)),
+                _ => format!("old * {}", rng.usize(2..20)),
+            };
+
+            // a monkey never throws an item to itself
+            let if_true = (monkey + rng.usize(1..monkey_count)) % monkey_count;
+            let if_false = (monkey + rng.usize(1..monkey_count)) % monkey_count;
+
+            format!(
+                "Monkey {monkey}:\n  Starting items: {}\n  Operation: new = {operation}\n  Test: divisible by {}\n    If true: throw to monkey {if_true}\n    If false: throw to monkey {if_false}\n",
+                items.join(", "),
+                divisors[monkey],
+            )
+        })
+        .collect();
+
+    monkeys.join("\n")
+}
@@ -0,0 +1,81 @@
+pub mod monkey;
+pub mod simulation;
+pub mod generator;
+
+use aoc_common::{Arguments, Parameters, ParseError, Solution};
+use monkey::{parse_monkeys, Monkey};
+use simulation::{monkey_business_level, simulation};
+
+/// The goal of this challenge is to find the monkey-business-level.
+pub struct Day11;
+
+/// The number of rounds simulated by each part.
+/// 
+/// # Fields
+/// 
+/// * `part_one` - The number of rounds of the first part, with relief mode activated, 20 in the puzzle.
+/// * `part_two` - The number of rounds of the second part, without relief mode, 10,000 in the puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rounds {
+    pub part_one: u64,
+    pub part_two: u64,
+}
+
+impl Default for Rounds {
+    fn default() -> Self {
+        Self { part_one: 20, part_two: 10_000 }
+    }
+}
+
+impl Parameters for Rounds {
+    const OPTIONS: &'static [(&'static str, &'static str)] = &[
+        ("part1-rounds", "the number of rounds of the first part, with relief mode (20 by default)"),
+        ("part2-rounds", "the number of rounds of the second part, without relief mode (10,000 by default)"),
+    ];
+
+    fn from_arguments(arguments: &Arguments) -> Result<Self, String> {
+        let default = Self::default();
+        Ok(Self {
+            part_one: arguments.parameter("part1-rounds", default.part_one)?,
+            part_two: arguments.parameter("part2-rounds", default.part_two)?,
+        })
+    }
+}
+
+impl Day11 {
+    /// The monkey-business-level after the rounds of the first part, with relief mode activated.
+    pub fn part_one_with(monkeys: &[Monkey], rounds: &Rounds) -> u64 {
+        let mut monkeys_v1 = monkeys.to_vec();
+        simulation(&mut monkeys_v1, rounds.part_one, true);
+        monkey_business_level(&monkeys_v1)
+    }
+
+    /// The monkey-business-level after the rounds of the second part, without relief mode.
+    pub fn part_two_with(monkeys: &[Monkey], rounds: &Rounds) -> u64 {
+        let mut monkeys_v2 = monkeys.to_vec();
+        simulation(&mut monkeys_v2, rounds.part_two, false);
+        monkey_business_level(&monkeys_v2)
+    }
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<Monkey>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_monkeys(input)
+    }
+
+    /// The monkey-business-level after 20 rounds, with relief mode activated.
+    fn part_one(monkeys: &Self::Input) -> Self::PartOne {
+        Self::part_one_with(monkeys, &Rounds::default())
+    }
+
+    /// The monkey-business-level after 10,000 rounds, without relief mode.
+    fn part_two(monkeys: &Self::Input) -> Self::PartTwo {
+        Self::part_two_with(monkeys, &Rounds::default())
+    }
+}
@@ -0,0 +1,192 @@
+use std::{collections::VecDeque, iter::Peekable};
+use aoc_common::{parse::{numbered_lines, parse_number}, ParseError};
+
+/// Represents how the worry-level changes after a monkey has inspected an item.
+#[derive(Debug, Clone, Copy)]
+pub enum Operation {
+    /// The worry-level is increased by a fixed amount.
+    Add(u64),
+    /// The worry-level is multiplied by a fixed amount.
+    Multiply(u64),
+    /// The worry-level is squared.
+    Square,
+}
+
+/// Represents a monkey.
+/// 
+/// # Attributes
+/// 
+/// * `items_worry_levels` - The worry-levels of the items that the monkey possesses.
+/// * `operation` - The operation to perform on the worry-levels of the items when the monkey inspects them.
+/// * `divisible_test` - If the worry-level of an item is divisible by this number, the monkey will throw the item to another monkey.
+/// * `test_results` - The indices of the monkeys that the item will be thrown to if the test succeeds or fails.
+/// * `inspected_items_count` - The number of items that the monkey has inspected.
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    pub items_worry_levels: VecDeque<u64>,
+    pub operation: Operation,
+    pub divisible_test: u64,
+    pub test_results: (usize, usize),
+    pub inspected_items_count: u64
+}
+
+/// A line of the input that names the monkey an item is thrown to, kept to report invalid targets.
+/// 
+/// The elements of the tuple are the line number, the content of the line, and the part of the line holding the target.
+type TargetLine<'a> = (usize, &'a str, &'a str);
+
+/// Parses the monkeys from the input string.
+/// 
+/// Each monkey is described by six lines, and the monkeys are separated by empty lines:
+/// 
+/// ```text
+/// Monkey 0:
+///   Starting items: 79, 98
+///   Operation: new = old * 19
+///   Test: divisible by 23
+///     If true: throw to monkey 2
+///     If false: throw to monkey 3
+/// ```
+pub fn parse_monkeys(input: &str) -> Result<Vec<Monkey>, ParseError> {
+    // create a vector to store the monkeys in
+    let mut monkeys = vec![];
+
+    // remember where the targets of the monkeys are described, they can only be checked once every monkey is known
+    let mut target_lines: Vec<[TargetLine; 2]> = vec![];
+
+    // iterate over the lines of the input
+    let mut lines = numbered_lines(input).peekable();
+    loop {
+        // skip the empty lines between the monkeys
+        while let Some(&(_, "")) = lines.peek() {
+            lines.next();
+        }
+
+        // stop once every monkey has been parsed
+        if lines.peek().is_none() {
+            break;
+        }
+
+        // parse the monkey
+        let (monkey, monkey_target_lines) = parse_monkey(&mut lines)?;
+        monkeys.push(monkey);
+        target_lines.push(monkey_target_lines);
+    }
+
+    // the monkey-business-level needs at least two monkeys
+    if monkeys.len() < 2 {
+        return Err(ParseError::new(format!("expected at least 2 monkeys, found {}", monkeys.len())));
+    }
+
+    // make sure every item is thrown to a monkey that exists
+    let monkey_count = monkeys.len();
+    for (monkey, monkey_target_lines) in monkeys.iter().zip(target_lines) {
+        for (target, (line_number, line, target_str)) in [monkey.test_results.0, monkey.test_results.1].into_iter().zip(monkey_target_lines) {
+            if target >= monkey_count {
+                return Err(
+                    ParseError::new(format!("there is no monkey {target}, the monkeys are numbered from 0 to {}", monkey_count - 1))
+                        .within(line, target_str)
+                        .at_line(line_number, line)
+                );
+            }
+        }
+    }
+
+    // return the vector of monkeys
+    Ok(monkeys)
+}
+
+/// Parses the six lines describing a monkey.
+/// 
+/// # Returns
+/// 
+/// The monkey, and the lines describing where its items are thrown.
+fn parse_monkey<'a>(
+    lines: &mut Peekable<impl Iterator<Item = (usize, &'a str)>>
+) -> Result<(Monkey, [TargetLine<'a>; 2]), ParseError> {
+    // parse the header of the monkey
+    let (line_number, line, monkey_number) = expect_line(lines, "Monkey ")?;
+    monkey_number
+        .strip_suffix(':')
+        .ok_or_else(|| ParseError::new("expected a monkey header, such as `Monkey 0:`"))
+        .and_then(|number| parse_number::<usize>(number, "a monkey number").map_err(|error| error.within(line, number)))
+        .map_err(|error| error.at_line(line_number, line))?;
+
+    // parse the starting items
+    let (line_number, line, items) = expect_line(lines, "Starting items: ")?;
+    let items_worry_levels: VecDeque<u64> = items
+        .split(", ")
+        .filter(|item| !item.is_empty())
+        .map(|item| parse_number(item, "a worry-level").map_err(|error| error.within(line, item).at_line(line_number, line)))
+        .collect::<Result<_, _>>()?;
+
+    // parse the operation
+    let (line_number, line, operation) = expect_line(lines, "Operation: new = old ")?;
+    let (operator, operand) = operation
+        .split_once(' ')
+        .ok_or_else(|| ParseError::new("expected an operation, such as `new = old * 19`").at_line(line_number, line))?;
+    let parse_operand = || parse_number(operand, "a number").map_err(|error| error.within(line, operand).at_line(line_number, line));
+    let operation = match (operator, operand) {
+        ("*", "old") => Operation::Square,
+        ("*", _) => Operation::Multiply(parse_operand()?),
+        ("+", _) => Operation::Add(parse_operand()?),
+        _ => return Err(
+            ParseError::new(format!("expected an operator (`+` or `*`), found `{operator}`"))
+                .within(line, operator)
+                .at_line(line_number, line)
+        ),
+    };
+
+    // parse the divisible test
+    let (line_number, line, divisible_str) = expect_line(lines, "Test: divisible by ")?;
+    let divisible: u64 = parse_number(divisible_str, "a divisor")
+        .and_then(|divisible| match divisible {
+            0 => Err(ParseError::new("cannot test the divisibility by 0")),
+            _ => Ok(divisible),
+        })
+        .map_err(|error| error.within(line, divisible_str).at_line(line_number, line))?;
+
+    // parse the test results
+    let mut parse_target = |prefix: &str| -> Result<(usize, TargetLine<'a>), ParseError> {
+        let (line_number, line, target_str) = expect_line(lines, prefix)?;
+        let target = parse_number(target_str, "a monkey number")
+            .map_err(|error| error.within(line, target_str).at_line(line_number, line))?;
+        Ok((target, (line_number, line, target_str)))
+    };
+    let (test_result_success, success_line) = parse_target("If true: throw to monkey ")?;
+    let (test_result_fail, fail_line) = parse_target("If false: throw to monkey ")?;
+
+    // create the monkey
+    let monkey = Monkey {
+        items_worry_levels,
+        operation,
+        divisible_test: divisible,
+        test_results: (test_result_success, test_result_fail),
+        inspected_items_count: 0,
+    };
+
+    Ok((monkey, [success_line, fail_line]))
+}
+
+/// Reads the next line, which must start with the given prefix (ignoring the indentation).
+/// 
+/// # Returns
+/// 
+/// The line number, the content of the line, and the rest of the line after the prefix.
+fn expect_line<'a>(
+    lines: &mut impl Iterator<Item = (usize, &'a str)>,
+    prefix: &str
+) -> Result<(usize, &'a str, &'a str), ParseError> {
+    // get the next line
+    let (line_number, line) = lines
+        .next()
+        .ok_or_else(|| ParseError::new(format!("unexpected end of the input, expected `{prefix}`")))?;
+
+    // remove the prefix
+    let rest = line
+        .trim_start()
+        .strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(format!("expected a line starting with `{prefix}`")).at_line(line_number, line))?;
+
+    Ok((line_number, line, rest))
+}
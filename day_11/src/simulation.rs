@@ -0,0 +1,102 @@
+use crate::monkey::{Monkey, Operation};
+
+/// Runs a simulation of the monkeys.
+/// 
+/// # Arguments
+/// 
+/// * `monkeys` - The  monkeys to run the simulation on.
+/// * `number_of_rounds` - The number of rounds to run the simulation for.
+/// * `relief_mode_activated` - Whether or not relief mode is activated. When relief mode is activated, the worry-levels of the items are divided by 3 before being tested.
+pub fn simulation(monkeys: &mut Vec<Monkey>, number_of_rounds: u64, relief_mode_activated: bool) {
+    // get the number of monkeys
+    let monkey_count = monkeys.len();
+
+    // get the lowest common multiple of the monkeys' divisible tests
+    // normally when computing the LCM, you would use the prime factorization of each number
+    // and then multiply the highest power of each prime together
+    // but since we know that the numbers are all distinct primes in our input, we can just multiply them all together
+    let lowest_common_multiple: u64 = monkeys.iter().map(|m| m.divisible_test).product();
+
+    // run the simulation for the specified number of rounds
+    for round in 1..=number_of_rounds {
+
+        // iterate over all the monkeys
+        for current_monkey_index in 0..monkey_count {
+            // get the current monkey out of the vector
+            let mut current_monkey = monkeys[current_monkey_index].clone();
+    
+            // while the current monkey has items to inspect
+            while !current_monkey.items_worry_levels.is_empty() {
+                // get the worry-level of the item the monkey is inspecting
+                let mut item_worry_level = current_monkey.items_worry_levels.pop_front().unwrap();
+    
+                // apply the operation to the item worry-level
+                item_worry_level = match current_monkey.operation {
+                    Operation::Add(n) => item_worry_level + n,
+                    Operation::Multiply(n) => item_worry_level * n,
+                    Operation::Square => item_worry_level.pow(2),
+                };
+
+                // apply relief mode if it is activated
+                if relief_mode_activated {
+                    item_worry_level /= 3;
+                }
+
+                // mod the worry-level by the LCM, so that it is in the range [0, LCM[
+                // else we might overflow the u64
+                item_worry_level %= lowest_common_multiple;
+    
+                // apply the divisible test
+                if item_worry_level % current_monkey.divisible_test == 0 {
+                    // if it is divisible, throw the item to the first monkey
+                    let target_monkey = current_monkey.test_results.0;
+
+                    aoc_common::trace!(11, "throw", round = round, monkey = current_monkey_index, worry_level = item_worry_level, to = target_monkey);
+
+                    // if the target monkey is the current monkey, push the item to the current monkey's queue
+                    if target_monkey == current_monkey_index {
+                        current_monkey.items_worry_levels.push_back(item_worry_level);
+                    }
+                    // else push the item to the target monkey's queue
+                    else {
+                        monkeys[target_monkey].items_worry_levels.push_back(item_worry_level);
+                    }
+                } else {
+                    // if it is not divisible, throw the item to the second monkey
+                    let target_monkey = current_monkey.test_results.1;
+
+                    aoc_common::trace!(11, "throw", round = round, monkey = current_monkey_index, worry_level = item_worry_level, to = target_monkey);
+
+                    // if the target monkey is the current monkey, push the item to the current monkey's queue
+                    if target_monkey == current_monkey_index {
+                        current_monkey.items_worry_levels.push_back(item_worry_level);
+                    }
+                    // else push the item to the target monkey's queue
+                    else {
+                        monkeys[target_monkey].items_worry_levels.push_back(item_worry_level);
+                    }
+                }
+
+                // increment the number of items inspected by the current monkey
+                current_monkey.inspected_items_count += 1;
+            }
+    
+            // put the current monkey back into the vector
+            monkeys[current_monkey_index] = current_monkey;
+        }
+    }
+}
+
+/// Computes the monkey-business-level, which is the product of the number of items inspected by the two most active monkeys.
+pub fn monkey_business_level(monkeys: &[Monkey]) -> u64 {
+    // get the number of monkeys
+    let monkey_count = monkeys.len();
+
+    // compute the monkey business level
+    let mut inspected_items_values = vec![];
+    for monkey in monkeys {
+        inspected_items_values.push(monkey.inspected_items_count);
+    }
+    inspected_items_values.sort();
+    inspected_items_values[monkey_count - 1] * inspected_items_values[monkey_count - 2]
+}
@@ -0,0 +1,56 @@
+use aoc_common::Solution;
+use day_11::{Day11, Rounds};
+
+/// The example from the puzzle description.
+const EXAMPLE: &str = "\
+Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 23
+    If true: throw to monkey 2
+    If false: throw to monkey 3
+
+Monkey 1:
+  Starting items: 54, 65, 75, 74
+  Operation: new = old + 6
+  Test: divisible by 19
+    If true: throw to monkey 2
+    If false: throw to monkey 0
+
+Monkey 2:
+  Starting items: 79, 60, 97
+  Operation: new = old * old
+  Test: divisible by 13
+    If true: throw to monkey 1
+    If false: throw to monkey 3
+
+Monkey 3:
+  Starting items: 74
+  Operation: new = old + 3
+  Test: divisible by 17
+    If true: throw to monkey 0
+    If false: throw to monkey 1
+";
+
+/// The puzzle input of the repository.
+const INPUT: &str = include_str!("../../inputs/day_11.txt");
+
+#[test]
+fn example() {
+    assert_eq!(Day11::solve(EXAMPLE).unwrap(), (10_605, 2_713_310_158));
+}
+
+#[test]
+fn input() {
+    assert_eq!(Day11::solve(INPUT).unwrap(), (90_294, 18_170_818_354));
+}
+
+#[test]
+fn example_rounds() {
+    let monkeys = Day11::parse(EXAMPLE).expect("The example should be valid");
+
+    // after 20 rounds without relief, the most active monkeys inspected 103 and 99 items
+    let rounds = Rounds { part_one: 20, part_two: 20 };
+    assert_eq!(Day11::part_one_with(&monkeys, &rounds), 10_605);
+    assert_eq!(Day11::part_two_with(&monkeys, &rounds), 103 * 99);
+}
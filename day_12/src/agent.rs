@@ -0,0 +1,26 @@
+use aoc_point::Point;
+
+/// Represents an agent on the map.
+/// An agent is a position on the map and the number of steps it took to get there. When moved, the agent will have a new position and the number of steps will be incremented by one.
+/// 
+/// # Fields
+/// 
+/// * `position` - The position of the agent.
+/// * `steps` - The number of steps it took to get to the current position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Agent {
+    pub position: Point<usize>,
+    pub steps: usize,
+}
+
+impl Agent {
+    /// Creates a new agent with the given position and number of steps.
+    pub fn new(position: Point<usize>, steps: usize) -> Self {
+        Self { position, steps }
+    }
+
+    /// Creates a new agent with the given position and zero steps.
+    pub fn from_position(position: Point<usize>) -> Self {
+        Self::new(position, 0)
+    }
+}
@@ -0,0 +1,60 @@
+use aoc_common::ParseError;
+use aoc_grid::Grid;
+use aoc_point::Point;
+
+/// Represents the elevation map.
+/// 
+/// # Fields
+/// 
+/// * `elevations` - The elevations of the map. Is a number between 0 and 25.
+/// * `start_position` - The start position of the map.
+/// * `end_position` - The end position of the map.
+pub struct ElevationMap {
+    pub elevations: Grid<i64>,
+    pub start_position: Point<usize>,
+    pub end_position: Point<usize>,
+}
+
+impl ElevationMap {
+    /// Creates a new elevation map from the given input.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an error if a character isn't an elevation (`a` to `z`, `S` or `E`), if the rows don't all have the same length,
+    /// or if the start or end position is missing or defined more than once.
+    pub fn parse_from_string(input: &str) -> Result<Self, ParseError> {
+        // variable to store the start position
+        let mut start_position: Option<Point<usize>> = None;
+        // variable to store the end position
+        let mut end_position: Option<Point<usize>> = None;
+
+        // parse the elevation of each point on the map
+        let elevations = Grid::parse(input, |(x, y), char| match char {
+            // the start position is represented by an S, and has an elevation of 0
+            'S' => {
+                if start_position.replace(Point::new(x, y)).is_some() {
+                    return Err(ParseError::new("the start position is defined more than once"));
+                }
+                Ok(0)
+            }
+            // the end position is represented by an E, and has an elevation of 25
+            'E' => {
+                if end_position.replace(Point::new(x, y)).is_some() {
+                    return Err(ParseError::new("the end position is defined more than once"));
+                }
+                Ok(25)
+            }
+            // otherwise, the character is a letter, with a to z corresponding to 0 to 25
+            'a'..='z' => Ok((char as i64) - 97),
+            // any other character is invalid
+            _ => Err(ParseError::new(format!("expected an elevation (`a` to `z`, `S` or `E`), found `{char}`"))),
+        })?;
+
+        // return the elevation map
+        Ok(ElevationMap {
+            elevations,
+            start_position: start_position.ok_or_else(|| ParseError::new("no start position (`S`) found"))?,
+            end_position: end_position.ok_or_else(|| ParseError::new("no end position (`E`) found"))?,
+        })
+    }
+}
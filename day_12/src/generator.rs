@@ -0,0 +1,49 @@
+use aoc_common::random::Rng;
+use aoc_grid::Grid;
+use aoc_point::{Direction, Point};
+
+/// Generates a valid heightmap of `size` rows (at least 5) and twice as many columns (at least 26).
+/// 
+/// The elevation grows steadily from the start `S`, on the left, to the end `E`, on the right.
+/// Away from a random path linking them, many positions get a random elevation, so the map contains cliffs and dead ends,
+/// but the end can always be reached.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let height = size.max(5);
+    let width = (2 * size).max(26);
+
+    // place the start and the end on opposite sides of the map, so the elevation has room to grow from `a` to `z`
+    let start = Point::new(0, rng.usize(0..height));
+    let end = Point::new(width - 1, rng.usize(0..height));
+    let start_distance = start.manhattan_distance(end);
+
+    // the elevation only depends on the distance to the end, so a step toward the end never climbs more than 1
+    let elevation = |position: Point<usize>| {
+        let distance = position.manhattan_distance(end).min(start_distance);
+        25 * (start_distance - distance) / start_distance
+    };
+    let mut elevations = Grid::from_fn(width, height, |position| elevation(Point::from(position)));
+
+    // find a path from the start to the end, always getting closer to the end
+    let mut path = Grid::new(width, height, false);
+    path[start] = true;
+    let mut position = start;
+    while position != end {
+        let vertical = if end.y < position.y { Direction::North } else { Direction::South };
+        let direction = if position.y == end.y || (position.x != end.x && rng.chance(0.5)) { Direction::East } else { vertical };
+        position = position.checked_step(direction).expect("The path stays inside of the map.");
+        path[position] = true;
+    }
+
+    // scramble the elevation of the other positions
+    for position in path.positions() {
+        if !path[position] && rng.chance(0.3) {
+            elevations[position] = rng.usize(0..26);
+        }
+    }
+
+    // draw the map
+    let mut map = elevations.map(|&elevation| (b'a' + elevation as u8) as char);
+    map[start] = 'S';
+    map[end] = 'E';
+    format!("{map}\n")
+}
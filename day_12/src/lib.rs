@@ -0,0 +1,50 @@
+pub mod agent;
+pub mod elevation_map;
+pub mod simulation;
+pub mod generator;
+pub mod visualization;
+
+use aoc_common::{MaybeAnswer, ParseError, Solution};
+use aoc_point::Point;
+use agent::Agent;
+use elevation_map::ElevationMap;
+use simulation::simulate;
+
+/// The goal of this challenge is to find the shortest path from the start position to the end position.
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = ElevationMap;
+    type PartOne = MaybeAnswer<usize>;
+    type PartTwo = MaybeAnswer<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        ElevationMap::parse_from_string(input)
+    }
+
+    /// The length of the shortest path when starting from S, if the end can be reached from it.
+    fn part_one(elevation_map: &Self::Input) -> Self::PartOne {
+        // create the first version of the agents
+        let mut agents_v1: Vec<Agent> = vec![];
+        agents_v1.push(Agent::from_position(elevation_map.start_position));
+
+        // run the first version of the simulation
+        MaybeAnswer::from_option(simulate(elevation_map, agents_v1), "no path to the end")
+    }
+
+    /// The length of the shortest path when starting from any position with an elevation of 0, if the end can be reached from one.
+    fn part_two(elevation_map: &Self::Input) -> Self::PartTwo {
+        // create the second version of the agents
+        let agents_v2: Vec<Agent> = elevation_map
+            .elevations
+            .enumerate()
+            .filter(|(_, &elevation)| elevation == 0)
+            .map(|(position, _)| Agent::from_position(Point::from(position)))
+            .collect();
+
+        // run the second version of the simulation
+        MaybeAnswer::from_option(simulate(elevation_map, agents_v2), "no path to the end")
+    }
+}
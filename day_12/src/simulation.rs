@@ -0,0 +1,62 @@
+use aoc_grid::Grid;
+use aoc_point::Point;
+use crate::{agent::Agent, elevation_map::ElevationMap};
+
+/// Simulates the agents moving on the map.
+pub fn simulate(elevation_map: &ElevationMap, agents: Vec<Agent>) -> Option<usize> {
+    simulate_observed(elevation_map, agents, |_| {})
+}
+
+/// Simulates the agents moving on the map, showing each wave of agents to the observer before it moves.
+pub fn simulate_observed(
+    elevation_map: &ElevationMap,
+    mut agents: Vec<Agent>,
+    mut observe: impl FnMut(&[Agent]),
+) -> Option<usize> {
+    let elevations = &elevation_map.elevations;
+
+    // store the locations that have been visited
+    let mut visited_locations = Grid::new(elevations.width(), elevations.height(), false);
+
+    // mark the starting positions of the agents as visited
+    for agent in &agents {
+        visited_locations[agent.position] = true;
+    }
+
+    // simulate the agents moving on the map until they found the end position or there are no more move options
+    loop {
+        // show the current wave of agents
+        observe(&agents);
+
+        // create a variable to store the next wave of agents
+        let mut next_agents: Vec<Agent> = vec![];
+
+        // iterate over each agent
+        for agent in agents {
+            // check if the agent has reached the end position
+            if agent.position == elevation_map.end_position {
+                return Some(agent.steps);
+            }
+
+            // get the elevation of the current agent
+            let agent_elevation = elevations[agent.position];
+
+            // go north, south, west and east if possible
+            for neighbour in elevations.neighbours(agent.position.into()).map(Point::from) {
+                if elevations[neighbour] - agent_elevation <= 1 && !visited_locations[neighbour] {
+                    visited_locations[neighbour] = true;
+                    next_agents.push(Agent::new(neighbour, agent.steps + 1));
+                }
+            }
+        }
+
+        // if the next wave of agents is empty, then there are no more move options
+        // in that case there are no paths to the end position
+        if next_agents.is_empty() {
+            return None;
+        }
+        
+        // update the agents to the next wave
+        agents = next_agents;
+    }
+}
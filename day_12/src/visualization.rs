@@ -0,0 +1,56 @@
+use aoc_grid::Grid;
+use aoc_point::Point;
+use aoc_visual::{Cell, Color, Frame, Frames, Visualize};
+use crate::{agent::Agent, elevation_map::ElevationMap, simulation::simulate_observed, Day12};
+
+/// The color of the lowest elevation, the highest one is white.
+const LOWLAND: Color = Color::rgb(30, 70, 30);
+
+/// Animates the search of the first part, one frame per wave of agents leaving the start position.
+impl Visualize for Day12 {
+    fn frames(elevation_map: Self::Input) -> Frames {
+        // record the positions of each wave of agents
+        let mut waves: Vec<Vec<Point<usize>>> = vec![];
+        let start = vec![Agent::from_position(elevation_map.start_position)];
+        simulate_observed(&elevation_map, start, |agents| {
+            waves.push(agents.iter().map(|agent| agent.position).collect());
+        });
+
+        // each frame adds a wave to the locations visited by the previous ones
+        let elevations = &elevation_map.elevations;
+        let mut visited = Grid::new(elevations.width(), elevations.height(), false);
+        let frames = waves.into_iter().map(move |wave| {
+            for &position in &wave {
+                visited[position] = true;
+            }
+            draw(&elevation_map, &visited, &wave)
+        });
+
+        Box::new(frames)
+    }
+}
+
+/// Draws the elevation of each location, along with the locations visited so far and the current wave of agents.
+fn draw(elevation_map: &ElevationMap, visited: &Grid<bool>, wave: &[Point<usize>]) -> Frame {
+    // the elevations go from green for the lowlands to white for the peaks, and are tinted in blue once visited
+    let elevations = &elevation_map.elevations;
+    let mut frame = Frame::from_grid(Grid::from_fn(elevations.width(), elevations.height(), |position| {
+        let elevation = elevations[position];
+        let symbol = (b'a' + elevation as u8) as char;
+        let color = LOWLAND.mix(Color::WHITE, elevation as f64 / 25.0);
+        if visited[position] {
+            Cell::new(symbol, color.mix(Color::BLUE, 0.6))
+        } else {
+            Cell::new(symbol, color)
+        }
+    }));
+
+    // the agents, then the start and end positions on top of them
+    for &position in wave {
+        frame[(position.x, position.y)].color = Color::YELLOW;
+    }
+    frame[elevation_map.start_position.into()] = Cell::new('S', Color::RED);
+    frame[elevation_map.end_position.into()] = Cell::new('E', Color::RED);
+
+    frame
+}
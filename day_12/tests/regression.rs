@@ -0,0 +1,46 @@
+use aoc_common::{MaybeAnswer, Solution};
+use aoc_visual::parse_frames;
+use day_12::Day12;
+
+/// The example from the puzzle description.
+const EXAMPLE: &str = "\
+Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi
+";
+
+/// The puzzle input of the repository.
+const INPUT: &str = include_str!("../../inputs/day_12.txt");
+
+#[test]
+fn example() {
+    assert_eq!(Day12::solve(EXAMPLE).unwrap(), (MaybeAnswer::Answer(31), MaybeAnswer::Answer(29)));
+}
+
+#[test]
+fn input() {
+    assert_eq!(Day12::solve(INPUT).unwrap(), (MaybeAnswer::Answer(352), MaybeAnswer::Answer(345)));
+}
+
+#[test]
+fn no_path() {
+    // the end is surrounded by positions too high to climb from
+    let walled = "\
+Sabcz
+abzzz
+abzEz
+";
+    let no_path = MaybeAnswer::NoAnswer("no path to the end");
+    assert_eq!(Day12::solve(walled).unwrap(), (no_path, no_path));
+    assert_eq!(no_path.to_string(), "no path to the end");
+}
+
+#[test]
+fn visualization() {
+    // one frame per wave of agents, until the end position is reached
+    let frames: Vec<String> = parse_frames::<Day12>(EXAMPLE).unwrap().map(|frame| frame.to_string()).collect();
+    assert_eq!(frames.len(), 32);
+    assert_eq!(frames[31], EXAMPLE.trim_end());
+}
@@ -0,0 +1,29 @@
+use aoc_common::random::Rng;
+use crate::packet::Packet;
+
+/// Generates a valid input made of `size` pairs of packets.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let pairs: Vec<String> = (0..size)
+        .map(|_| format!("{}\n{}\n", generate_packet(rng, 4), generate_packet(rng, 4)))
+        .collect();
+
+    pairs.join("\n")
+}
+
+/// Generates a list-packet, nesting lists at most `depth` times.
+/// 
+/// The numbers are small, so that the packets often share a common prefix, like in the real inputs.
+pub fn generate_packet(rng: &mut Rng, depth: usize) -> Packet {
+    let length = rng.usize(0..6);
+    let packets = (0..length)
+        .map(|_| {
+            if depth > 0 && rng.chance(0.3) {
+                generate_packet(rng, depth - 1)
+            } else {
+                Packet::Number(rng.below(11))
+            }
+        })
+        .collect();
+
+    Packet::List(packets)
+}
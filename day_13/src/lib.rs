@@ -0,0 +1,101 @@
+pub mod packet;
+pub mod generator;
+
+use aoc_common::{parse::numbered_lines, ParseError, Solution};
+use packet::Packet;
+
+/// The goal of this challenge is to compare and sort the packets of the distress signal.
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<(Packet, Packet)>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // create a vector to store the pairs of packets
+        let mut pairs = vec![];
+
+        // group the lines into pairs, the pairs are separated by empty lines
+        let mut pair: Vec<(usize, &str)> = vec![];
+        let mut lines = numbered_lines(input).peekable();
+        while let Some((line_number, line)) = lines.next() {
+            // accumulate the lines of the current pair
+            if !line.is_empty() {
+                pair.push((line_number, line));
+            }
+
+            // wait until the end of the pair
+            if !(line.is_empty() || lines.peek().is_none()) || pair.is_empty() {
+                continue;
+            }
+
+            // if the pair doesn't contain exactly two packets, return an error
+            if pair.len() != 2 {
+                let (first_line_number, first_line) = pair[0];
+                return Err(
+                    ParseError::new(format!("expected a pair of 2 packets, found {} packets", pair.len()))
+                        .at_line(first_line_number, first_line)
+                );
+            }
+
+            // parse the two packets
+            let parse_packet = |(line_number, line): (usize, &str)| {
+                Packet::try_from(line).map_err(|error| error.at_line(line_number, line))
+            };
+            let packet_1 = parse_packet(pair[0])?;
+            let packet_2 = parse_packet(pair[1])?;
+
+            // add the pair to the vector of pairs
+            pairs.push((packet_1, packet_2));
+            pair.clear();
+        }
+
+        Ok(pairs)
+    }
+
+    /// The sum of the indices of the pairs of packets that are in the right order.
+    fn part_one(pairs: &Self::Input) -> Self::PartOne {
+        let mut valid_pairs_index_sum = 0;
+
+        // iterate over the pairs of packets
+        for (pair_index, (packet_1, packet_2)) in pairs.iter().enumerate() {
+            // if the packets are in the right order, add the pair index to the sum
+            if packet_1 <= packet_2 {
+                // + 1 because the index starts at 1 and not 0
+                valid_pairs_index_sum += pair_index + 1;
+            }
+        }
+
+        valid_pairs_index_sum
+    }
+
+    /// The product of the indices of the divider packets, once all the packets are sorted.
+    fn part_two(pairs: &Self::Input) -> Self::PartTwo {
+        // create a vector to store the packets
+        let mut packets = vec![];
+        for (packet_1, packet_2) in pairs {
+            packets.push(packet_1.clone());
+            packets.push(packet_2.clone());
+        }
+
+        // create the two divider packets
+        let packet_two = Packet::Number(2).wrap().wrap();
+        let packet_six = Packet::Number(6).wrap().wrap();
+
+        // add the divider packets to the vector of packets
+        packets.push(packet_two.clone());
+        packets.push(packet_six.clone());
+
+        // sort the packets
+        packets.sort();
+
+        // find the indices of the divider packets
+        let index_packet_two = packets.binary_search(&packet_two).expect("Packet two not found");
+        let index_packet_six = packets.binary_search(&packet_six).expect("Packet six not found");
+
+        (index_packet_two + 1) * (index_packet_six + 1)
+    }
+}
@@ -0,0 +1,44 @@
+use std::cmp::Ordering;
+use aoc_common::{random::check_property, Solution};
+use day_13::{generator::{generate_input, generate_packet}, packet::Packet, Day13};
+
+#[test]
+fn display_and_parse_round_trip() {
+    check_property(300, |rng| {
+        let packet = generate_packet(rng, 5);
+        let displayed = packet.to_string();
+        assert_eq!(Packet::try_from(displayed.as_str()), Ok(packet), "`{displayed}` didn't survive a round trip");
+    });
+}
+
+#[test]
+fn order_is_total() {
+    check_property(300, |rng| {
+        let [a, b, c] = [(); 3].map(|_| generate_packet(rng, 3));
+
+        // reflexive and antisymmetric
+        assert_eq!(a.cmp(&a), Ordering::Equal);
+        assert_eq!(a.cmp(&b), b.cmp(&a).reverse(), "{a} and {b} are not ordered consistently");
+
+        // transitive
+        for (x, y, z) in [(&a, &b, &c), (&a, &c, &b), (&b, &a, &c), (&b, &c, &a), (&c, &a, &b), (&c, &b, &a)] {
+            if x <= y && y <= z {
+                assert!(x <= z, "{x} <= {y} and {y} <= {z}, but not {x} <= {z}");
+            }
+        }
+
+        // a number and the list containing only that number are equivalent
+        let number = Packet::Number(rng.below(11));
+        assert_eq!(number.cmp(&number.wrap()), Ordering::Equal);
+    });
+}
+
+#[test]
+fn generated_input_is_solvable() {
+    check_property(50, |rng| {
+        let size = rng.usize(1..30);
+        let input = generate_input(rng, size);
+        let (right_order_sum, _) = Day13::solve(&input).unwrap();
+        assert!(right_order_sum <= size * (size + 1) / 2);
+    });
+}
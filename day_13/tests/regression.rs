@@ -0,0 +1,42 @@
+use aoc_common::Solution;
+use day_13::Day13;
+
+/// The example from the puzzle description.
+const EXAMPLE: &str = "\
+[1,1,3,1,1]
+[1,1,5,1,1]
+
+[[1],[2,3,4]]
+[[1],4]
+
+[9]
+[[8,7,6]]
+
+[[4,4],4,4]
+[[4,4],4,4,4]
+
+[7,7,7,7]
+[7,7,7]
+
+[]
+[3]
+
+[[[]]]
+[[]]
+
+[1,[2,[3,[4,[5,6,7]]]],8,9]
+[1,[2,[3,[4,[5,6,0]]]],8,9]
+";
+
+/// The puzzle input of the repository.
+const INPUT: &str = include_str!("../../inputs/day_13.txt");
+
+#[test]
+fn example() {
+    assert_eq!(Day13::solve(EXAMPLE).unwrap(), (13, 140));
+}
+
+#[test]
+fn input() {
+    assert_eq!(Day13::solve(INPUT).unwrap(), (5_196, 22_134));
+}
@@ -0,0 +1,34 @@
+use aoc_common::random::Rng;
+
+/// Generates a valid input made of `size` rock structures.
+/// 
+/// The structures get deeper and wider as their number grows, so the sand has room to fall between them.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    let depth = 10 + size;
+
+    for _ in 0..size {
+        // start the structure anywhere below the source of the sand
+        let mut x = rng.usize(500usize.saturating_sub(depth)..500 + depth);
+        let mut y = rng.usize(1..depth);
+        let mut points = vec![format!("{x},{y}")];
+
+        // alternate horizontal and vertical segments
+        let mut horizontal = rng.chance(0.5);
+        for _ in 0..rng.usize(1..5) {
+            let length = rng.usize(1..8);
+            if horizontal {
+                x = if rng.chance(0.5) { x + length } else { x.saturating_sub(length) };
+            } else {
+                y = if rng.chance(0.5) { y + length } else { y.saturating_sub(length).max(1) };
+            }
+            points.push(format!("{x},{y}"));
+            horizontal = !horizontal;
+        }
+
+        input.push_str(&points.join(" -> "));
+        input.push('\n');
+    }
+
+    input
+}
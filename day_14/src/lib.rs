@@ -0,0 +1,50 @@
+pub mod line;
+pub mod map;
+pub mod generator;
+pub mod visualization;
+
+use aoc_common::{ParseError, Solution};
+use map::{Map, UpdateState};
+
+/// Adds units of sand to the map until one of them doesn't come to rest.
+/// 
+/// # Returns
+/// 
+/// The number of units of sand that came to rest.
+pub fn count_resting_sand(mut map: Map) -> usize {
+    let mut units_added = 0;
+    while let UpdateState::SandComesToRest = map.update() {
+        units_added += 1;
+    }
+    units_added
+}
+
+/// The goal of this challenge is to simulate sand falling into a cave.
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Map;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // parse the input file into a map
+        Map::try_from(input)
+    }
+
+    /// The units of sand added before they start flowing into the void.
+    fn part_one(map: &Self::Input) -> Self::PartOne {
+        // execute the first version of the simulation
+        count_resting_sand(map.clone())
+    }
+
+    /// The units of sand added before the source is blocked, when there is a floor.
+    fn part_two(map: &Self::Input) -> Self::PartTwo {
+        // execute the second version of the simulation (this time with a floor)
+        let mut map_v2 = map.clone();
+        map_v2.add_floor();
+        count_resting_sand(map_v2)
+    }
+}
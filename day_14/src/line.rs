@@ -1,23 +1,38 @@
-use crate::point::Point;
+use aoc_common::ParseError;
+use aoc_point::Point;
 
 /// A line in a 2D space.
 /// It is composed of a list of points, connected together.
 #[derive(Debug, Clone)]
 pub struct Line {
-    pub points: Vec<Point>,
+    pub points: Vec<Point<usize>>,
 }
 
+/// Parses a line, such as `498,4 -> 498,6 -> 496,6`.
+/// 
+/// The column of the error is relative to the given string.
 impl TryFrom<&str> for Line {
-    type Error = ();
+    type Error = ParseError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         // split the string when a " -> " is found
         // the line is a list of connected points, to each element should be a point
         // if any of the elements is not a point, then the string is not a valid line
-        let points: Vec<Point> = value
-            .split(" -> ")
-            .map(|s| Point::try_from(s))
-            .collect::<Result<Vec<Point>, ()>>()?;
+        let points_str: Vec<&str> = value.split(" -> ").collect();
+        let points: Vec<Point<usize>> = points_str
+            .iter()
+            .map(|s| s.parse().map_err(|error: ParseError| error.within(value, s)))
+            .collect::<Result<Vec<Point<usize>>, ParseError>>()?;
+
+        // the points must be connected by horizontal or vertical segments
+        for (index, (point_1, point_2)) in points.iter().zip(points.iter().skip(1)).enumerate() {
+            if point_1.x != point_2.x && point_1.y != point_2.y {
+                return Err(
+                    ParseError::new("expected a point on the same row or column as the previous one, diagonal segments are not supported")
+                        .within(value, points_str[index + 1])
+                );
+            }
+        }
 
         // return the line
         Ok(Line { points })
@@ -25,7 +40,7 @@ impl TryFrom<&str> for Line {
 }
 
 impl TryFrom<String> for Line {
-    type Error = ();
+    type Error = ParseError;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         Line::try_from(value.as_ref())
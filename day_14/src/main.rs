@@ -1,40 +1,39 @@
-mod point;
-mod line;
-mod map;
-
-use std::fs;
-use map::{Map, UpdateState};
+use aoc_common::{load_input_or_exit, memory::CountingAllocator, print_answers, print_timings, Arguments, Format, Solution};
+use day_14::Day14;
 use thousands::Separable;
 
-fn main() {
-    // read the input file
-    let input = fs::read_to_string("inputs/day_14.txt").expect("Unable to read the input file.");
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
 
-    // parse the input file into a map
-    let map = Map::try_from(input).expect("Unable to parse the input file into a map.");
+fn main() {
+    // read the input file, its path can be given as an argument (`-` for the standard input)
+    let arguments = Arguments::from_env();
+    let input = load_input_or_exit(Day14::DAY, arguments.input.as_deref());
 
-    // execute the first version of the simulation
-    let mut map_v1 = map.clone();
-    let mut units_added_v1 = 0;
-    while let UpdateState::SandComesToRest = map_v1.update() {
-        units_added_v1 += 1;
+    // the time taken and the heap used by each phase can be reported along with the answers
+    if arguments.timings {
+        return print_timings::<Day14>(&input, arguments.format);
     }
-    
-    // execute the second version of the simulation (this time with a floor)
-    let mut map_v2 = map.clone();
-    map_v2.add_floor();
-    let mut units_added_v2 = 0;
-    while let UpdateState::SandComesToRest = map_v2.update() {
-        units_added_v2 += 1;
+
+    // the answers can be displayed in a machine-readable format instead
+    if arguments.format != Format::Text {
+        return print_answers::<Day14>(&input, arguments.format, arguments.command);
     }
 
-    // print the results of the simulations
-    println!(
-        "Units of sands added before they start flowing into the void: {units_added_v1}",
-        units_added_v1 = units_added_v1.separate_with_commas()
-    );
-    println!(
-        "Units of sands added before we can't add more (with a floor this time): {units_added_v2}",
-        units_added_v2 = units_added_v2.separate_with_commas()
-    );
+    // parse the map of the cave
+    let map = Day14::parse_input(&input).unwrap_or_else(|error| error.exit());
+
+    // execute each version of the simulation, and print the results
+    if arguments.command.runs(1) {
+        println!(
+            "Units of sands added before they start flowing into the void: {units_added_v1}",
+            units_added_v1 = Day14::part_one(&map).separate_with_commas()
+        );
+    }
+    if arguments.command.runs(2) {
+        println!(
+            "Units of sands added before we can't add more (with a floor this time): {units_added_v2}",
+            units_added_v2 = Day14::part_two(&map).separate_with_commas()
+        );
+    }
 }
@@ -1,4 +1,6 @@
 use std::{convert::TryFrom, fmt::Display};
+use aoc_common::{parse::numbered_lines, ParseError};
+use aoc_grid::Grid;
 use crate::line::Line;
 
 /// A cell represents the state of a single point in the map.
@@ -24,9 +26,7 @@ pub enum UpdateState {
 /// A map is a 2D space, where sand can be simulated.
 #[derive(Debug, Clone)]
 pub struct Map {
-    state: Vec<Vec<Cell>>,
-    width: usize,
-    height: usize,
+    state: Grid<Cell>,
     sand_start_x: usize,
 }
 
@@ -38,41 +38,45 @@ impl Map {
         let mut sand_y = 0;
 
         // if the sand is blocked
-        if self.state[sand_y][sand_x] != Cell::Air {
+        if self.state[(sand_x, sand_y)] != Cell::Air {
             // return that the sand cannot flow
+            aoc_common::trace!(14, "blocked", x = sand_x, y = sand_y);
             return UpdateState::SandCannotFlow;
         }
 
         // while the sand can flow, move it
         loop {
             // if the sand is at the bottom of the map
-            if sand_y + 1 == self.height {
+            if sand_y + 1 == self.state.height() {
                 // return that the sand has fallen into the abyss
+                aoc_common::trace!(14, "abyss", x = sand_x, y = sand_y);
                 return UpdateState::SandFlowsIntoAbyss;
             }
             // else if the sand can flow down
-            else if self.state[sand_y+1][sand_x] == Cell::Air {
+            else if self.state[(sand_x, sand_y+1)] == Cell::Air {
                 // move the sand down
                 sand_y += 1;
             }
             // else if the sand is at the left edge of the map
             else if sand_x == 0 {
                 // return that the sand has fallen into the abyss
+                aoc_common::trace!(14, "abyss", x = sand_x, y = sand_y);
                 return UpdateState::SandFlowsIntoAbyss;
             }
             // else if the sand can flow left
-            else if self.state[sand_y+1][sand_x-1] == Cell::Air {
+            else if self.state[(sand_x-1, sand_y+1)] == Cell::Air {
                 // move the sand left
                 sand_x -= 1;
                 sand_y += 1;
             }
             // else if the sand is at the right edge of the map
-            else if sand_x + 1 == self.width {
+            else if sand_x + 1 == self.state.width() {
                 // return that the sand has fallen into the abyss
+                aoc_common::trace!(14, "abyss", x = sand_x, y = sand_y);
                 return UpdateState::SandFlowsIntoAbyss;
             }
             // else if the sand can flow right
-            else if self.state[sand_y+1][sand_x+1] == Cell::Air {
+            else if self.state[(sand_x+1, sand_y+1)] == Cell::Air {
                 // move the sand right
                 sand_x += 1;
                 sand_y += 1;
@@ -82,39 +86,57 @@ impl Map {
             else {
                 break;
             }
+
+            aoc_common::trace!(14, "fall", x = sand_x, y = sand_y);
         }
 
         // update the map
-        self.state[sand_y][sand_x] = Cell::Sand;
+        self.state[(sand_x, sand_y)] = Cell::Sand;
+        aoc_common::trace!(14, "rest", x = sand_x, y = sand_y);
 
         // return that the sand has come to rest
         return UpdateState::SandComesToRest;
     }
 
+    /// The cells of the map.
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.state
+    }
+
+    /// The position where the sand is created.
+    pub fn sand_source(&self) -> (usize, usize) {
+        (self.sand_start_x, 0)
+    }
+
     /// Add a floor to the map.
     pub fn add_floor(&mut self) {
-        for x in 0..self.width {
-            self.state[self.height - 1][x] = Cell::Wall;
-        }
+        let floor = self.state.height() - 1;
+        self.state.row_mut(floor).fill(Cell::Wall);
     }
 }
 
+/// Parses a map from the scan of the rock structures, one line per structure.
 impl TryFrom<&str> for Map {
-    type Error = ();
+    type Error = ParseError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         // try to parse the input into a vector of lines
-        let lines: Vec<Line> = value
-            .lines()
-            .map(|s| s.try_into())
-            .collect::<Result<Vec<Line>, ()>>()?;
+        let lines: Vec<Line> = numbered_lines(value)
+            .map(|(line_number, line)| Line::try_from(line).map_err(|error| error.at_line(line_number, line)))
+            .collect::<Result<Vec<Line>, ParseError>>()?;
 
         // find the maximum y coordinate present in the input
-        let map_max_y = lines.iter().map(|l| l.points.iter().map(|p| p.y).max().unwrap()).max().unwrap() + 2;
+        let map_max_y = lines
+            .iter()
+            .flat_map(|l| l.points.iter().map(|p| p.y))
+            .max()
+            .ok_or_else(|| ParseError::new("the scan doesn't contain any rock structure"))?
+            + 2;
         // find the minimum and maximum x coordinates needed to contain the map
         // we are making the map bigger than needed,
         // but it's the smallest map that can simulate until the end without needing a resize
-        let map_min_x = 500 - map_max_y;
+        // the rocks outside of these bounds can't be reached by the sand, so they are not drawn
+        let map_min_x = 500usize.saturating_sub(map_max_y);
         let map_max_x = 500 + map_max_y;
         
         // calculate the width and height of the map
@@ -122,7 +144,7 @@ impl TryFrom<&str> for Map {
         let map_height = map_max_y + 1;
 
         // create the map, and fill it with air
-        let mut state = vec![vec![Cell::Air; map_width]; map_height];
+        let mut state = Grid::new(map_width, map_height, Cell::Air);
 
         // for each line in the input
         for line in lines {
@@ -138,8 +160,10 @@ impl TryFrom<&str> for Map {
                     };
 
                     // draw the line
-                    for y in line_start..=line_end {
-                        state[y][point_1.x - map_min_x] = Cell::Wall;
+                    if (map_min_x..=map_max_x).contains(&point_1.x) {
+                        for y in line_start..=line_end {
+                            state[(point_1.x - map_min_x, y)] = Cell::Wall;
+                        }
                     }
                 }
                 
@@ -153,8 +177,8 @@ impl TryFrom<&str> for Map {
                     };
 
                     // draw the line
-                    for x in line_start..=line_end {
-                        state[point_1.y][x - map_min_x] = Cell::Wall;
+                    for x in line_start.max(map_min_x)..=line_end.min(map_max_x) {
+                        state[(x - map_min_x, point_1.y)] = Cell::Wall;
                     }
                 }
             }
@@ -163,43 +187,33 @@ impl TryFrom<&str> for Map {
         // create the map
         Ok(Self {
             state,
-            width: map_width,
-            height: map_height,
             sand_start_x: 500 - map_min_x,
         })
     }
 }
 
 impl TryFrom<String> for Map {
-    type Error = ();
+    type Error = ParseError;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
         Self::try_from(value.as_str())
     }
 }
 
-impl Display for Map {
+/// Displays a cell as a single character, followed by a space to keep the map roughly square.
+impl Display for Cell {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // create a string to hold the stringified map
-        let mut stringified_map = String::new();
-
-        // iterate over lines of the map
-        for line in &self.state {
-            // for each cell in the line
-            for cell in line {
-                // display the cell
-                match cell {
-                    Cell::Air => stringified_map.push_str(". "),
-                    Cell::Wall => stringified_map.push_str("# "),
-                    Cell::Sand => stringified_map.push_str("O "),
-                }
-            }
-
-            // add a new line
-            stringified_map.push_str("\n");
+        match self {
+            Cell::Air => write!(f, ". "),
+            Cell::Wall => write!(f, "# "),
+            Cell::Sand => write!(f, "O "),
         }
+    }
+}
 
-        // display the stringified map
-        write!(f, "{}", stringified_map)
+impl Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // display the map line by line
+        writeln!(f, "{}", self.state)
     }
 }
@@ -0,0 +1,60 @@
+use std::iter;
+use aoc_visual::{Cell, Color, Frame, Frames, Visualize};
+use crate::{map::{Cell as MapCell, Map, UpdateState}, Day14};
+
+/// Once the floor is added, a frame is only drawn every time this many units of sand came to rest, as the pile grows much bigger.
+const UNITS_PER_FRAME_WITH_FLOOR: usize = 25;
+
+/// Animates the sand piling up, one frame per unit of sand coming to rest until the sand flows into the abyss.
+/// The floor is then added, and the sand keeps piling up until the source is blocked.
+impl Visualize for Day14 {
+    fn frames(mut map: Self::Input) -> Frames {
+        let initial_frame = draw(&map);
+        let mut has_floor = false;
+        let mut units_since_floor = 0;
+        let mut finished = false;
+
+        let frames = iter::from_fn(move || {
+            while !finished {
+                match map.update() {
+                    UpdateState::SandComesToRest if !has_floor => return Some(draw(&map)),
+                    UpdateState::SandComesToRest => {
+                        units_since_floor += 1;
+                        if units_since_floor % UNITS_PER_FRAME_WITH_FLOOR == 0 {
+                            return Some(draw(&map));
+                        }
+                    }
+                    // the first part is over, the sand can't flow into the abyss once the floor is added
+                    UpdateState::SandFlowsIntoAbyss if !has_floor => {
+                        map.add_floor();
+                        has_floor = true;
+                        return Some(draw(&map));
+                    }
+                    // the source is blocked, the last frame shows the whole pile
+                    UpdateState::SandFlowsIntoAbyss | UpdateState::SandCannotFlow => {
+                        finished = true;
+                        return Some(draw(&map));
+                    }
+                }
+            }
+            None
+        });
+
+        Box::new(iter::once(initial_frame).chain(frames))
+    }
+}
+
+/// Draws the rocks, the sand that came to rest, and the source of the sand.
+fn draw(map: &Map) -> Frame {
+    let mut frame = Frame::from_grid(map.cells().map(|cell| match cell {
+        MapCell::Air => Cell::EMPTY,
+        MapCell::Wall => Cell::new('#', Color::GRAY),
+        MapCell::Sand => Cell::new('o', Color::SAND),
+    }));
+
+    if frame[map.sand_source()] == Cell::EMPTY {
+        frame[map.sand_source()] = Cell::new('+', Color::WHITE);
+    }
+
+    frame
+}
@@ -0,0 +1,25 @@
+use aoc_common::{random::check_property, Solution};
+use day_14::{generator::generate_input, line::Line, Day14};
+
+#[test]
+fn sand_fits_in_the_floor_triangle() {
+    check_property(100, |rng| {
+        let size = rng.usize(1..15);
+        let input = generate_input(rng, size);
+        let (resting_sand, resting_sand_with_floor) = Day14::solve(&input).unwrap();
+
+        // the sand falls at most one column aside per row, so with a floor 2 rows below the lowest rock,
+        // it fills at most a triangle of `floor` rows, holding 1 + 3 + 5 + ... units of sand
+        let lowest_rock = input
+            .lines()
+            .flat_map(|line| Line::try_from(line).unwrap().points)
+            .map(|point| point.y)
+            .max()
+            .unwrap();
+        let floor = lowest_rock + 2;
+        assert!(resting_sand_with_floor <= floor * floor, "{resting_sand_with_floor} units of sand can't fit above a floor at y={floor}");
+
+        // the floor can only catch more sand
+        assert!(resting_sand <= resting_sand_with_floor);
+    });
+}
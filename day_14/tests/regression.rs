@@ -0,0 +1,36 @@
+use aoc_common::Solution;
+use aoc_visual::parse_frames;
+use day_14::Day14;
+
+/// The example from the puzzle description.
+const EXAMPLE: &str = "\
+498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9
+";
+
+/// The puzzle input of the repository.
+const INPUT: &str = include_str!("../../inputs/day_14.txt");
+
+#[test]
+fn example() {
+    assert_eq!(Day14::solve(EXAMPLE).unwrap(), (24, 93));
+}
+
+#[test]
+fn input() {
+    assert_eq!(Day14::solve(INPUT).unwrap(), (715, 25_248));
+}
+
+#[test]
+fn visualization() {
+    let sand_counts: Vec<usize> = parse_frames::<Day14>(EXAMPLE)
+        .unwrap()
+        .map(|frame| frame.to_string().matches('o').count())
+        .collect();
+
+    // one frame per unit of sand until the sand flows into the abyss, then the floor is added
+    assert_eq!(sand_counts[..26], (0..=24).chain([24]).collect::<Vec<_>>());
+
+    // the last frame shows the whole pile
+    assert_eq!(sand_counts.last(), Some(&93));
+}
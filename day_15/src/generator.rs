@@ -0,0 +1,59 @@
+use aoc_common::random::Rng;
+use aoc_point::Point;
+
+/// The largest coordinate of the area where the distress beacon is hidden.
+const MAX_COORDINATE: isize = 4_000_000;
+
+/// Generates a valid input made of `size` sensors, plus 4 sensors making sure there is a single place to hide the distress beacon.
+/// 
+/// The 4 extra sensors sit diagonally around the hidden beacon, just far enough not to detect it,
+/// and together they cover the rest of the search area. The other sensors are scattered randomly, without detecting the hidden beacon.
+/// Each beacon is at the edge of its sensor's range, but it isn't always the closest beacon to every other sensor.
+pub fn generate_input(rng: &mut Rng, size: usize) -> String {
+    let hidden_beacon = random_location(rng);
+    let mut sensors = vec![];
+
+    // surround the hidden beacon, each sensor reaching one position short of it
+    let offset = MAX_COORDINATE + 1;
+    for (x, y) in [(-1, -1), (1, -1), (-1, 1), (1, 1)] {
+        let location = hidden_beacon + Point::new(x * offset, y * offset);
+        let radius = 2 * offset - 1;
+
+        // the beacon is on the far side of the sensor, away from the search area
+        sensors.push((location, location + Point::new(x * radius, 0)));
+    }
+
+    // scatter the other sensors, none of them may detect the hidden beacon
+    while sensors.len() < size + 4 {
+        let location = random_location(rng);
+        let distance = location.manhattan_distance(hidden_beacon) as i64;
+        if distance == 0 {
+            continue;
+        }
+
+        // put the beacon anywhere on the edge of the range of the sensor
+        let radius = rng.i64(0..distance);
+        let x = rng.i64(-radius..radius + 1);
+        let y = (radius - x.abs()) * if rng.chance(0.5) { 1 } else { -1 };
+        sensors.push((location, location + Point::new(x as isize, y as isize)));
+    }
+
+    // the sensors are listed in any order
+    rng.shuffle(&mut sensors);
+
+    let mut input = String::new();
+    for (sensor, beacon) in sensors {
+        input.push_str(&format!(
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+            sensor.x, sensor.y, beacon.x, beacon.y
+        ));
+    }
+
+    input
+}
+
+/// Picks a location inside of the search area.
+fn random_location(rng: &mut Rng) -> Point<isize> {
+    let mut coordinate = || rng.i64(0..MAX_COORDINATE as i64 + 1) as isize;
+    Point::new(coordinate(), coordinate())
+}
@@ -0,0 +1,72 @@
+use z3::{self, ast::Ast};
+use aoc_point::Point;
+use crate::sensor::Sensor;
+
+// find the only location within the grid that is not covered by any sensor
+pub fn find_hidden_beacon(sensors: &[Sensor], max_coordinate: isize) -> Option<Point<isize>> {
+    // create a z3 solver
+    let solver_config = z3::Config::new();
+    let solver_context = z3::Context::new(&solver_config);
+    let solver = z3::Solver::new(&solver_context);
+
+    // create the hidden beacon's location variables
+    let hidden_beacon_x = z3::ast::Int::new_const(&solver_context, "hidden_beacon_x");
+    let hidden_beacon_y = z3::ast::Int::new_const(&solver_context, "hidden_beacon_y");
+
+    // assert that the beacon's location is within a grid of max_coordinate x max_coordinate
+    solver.assert(&hidden_beacon_x.ge(&z3::ast::Int::from_i64(&solver_context, 0)));
+    solver.assert(&hidden_beacon_x.le(&z3::ast::Int::from_i64(&solver_context, max_coordinate as i64)));
+    solver.assert(&hidden_beacon_y.ge(&z3::ast::Int::from_i64(&solver_context, 0)));
+    solver.assert(&hidden_beacon_y.le(&z3::ast::Int::from_i64(&solver_context, max_coordinate as i64)));
+
+    // for each sensor in the dataset
+    for sensor in sensors {
+        // convert the sensor's location to z3's Int
+        let sensor_x = z3::ast::Int::from_i64(&solver_context, sensor.location.x as i64);
+        let sensor_y = z3::ast::Int::from_i64(&solver_context, sensor.location.y as i64);
+        let sensor_clear_radius = z3::ast::Int::from_i64(&solver_context, sensor.sensor_clear_radius as i64);
+
+        // calculate the distance from the sensor to the beacon
+        let distance_from_sensor = z3_manhattan_distance(
+            &sensor_x, &sensor_y,
+            &hidden_beacon_x, &hidden_beacon_y
+        );
+
+        // assert that the distance from the sensor to the beacon is greater than the sensor's clear radius
+        solver.assert(&distance_from_sensor.gt(&sensor_clear_radius));
+    }
+
+    // check that the problem is satisfiable
+    if solver.check() != z3::SatResult::Sat {
+        return None;
+    }
+
+    // get the model
+    let model = solver.get_model().unwrap();
+
+    // extract the solutions
+    let hidden_beacon_x_i64 = model
+        .eval(&hidden_beacon_x, true)
+        .expect("Could not extract the beacon's x coordinate.")
+        .as_i64()
+        .expect("Could not convert the beacon's x coordinate to an i64.");
+    let hidden_beacon_y_i64 = model
+        .eval(&hidden_beacon_y, true)
+        .expect("Could not extract the beacon's y coordinate.")
+        .as_i64()
+        .expect("Could not convert the beacon's y coordinate to an i64.");
+
+    Some(Point::new(hidden_beacon_x_i64 as isize, hidden_beacon_y_i64 as isize))
+}
+
+// compute the absolute value of a z3 integer
+fn z3_abs<'ctx>(number: &z3::ast::Int<'ctx>) -> z3::ast::Int<'ctx> {
+    let ctx = number.get_ctx();
+    let zero = z3::ast::Int::from_i64(ctx, 0i64);
+    number.ge(&zero).ite(&number, &(-number))
+}
+
+// compute the manhattan distance between two z3 integers
+fn z3_manhattan_distance<'ctx>(x1: &z3::ast::Int<'ctx>, y1: &z3::ast::Int<'ctx>, x2: &z3::ast::Int<'ctx>, y2: &z3::ast::Int<'ctx>) -> z3::ast::Int<'ctx> {
+    z3_abs(&(x1 - x2)) + z3_abs(&(y1 - y2))
+}
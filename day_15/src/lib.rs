@@ -0,0 +1,91 @@
+pub mod sensor;
+pub mod scanline;
+pub mod hidden_beacon;
+pub mod generator;
+
+use aoc_common::{parse::numbered_lines, Arguments, MaybeAnswer, Parameters, ParseError, Solution};
+use hidden_beacon::find_hidden_beacon;
+use scanline::count_empty_positions;
+use sensor::Sensor;
+
+/// The goal of this challenge is to find where the distress beacon is hidden.
+pub struct Day15;
+
+/// Where the beacons are looked for.
+/// 
+/// # Fields
+/// 
+/// * `row` - The row whose empty positions are counted by the first part, y=2,000,000 in the puzzle.
+/// * `bound` - The highest coordinate of the hidden beacon searched by the second part, 4,000,000 in the puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchArea {
+    pub row: isize,
+    pub bound: isize,
+}
+
+impl Default for SearchArea {
+    fn default() -> Self {
+        Self { row: 2_000_000, bound: 4_000_000 }
+    }
+}
+
+impl Parameters for SearchArea {
+    const OPTIONS: &'static [(&'static str, &'static str)] = &[
+        ("row", "the row whose empty positions are counted by the first part (2,000,000 by default)"),
+        ("bound", "the highest coordinate of the hidden beacon searched by the second part (4,000,000 by default)"),
+    ];
+
+    fn from_arguments(arguments: &Arguments) -> Result<Self, String> {
+        let default = Self::default();
+        Ok(Self {
+            row: arguments.parameter("row", default.row)?,
+            bound: arguments.parameter("bound", default.bound)?,
+        })
+    }
+}
+
+impl Day15 {
+    /// The number of positions where a beacon cannot be present on the row of the search area.
+    pub fn part_one_with(sensors: &[Sensor], area: &SearchArea) -> isize {
+        count_empty_positions(sensors, area.row)
+    }
+
+    /// The tuning frequency of the hidden beacon, searched between 0 and the bound of the search area.
+    /// 
+    /// Returns `None` if there is no position where the beacon can hide.
+    pub fn part_two_with(sensors: &[Sensor], area: &SearchArea) -> Option<i64> {
+        let hidden_beacon = find_hidden_beacon(sensors, area.bound)?;
+
+        // compute the beacon's tuning frequency, whose multiplier doesn't depend on the search area
+        Some(hidden_beacon.x as i64 * 4_000_000 + hidden_beacon.y as i64)
+    }
+
+    /// The answer of the second part in the given search area, or the reason why there is none.
+    pub fn part_two_answer(sensors: &[Sensor], area: &SearchArea) -> MaybeAnswer<i64> {
+        MaybeAnswer::from_option(Self::part_two_with(sensors, area), "no position for the beacon")
+    }
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Vec<Sensor>;
+    type PartOne = isize;
+    type PartTwo = MaybeAnswer<i64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        numbered_lines(input)
+            .map(|(line_number, line)| line.parse::<Sensor>().map_err(|error| error.at_line(line_number, line)))
+            .collect()
+    }
+
+    /// The number of positions where a beacon cannot be present on the row y=2,000,000.
+    fn part_one(sensors: &Self::Input) -> Self::PartOne {
+        Self::part_one_with(sensors, &SearchArea::default())
+    }
+
+    /// The tuning frequency of the hidden beacon.
+    fn part_two(sensors: &Self::Input) -> Self::PartTwo {
+        Self::part_two_answer(sensors, &SearchArea::default())
+    }
+}
@@ -0,0 +1,78 @@
+use std::{collections::HashSet, ops::Range};
+use aoc_point::Point;
+use crate::sensor::Sensor;
+
+// compute the scanline ranges for a given scanline
+pub fn get_scanline_ranges(sensors: &[Sensor], scanline_y: isize) -> Vec<Range<isize>> {
+    let mut ranges_start: Vec<isize> = vec![];
+    let mut ranges: Vec<Range<isize>> = vec![];
+
+    for sensor in sensors {
+        let distance_from_scan_line = (sensor.location.y - scanline_y).abs();
+        let sensor_clear_radius = sensor.sensor_clear_radius as isize;
+
+        if sensor_clear_radius < distance_from_scan_line {
+            continue;
+        }
+
+        let scan_line_radius = sensor_clear_radius - distance_from_scan_line;
+        let range_start = sensor.location.x - scan_line_radius;
+        let range_end = sensor.location.x + scan_line_radius + 1;
+        
+        let insertion_index = ranges_start.binary_search(&range_start).unwrap_or_else(|e| e);
+        ranges_start.insert(insertion_index, range_start);
+        ranges.insert(insertion_index, range_start..range_end);
+
+        let mut new_ranges = vec![];
+        let mut current_range = ranges[0].clone();
+
+        for range in ranges.iter().skip(1) {
+            if range.start <= current_range.end {
+                current_range.end = current_range.end.max(range.end);
+            } else {
+                new_ranges.push(current_range);
+                current_range = range.clone();
+            }
+        }
+        new_ranges.push(current_range);
+
+        ranges_start = Vec::with_capacity(new_ranges.len());
+        for range in &new_ranges {
+            ranges_start.push(range.start);
+        }
+        ranges = new_ranges;
+    }
+
+    ranges
+}
+
+// count the positions of the scanline where a beacon cannot be present
+pub fn count_empty_positions(sensors: &[Sensor], scanline_y: isize) -> isize {
+    // find the beacons detected by the sensors
+    let beacons: HashSet<Point<isize>> = sensors.iter().map(|sensor| sensor.beacon_location).collect();
+
+    // compute the empty position at index
+    let mut total_empty_positions = 0;
+
+    // load the ranges of the scanline
+    let ranges = get_scanline_ranges(sensors, scanline_y);
+
+    // compute the total number of empty positions
+    for range in &ranges {
+        total_empty_positions += range.end - range.start;
+    }
+
+    // remove the beacons from the empty positions
+    'beacons_iter: for beacon in &beacons {
+        if beacon.y == scanline_y {
+            for range in &ranges {
+                if range.contains(&beacon.x) {
+                    total_empty_positions -= 1;
+                    continue 'beacons_iter;
+                }
+            }
+        }
+    }
+
+    total_empty_positions
+}
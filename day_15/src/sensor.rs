@@ -0,0 +1,46 @@
+use std::str::FromStr;
+use aoc_common::ParseError;
+use aoc_point::Point;
+
+// a sensor, along with the closest beacon it detected
+// no other beacon can be closer to the sensor than `sensor_clear_radius`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sensor {
+    pub location: Point<isize>,
+    pub beacon_location: Point<isize>,
+    pub sensor_clear_radius: usize,
+}
+
+impl Sensor {
+    pub fn new(location: Point<isize>, beacon_location: Point<isize>, sensor_clear_radius: usize) -> Self {
+        Self { location, beacon_location, sensor_clear_radius }
+    }
+}
+
+// parses a sensor, such as `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`
+// the column of the error is relative to the given string
+impl FromStr for Sensor {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // split the locations of the sensor and of the beacon
+        let (sensor_str, beacon_str) = s
+            .strip_prefix("Sensor at ")
+            .and_then(|locations| locations.split_once(": closest beacon is at "))
+            .ok_or_else(|| ParseError::new("expected a sensor, such as `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`"))?;
+
+        // both locations are written as `x=2, y=18`
+        let parse_location = |location: &str| -> Result<Point<isize>, ParseError> {
+            location.parse().map_err(|error: ParseError| error.within(s, location))
+        };
+
+        let sensor_location = parse_location(sensor_str)?;
+        let beacon_location = parse_location(beacon_str)?;
+
+        Ok(Sensor::new(
+            sensor_location,
+            beacon_location,
+            sensor_location.manhattan_distance(beacon_location).unsigned_abs()
+        ))
+    }
+}
@@ -0,0 +1,72 @@
+use aoc_common::{MaybeAnswer, Solution};
+use aoc_point::Point;
+use day_15::{
+    hidden_beacon::find_hidden_beacon,
+    scanline::{count_empty_positions, get_scanline_ranges},
+    Day15, SearchArea,
+};
+
+/// The example from the puzzle description.
+/// 
+/// The example looks at the row y=10, and searches the hidden beacon between 0 and 20.
+const EXAMPLE: &str = "\
+Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3
+";
+
+/// The puzzle input of the repository.
+const INPUT: &str = include_str!("../../inputs/day_15.txt");
+
+#[test]
+fn example_scanline() {
+    let sensors = Day15::parse(EXAMPLE).expect("The example should be valid");
+
+    // the positions covered by the sensors on the row y=10 are contiguous
+    let covered: isize = get_scanline_ranges(&sensors, 10).iter().map(|range| range.end - range.start).sum();
+    assert_eq!(covered, 27);
+
+    assert_eq!(count_empty_positions(&sensors, 10), 26);
+}
+
+#[test]
+fn example_hidden_beacon() {
+    let sensors = Day15::parse(EXAMPLE).expect("The example should be valid");
+    assert_eq!(find_hidden_beacon(&sensors, 20), Some(Point::new(14, 11)));
+}
+
+#[test]
+fn input_part_one() {
+    let sensors = Day15::parse(INPUT).expect("The input should be valid");
+    assert_eq!(Day15::part_one(&sensors), 4_793_062);
+}
+
+#[test]
+fn input_part_two() {
+    let sensors = Day15::parse(INPUT).expect("The input should be valid");
+    assert_eq!(Day15::part_two(&sensors), MaybeAnswer::Answer(10_826_395_253_551));
+}
+
+#[test]
+fn example_search_area() {
+    let sensors = Day15::parse(EXAMPLE).expect("The example should be valid");
+
+    let area = SearchArea { row: 10, bound: 20 };
+    assert_eq!(Day15::part_one_with(&sensors, &area), 26);
+    assert_eq!(Day15::part_two_with(&sensors, &area), Some(56_000_011));
+
+    // the beacon is outside of a smaller search area, which is fully covered by the sensors
+    let area = SearchArea { row: 10, bound: 10 };
+    assert_eq!(Day15::part_two_answer(&sensors, &area), MaybeAnswer::NoAnswer("no position for the beacon"));
+}